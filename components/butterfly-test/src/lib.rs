@@ -397,6 +397,30 @@ impl SwimNet {
         }
     }
 
+    pub fn local_health_of(&self, member: usize) -> usize {
+        self.members
+            .get(member)
+            .expect("Asked for a network member who is out of bounds")
+            .local_health_multiplier()
+    }
+
+    pub fn wait_for_local_health_below(&self, member: usize, limit: usize) -> bool {
+        let rounds_in = self.rounds_in(self.max_rounds());
+        loop {
+            if self.local_health_of(member) < limit {
+                return true;
+            }
+            if self.check_rounds(&rounds_in) {
+                println!("Failed local health check for {}: {} is not below {}",
+                         member,
+                         self.local_health_of(member),
+                         limit);
+                return false;
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+
     #[allow(dead_code)]
     pub fn wait_protocol_period(&self) {
         let timing = Timing::default();
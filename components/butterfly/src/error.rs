@@ -31,11 +31,17 @@ pub enum Error {
     CannotBind(io::Error),
     HabitatCore(habitat_core::error::Error),
     NonExistentRumor(String, String),
+    PrimaryRingKey(String),
     ProtobufError(protobuf::ProtobufError),
+    PushPullIo(io::Error),
     ServiceConfigNotUtf8(FromUtf8Error),
+    SnapshotCorrupt(String),
+    SnapshotIo(io::Error),
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    UndecryptableWire,
+    UnknownRingKey(String),
     ZmqConnectError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
@@ -51,11 +57,17 @@ impl fmt::Display for Error {
                         member_id,
                         rumor_id)
             }
+            Error::PrimaryRingKey(ref key) => {
+                format!("Cannot remove ring key {}; it is the primary ring key", key)
+            }
             Error::ProtobufError(ref err) => format!("ProtoBuf Error: {}", err),
+            Error::PushPullIo(ref err) => format!("Cannot exchange state with a peer: {}", err),
             Error::ServiceConfigNotUtf8(ref err) => {
                 format!("Cannot decode service configuration; it is not UTF-8: {}",
                         err)
             }
+            Error::SnapshotCorrupt(ref err) => format!("Snapshot is corrupt: {}", err),
+            Error::SnapshotIo(ref err) => format!("Cannot read or write snapshot: {}", err),
             Error::SocketSetReadTimeout(ref err) => {
                 format!("Cannot set UDP socket read timeout: {}", err)
             }
@@ -63,6 +75,10 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::UndecryptableWire => {
+                format!("Cannot decrypt message; it was not encrypted with a known ring key")
+            }
+            Error::UnknownRingKey(ref key) => format!("Unknown ring key: {}", key),
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
@@ -82,11 +98,17 @@ impl error::Error for Error {
             Error::NonExistentRumor(ref _member_id, ref _rumor_id) => {
                 "Cannot write rumor to bytes because it does not exist"
             }
+            Error::PrimaryRingKey(ref _key) => "Cannot remove the primary ring key",
             Error::ProtobufError(ref err) => err.description(),
+            Error::PushPullIo(ref _err) => "Cannot exchange state with a peer",
             Error::ServiceConfigNotUtf8(ref _err) => "Cannot convert a service config to UTF-8",
+            Error::SnapshotCorrupt(ref _err) => "Snapshot is corrupt",
+            Error::SnapshotIo(ref _err) => "Cannot read or write snapshot",
             Error::SocketSetReadTimeout(ref _err) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(ref _err) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::UndecryptableWire => "Cannot decrypt message with a known ring key",
+            Error::UnknownRingKey(ref _key) => "Unknown ring key",
             Error::ZmqConnectError(ref _err) => "Cannot connect ZMQ socket",
             Error::ZmqSendError(ref _err) => "Cannot send message through ZMQ socket",
         }
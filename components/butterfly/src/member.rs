@@ -14,7 +14,7 @@
 
 //! Tracks membership. Contains both the `Member` struct and the `MemberList`.
 
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, HashMap, HashSet};
 use std::fmt;
 use std::iter::IntoIterator;
 use std::net::SocketAddr;
@@ -24,7 +24,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 use uuid::Uuid;
 use rand::{thread_rng, Rng};
-use time::SteadyTime;
+use time::{Duration, SteadyTime};
 
 use rumor::RumorKey;
 use message::swim::{Member as ProtoMember, Membership as ProtoMembership,
@@ -33,12 +33,14 @@ use message::swim::{Member as ProtoMember, Membership as ProtoMembership,
 /// How many nodes do we target when we need to run PingReq.
 const PINGREQ_TARGETS: usize = 5;
 
-/// The health of a node.
+/// The health of a node. `Departed` members left the ring on purpose, rather than being
+/// confirmed dead by the failure detector.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Health {
     Alive,
     Suspect,
     Confirmed,
+    Departed,
 }
 
 /// Maps our internal health to the wire protocols health.
@@ -48,6 +50,7 @@ impl From<ProtoMembership_Health> for Health {
             ProtoMembership_Health::ALIVE => Health::Alive,
             ProtoMembership_Health::SUSPECT => Health::Suspect,
             ProtoMembership_Health::CONFIRMED => Health::Confirmed,
+            ProtoMembership_Health::DEPARTED => Health::Departed,
         }
     }
 }
@@ -58,6 +61,7 @@ impl From<Health> for ProtoMembership_Health {
             Health::Alive => ProtoMembership_Health::ALIVE,
             Health::Suspect => ProtoMembership_Health::SUSPECT,
             Health::Confirmed => ProtoMembership_Health::CONFIRMED,
+            Health::Departed => ProtoMembership_Health::DEPARTED,
         }
     }
 }
@@ -68,6 +72,7 @@ impl<'a> From<&'a Health> for ProtoMembership_Health {
             &Health::Alive => ProtoMembership_Health::ALIVE,
             &Health::Suspect => ProtoMembership_Health::SUSPECT,
             &Health::Confirmed => ProtoMembership_Health::CONFIRMED,
+            &Health::Departed => ProtoMembership_Health::DEPARTED,
         }
     }
 }
@@ -78,6 +83,7 @@ impl fmt::Display for Health {
             &Health::Alive => write!(f, "Alive"),
             &Health::Suspect => write!(f, "Suspect"),
             &Health::Confirmed => write!(f, "Confirmed"),
+            &Health::Departed => write!(f, "Departed"),
         }
     }
 }
@@ -162,12 +168,38 @@ impl<'a> From<&'a &'a Member> for RumorKey {
 // This is a Uuid type turned to a string
 pub type UuidSimple = String;
 
-/// Tracks lists of members, their health, and how long they have been suspect.
+/// When we started suspecting a member, the member that first suspected it, and which other
+/// members have since told us that they suspect it too. The more independent confirmations, the
+/// sooner the suspicion expires.
+///
+/// Suspicions are relayed with the member that first suspected it, so a suspicion that reaches us
+/// through many relays still counts once. Only members who suspect it on their own confirm it.
+#[derive(Debug, Clone)]
+pub struct Suspicion {
+    pub since: SteadyTime,
+    pub suspected_by: Option<UuidSimple>,
+    pub confirmations: HashSet<UuidSimple>,
+}
+
+impl Suspicion {
+    /// A suspicion starting now, that nobody has confirmed yet.
+    pub fn new() -> Suspicion {
+        Suspicion {
+            since: SteadyTime::now(),
+            suspected_by: None,
+            confirmations: HashSet::new(),
+        }
+    }
+}
+
+/// Tracks lists of members, their health, how long they have been suspect, and how long they have
+/// been gone (confirmed or departed).
 #[derive(Debug, Clone)]
 pub struct MemberList {
     members: Arc<RwLock<HashMap<UuidSimple, Member>>>,
     health: Arc<RwLock<HashMap<UuidSimple, Health>>>,
-    suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
+    suspect: Arc<RwLock<HashMap<UuidSimple, Suspicion>>>,
+    gone: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
     initial_members: Arc<RwLock<Vec<Member>>>,
     update_counter: Arc<AtomicUsize>,
 }
@@ -179,6 +211,7 @@ impl MemberList {
             members: Arc::new(RwLock::new(HashMap::new())),
             health: Arc::new(RwLock::new(HashMap::new())),
             suspect: Arc::new(RwLock::new(HashMap::new())),
+            gone: Arc::new(RwLock::new(HashMap::new())),
             initial_members: Arc::new(RwLock::new(Vec::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
         }
@@ -218,8 +251,6 @@ impl MemberList {
     /// Inserts a member into the member list with the given health.
     pub fn insert(&self, member: Member, health: Health) -> bool {
         let share_rumor: bool;
-        let mut start_suspicion: bool = false;
-        let mut stop_suspicion: bool = false;
 
         // If we have an existing member record..
         if let Some(current_member) = self.members
@@ -242,7 +273,6 @@ impl MemberList {
                              insert?");
                 // If currently healthy and the rumor is suspicion, then we are now suspicious.
                 if *current_health == Health::Alive && health == Health::Suspect {
-                    start_suspicion = true;
                     share_rumor = true;
                     // If currently healthy and the rumor is confirmation, then we are now confirmed
                 } else if *current_health == Health::Alive && health == Health::Confirmed {
@@ -258,10 +288,15 @@ impl MemberList {
                     share_rumor = false;
                     // If currently suspicious and the rumor is confirmation, then we are now confirmed
                 } else if *current_health == Health::Suspect && health == Health::Confirmed {
-                    stop_suspicion = true;
                     share_rumor = true;
-                    // When we are currently confirmed, we stay that way until something with a
-                    // higher incarnation changes our mind.
+                    // If currently suspicious and the rumor is departure, then we have departed
+                } else if *current_health == Health::Suspect && health == Health::Departed {
+                    share_rumor = true;
+                    // A departure is more specific than a confirmation, so it always wins
+                } else if *current_health != Health::Departed && health == Health::Departed {
+                    share_rumor = true;
+                    // When we are currently confirmed or departed, we stay that way until
+                    // something with a higher incarnation changes our mind.
                 } else {
                     share_rumor = false;
                 }
@@ -276,15 +311,8 @@ impl MemberList {
                 .write()
                 .expect("Health lock is poisoned")
                 .insert(String::from(member.get_id()), health);
-            if start_suspicion == true {
-                self.suspect
-                    .write()
-                    .expect("Suspect lock is poisoned")
-                    .insert(String::from(member.get_id()), SteadyTime::now());
-            }
-            if stop_suspicion == true {
-                self.suspect.write().expect("Suspect lock is poisoned").remove(member.get_id());
-            }
+            self.track_gone(member.get_id(), health);
+            self.track_suspicion(member.get_id(), health);
             self.members
                 .write()
                 .expect("Member list lock is poisoned")
@@ -337,9 +365,11 @@ impl MemberList {
     }
 
     /// Returns true if we are pinging this member because they are persistent, but we think they
-    /// are gone.
+    /// are gone, either confirmed dead or departed.
     pub fn persistent_and_confirmed(&self, member: &Member) -> bool {
-        member.get_persistent() && self.check_health_of(member, Health::Confirmed)
+        member.get_persistent() &&
+        (self.check_health_of(member, Health::Confirmed) ||
+         self.check_health_of(member, Health::Departed))
     }
 
     /// Updates the health of a member without touching the member itself. Returns true if the
@@ -353,18 +383,103 @@ impl MemberList {
                 return false;
             }
         }
-        if health == Health::Suspect {
-            let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
-            sl.insert(String::from(member_id), SteadyTime::now());
-        }
+        self.track_suspicion(member_id, health);
         self.health
             .write()
             .expect("Health write lock is poisoned")
             .insert(String::from(member_id), health);
+        self.track_gone(member_id, health);
         self.increment_update_counter();
         true
     }
 
+    /// Start suspecting a member when it becomes suspect, and stop when it becomes anything else.
+    /// A suspicion that is already running keeps its start time and its confirmations.
+    fn track_suspicion(&self, member_id: &str, health: Health) {
+        let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
+        if health == Health::Suspect {
+            sl.entry(String::from(member_id)).or_insert(Suspicion::new());
+        } else {
+            sl.remove(member_id);
+        }
+    }
+
+    /// Record that `suspected_by` suspects `member_id`. The first member we hear it from is the
+    /// one that started our suspicion; every other member confirms it. Returns true if this is a
+    /// new confirmation: we suspect the member ourselves, and `suspected_by` had neither started
+    /// nor confirmed our suspicion yet.
+    pub fn confirm_suspicion(&self, member_id: &str, suspected_by: &str) -> bool {
+        if member_id == suspected_by {
+            return false;
+        }
+        match self.suspect.write().expect("Suspect lock is poisoned").get_mut(member_id) {
+            Some(suspicion) => {
+                match suspicion.suspected_by {
+                    None => {
+                        suspicion.suspected_by = Some(String::from(suspected_by));
+                        false
+                    }
+                    Some(ref started_by) if started_by == suspected_by => false,
+                    Some(_) => suspicion.confirmations.insert(String::from(suspected_by)),
+                }
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the member that started our suspicion of `member_id`, if we suspect it and know.
+    pub fn suspected_by(&self, member_id: &str) -> Option<UuidSimple> {
+        self.suspect
+            .read()
+            .expect("Suspect lock is poisoned")
+            .get(member_id)
+            .and_then(|suspicion| suspicion.suspected_by.clone())
+    }
+
+    /// Record when a member became confirmed or departed, and forget it when it comes back.
+    fn track_gone(&self, member_id: &str, health: Health) {
+        let mut gone = self.gone.write().expect("Gone lock is poisoned");
+        match health {
+            Health::Confirmed | Health::Departed => {
+                gone.entry(String::from(member_id)).or_insert(SteadyTime::now());
+            }
+            Health::Alive | Health::Suspect => {
+                gone.remove(member_id);
+            }
+        }
+    }
+
+    /// Returns the ids of members that have been confirmed or departed for longer than
+    /// `timeout`. Persistent members are never returned; we keep trying to reach them.
+    pub fn reapable(&self, timeout: Duration) -> Vec<UuidSimple> {
+        let now = SteadyTime::now();
+        let members = self.members.read().expect("Member list lock is poisoned");
+        self.gone
+            .read()
+            .expect("Gone lock is poisoned")
+            .iter()
+            .filter(|&(id, since)| {
+                *since + timeout <= now &&
+                members.get(id).map_or(true, |m| !m.get_persistent())
+            })
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Remove a member, and everything we know about its health, from the list. Returns the
+    /// member, if we had it.
+    pub fn remove(&self, member_id: &str) -> Option<Member> {
+        self.health.write().expect("Health lock is poisoned").remove(member_id);
+        self.suspect.write().expect("Suspect lock is poisoned").remove(member_id);
+        self.gone.write().expect("Gone lock is poisoned").remove(member_id);
+        let member = self.members
+            .write()
+            .expect("Member list lock is poisoned")
+            .remove(member_id);
+        self.increment_update_counter();
+        member
+    }
+
     /// The same as `insert_health_by_id`, but takes a member rather than an id.
     pub fn insert_health(&self, member: &Member, health: Health) -> bool {
         self.insert_health_by_id(member.get_id(), health)
@@ -384,9 +499,33 @@ impl MemberList {
             .expect("Should have membership before calling membership_for");
         pm.set_health(mhealth);
         pm.set_member(member.proto.clone());
+        if let Some(suspected_by) = self.suspected_by(member_id) {
+            pm.set_suspected_by(suspected_by);
+        }
         pm
     }
 
+    /// Returns a protobuf membership record for every member.
+    pub fn memberships(&self) -> Vec<ProtoMembership> {
+        let hl = self.health.read().expect("Health lock is poisoned");
+        let ml = self.members.read().expect("Member list lock is poisoned");
+        let sl = self.suspect.read().expect("Suspect lock is poisoned");
+        ml.values()
+            .filter_map(|member| {
+                hl.get(member.get_id()).map(|health| {
+                    let mut pm = ProtoMembership::new();
+                    pm.set_health(health.into());
+                    pm.set_member(member.proto.clone());
+                    if let Some(suspected_by) = sl.get(member.get_id())
+                        .and_then(|suspicion| suspicion.suspected_by.clone()) {
+                        pm.set_suspected_by(suspected_by);
+                    }
+                    pm
+                })
+            })
+            .collect()
+    }
+
     /// Returns the number of members.
     pub fn len(&self) -> usize {
         self.members.read().expect("Member list lock is poisoned").len()
@@ -466,7 +605,7 @@ impl MemberList {
 
     /// Iterates over every suspected membership entry, calling the given closure.
     pub fn with_suspects<F>(&self, mut with_closure: F) -> ()
-        where F: FnMut((&str, &SteadyTime)) -> ()
+        where F: FnMut((&str, &Suspicion)) -> ()
     {
         for (id, suspect) in self.suspect.read().expect("Suspect list lock is poisoned").iter() {
             with_closure((id, suspect));
@@ -513,6 +652,8 @@ mod tests {
     }
 
     mod member_list {
+        use time::Duration;
+
         use member::{Member, MemberList, Health, PINGREQ_TARGETS};
 
         fn populated_member_list(size: u64) -> MemberList {
@@ -783,5 +924,168 @@ mod tests {
             assert!(ml.check_health_of(&mcheck_two, Health::Confirmed));
         }
 
+        #[test]
+        fn insert_equal_incarnation_current_suspect_new_departed() {
+            let ml = MemberList::new();
+            let member_one = Member::new();
+            let mcheck_one = member_one.clone();
+            let member_two = member_one.clone();
+            let mcheck_two = member_two.clone();
+
+            assert_eq!(ml.insert(member_one, Health::Alive), true);
+            assert_eq!(ml.insert(member_two.clone(), Health::Suspect), true);
+            assert!(ml.check_health_of(&mcheck_one, Health::Suspect));
+
+            assert_eq!(ml.insert(member_two, Health::Departed), true);
+            assert!(ml.check_health_of(&mcheck_two, Health::Departed));
+            let mut suspects = 0;
+            ml.with_suspects(|_| suspects += 1);
+            assert_eq!(suspects, 0);
+        }
+
+        #[test]
+        fn insert_equal_incarnation_current_confirmed_new_departed() {
+            let ml = MemberList::new();
+            let member_one = Member::new();
+            let mcheck_one = member_one.clone();
+            let member_two = member_one.clone();
+            let mcheck_two = member_two.clone();
+
+            assert_eq!(ml.insert(member_one, Health::Confirmed), true);
+            assert!(ml.check_health_of(&mcheck_one, Health::Confirmed));
+
+            assert_eq!(ml.insert(member_two, Health::Departed), true);
+            assert!(ml.check_health_of(&mcheck_two, Health::Departed));
+        }
+
+        #[test]
+        fn insert_equal_incarnation_current_departed_new_alive() {
+            let ml = MemberList::new();
+            let member_one = Member::new();
+            let mcheck_one = member_one.clone();
+            let member_two = member_one.clone();
+            let mcheck_two = member_two.clone();
+
+            assert_eq!(ml.insert(member_one, Health::Departed), true);
+            assert!(ml.check_health_of(&mcheck_one, Health::Departed));
+
+            assert_eq!(ml.insert(member_two, Health::Alive), false);
+            assert!(ml.check_health_of(&mcheck_two, Health::Departed));
+        }
+
+        #[test]
+        fn insert_higher_incarnation_current_departed_new_alive() {
+            let ml = MemberList::new();
+            let member_one = Member::new();
+            let mut member_two = member_one.clone();
+            member_two.set_incarnation(1);
+            let mcheck_two = member_two.clone();
+
+            assert_eq!(ml.insert(member_one, Health::Departed), true);
+            assert_eq!(ml.insert(member_two, Health::Alive), true);
+            assert!(ml.check_health_of(&mcheck_two, Health::Alive));
+        }
+
+        #[test]
+        fn gone_members_become_reapable() {
+            let ml = MemberList::new();
+            let alive = Member::new();
+            let confirmed = Member::new();
+            let departed = Member::new();
+            let mut persistent = Member::new();
+            persistent.set_persistent(true);
+            let confirmed_id = String::from(confirmed.get_id());
+            let departed_id = String::from(departed.get_id());
+            ml.insert(alive, Health::Alive);
+            ml.insert(confirmed, Health::Confirmed);
+            ml.insert(departed, Health::Departed);
+            ml.insert(persistent, Health::Confirmed);
+
+            assert!(ml.reapable(Duration::minutes(1)).is_empty());
+            let mut reapable = ml.reapable(Duration::zero());
+            reapable.sort();
+            let mut expected = vec![confirmed_id.clone(), departed_id];
+            expected.sort();
+            assert_eq!(reapable, expected);
+
+            ml.insert_health_by_id(&confirmed_id, Health::Alive);
+            assert_eq!(ml.reapable(Duration::zero()).len(), 1);
+        }
+
+        #[test]
+        fn remove_forgets_the_member() {
+            let ml = populated_member_list(2);
+            let member = ml.check_list("foo").remove(0);
+            assert_eq!(ml.remove(member.get_id()), Some(member.clone()));
+            assert_eq!(ml.len(), 1);
+            assert!(ml.health_of(&member).is_none());
+            assert_eq!(ml.remove(member.get_id()), None);
+        }
+
+        #[test]
+        fn departed_members_are_not_pingable() {
+            let ml = MemberList::new();
+            let member = Member::new();
+            let mcheck = member.clone();
+            ml.insert(member, Health::Departed);
+            assert_eq!(ml.pingable(&mcheck), false);
+
+            let mut persistent = Member::new();
+            persistent.set_persistent(true);
+            let pcheck = persistent.clone();
+            ml.insert(persistent, Health::Departed);
+            assert!(ml.pingable(&pcheck));
+            assert!(ml.persistent_and_confirmed(&pcheck));
+        }
+
+        #[test]
+        fn insert_higher_incarnation_current_suspect_new_alive_stops_suspicion() {
+            let ml = MemberList::new();
+            let member_one = Member::new();
+            let mut member_two = member_one.clone();
+            member_two.set_incarnation(1);
+
+            assert_eq!(ml.insert(member_one, Health::Suspect), true);
+            let mut suspects = 0;
+            ml.with_suspects(|_| suspects += 1);
+            assert_eq!(suspects, 1);
+
+            assert_eq!(ml.insert(member_two, Health::Alive), true);
+            let mut suspects = 0;
+            ml.with_suspects(|_| suspects += 1);
+            assert_eq!(suspects, 0);
+        }
+
+        #[test]
+        fn confirm_suspicion_counts_each_member_once() {
+            let ml = MemberList::new();
+            let member = Member::new();
+            let mcheck = member.clone();
+            ml.insert(member, Health::Alive);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "foo"), false);
+
+            ml.insert_health(&mcheck, Health::Suspect);
+            // The first member we hear it from started the suspicion, and does not confirm it.
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "adam"), false);
+            assert_eq!(ml.suspected_by(mcheck.get_id()), Some(String::from("adam")));
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "foo"), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "foo"), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "bar"), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "adam"), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), mcheck.get_id()), false);
+            ml.with_suspects(|(_, suspicion)| assert_eq!(suspicion.confirmations.len(), 2));
+        }
+
+        #[test]
+        fn suspect_memberships_carry_who_started_the_suspicion() {
+            let ml = MemberList::new();
+            let member = Member::new();
+            ml.insert(member.clone(), Health::Suspect);
+            ml.confirm_suspicion(member.get_id(), "adam");
+            assert_eq!(ml.membership_for(member.get_id()).get_suspected_by(), "adam");
+            assert_eq!(ml.memberships()[0].get_suspected_by(), "adam");
+            ml.insert_health(&member, Health::Alive);
+            assert!(!ml.membership_for(member.get_id()).has_suspected_by());
+        }
     }
 }
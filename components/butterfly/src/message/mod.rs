@@ -22,7 +22,7 @@ use rustc_serialize::{Encoder, Encodable};
 
 use error::Result;
 use message::swim::Wire;
-use protobuf::{self, Message};
+use protobuf::Message;
 
 pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
     let mut wire = Wire::new();
@@ -37,15 +37,6 @@ pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<
     Ok(try!(wire.write_to_bytes()))
 }
 
-pub fn unwrap_wire(payload: &[u8], ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
-    let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
-    if let Some(ref ring_key) = *ring_key {
-        Ok(try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload())))
-    } else {
-        Ok(wire.take_payload())
-    }
-}
-
 impl Encodable for swim::Election {
     fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
         try!(s.emit_struct("election", 6, |s| {
@@ -118,7 +109,7 @@ impl Encodable for swim::Rumor {
 
 impl Encodable for swim::Service {
     fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
-        try!(s.emit_struct("service", 8, |s| {
+        try!(s.emit_struct("service", 12, |s| {
             try!(s.emit_struct_field("member_id", 0, |s| self.get_member_id().encode(s)));
             try!(s.emit_struct_field("service_group", 1, |s| self.get_service_group().encode(s)));
             try!(s.emit_struct_field("package", 2, |s| self.get_package_ident().encode(s)));
@@ -128,6 +119,12 @@ impl Encodable for swim::Service {
             try!(s.emit_struct_field("port", 6, |s| self.get_port().encode(s)));
             try!(s.emit_struct_field("exposes", 7, |s| self.get_exposes().encode(s)));
             try!(s.emit_struct_field("initialized", 8, |s| self.get_initialized().encode(s)));
+            try!(s.emit_struct_field("health", 9, |s| (self.get_health() as usize).encode(s)));
+            try!(s.emit_struct_field("suitability", 10, |s| self.get_suitability().encode(s)));
+            match str::from_utf8(self.get_cfg()) {
+                Ok(c) => try!(s.emit_struct_field("cfg", 11, |s| c.encode(s))),
+                Err(_) => try!(s.emit_struct_field("cfg", 11, |s| self.get_cfg().encode(s))),
+            }
             Ok(())
         }));
         Ok(())
@@ -1137,6 +1137,7 @@ pub struct Membership {
     // message fields
     member: ::protobuf::SingularPtrField<Member>,
     health: ::std::option::Option<Membership_Health>,
+    suspected_by: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -1160,6 +1161,7 @@ impl Membership {
                 Membership {
                     member: ::protobuf::SingularPtrField::none(),
                     health: ::std::option::Option::None,
+                    suspected_by: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1218,6 +1220,42 @@ impl Membership {
     pub fn get_health(&self) -> Membership_Health {
         self.health.unwrap_or(Membership_Health::ALIVE)
     }
+
+    // optional string suspected_by = 3;
+
+    pub fn clear_suspected_by(&mut self) {
+        self.suspected_by.clear();
+    }
+
+    pub fn has_suspected_by(&self) -> bool {
+        self.suspected_by.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suspected_by(&mut self, v: ::std::string::String) {
+        self.suspected_by = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_suspected_by(&mut self) -> &mut ::std::string::String {
+        if self.suspected_by.is_none() {
+            self.suspected_by.set_default();
+        };
+        self.suspected_by.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_suspected_by(&mut self) -> ::std::string::String {
+        self.suspected_by.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_suspected_by(&self) -> &str {
+        match self.suspected_by.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
 impl ::protobuf::Message for Membership {
@@ -1239,6 +1277,9 @@ impl ::protobuf::Message for Membership {
                     let tmp = try!(is.read_enum());
                     self.health = ::std::option::Option::Some(tmp);
                 },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.suspected_by));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -1258,6 +1299,9 @@ impl ::protobuf::Message for Membership {
         for value in &self.health {
             my_size += ::protobuf::rt::enum_size(2, *value);
         };
+        for value in &self.suspected_by {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1272,6 +1316,9 @@ impl ::protobuf::Message for Membership {
         if let Some(v) = self.health {
             try!(os.write_enum(2, v.value()));
         };
+        if let Some(v) = self.suspected_by.as_ref() {
+            try!(os.write_string(3, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1324,6 +1371,11 @@ impl ::protobuf::MessageStatic for Membership {
                     Membership::has_health,
                     Membership::get_health,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "suspected_by",
+                    Membership::has_suspected_by,
+                    Membership::get_suspected_by,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Membership>(
                     "Membership",
                     fields,
@@ -1338,6 +1390,7 @@ impl ::protobuf::Clear for Membership {
     fn clear(&mut self) {
         self.clear_member();
         self.clear_health();
+        self.clear_suspected_by();
         self.unknown_fields.clear();
     }
 }
@@ -1346,6 +1399,7 @@ impl ::std::cmp::PartialEq for Membership {
     fn eq(&self, other: &Membership) -> bool {
         self.member == other.member &&
         self.health == other.health &&
+        self.suspected_by == other.suspected_by &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1361,6 +1415,7 @@ pub enum Membership_Health {
     ALIVE = 1,
     SUSPECT = 2,
     CONFIRMED = 3,
+    DEPARTED = 4,
 }
 
 impl ::protobuf::ProtobufEnum for Membership_Health {
@@ -1373,6 +1428,7 @@ impl ::protobuf::ProtobufEnum for Membership_Health {
             1 => ::std::option::Option::Some(Membership_Health::ALIVE),
             2 => ::std::option::Option::Some(Membership_Health::SUSPECT),
             3 => ::std::option::Option::Some(Membership_Health::CONFIRMED),
+            4 => ::std::option::Option::Some(Membership_Health::DEPARTED),
             _ => ::std::option::Option::None
         }
     }
@@ -1382,6 +1438,7 @@ impl ::protobuf::ProtobufEnum for Membership_Health {
             Membership_Health::ALIVE,
             Membership_Health::SUSPECT,
             Membership_Health::CONFIRMED,
+            Membership_Health::DEPARTED,
         ];
         values
     }
@@ -1862,6 +1919,9 @@ pub struct Service {
     exposes: ::std::vec::Vec<u32>,
     initialized: ::std::option::Option<bool>,
     package_ident: ::protobuf::SingularField<::std::string::String>,
+    health: ::std::option::Option<Service_Health>,
+    suitability: ::std::option::Option<u64>,
+    cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -1892,6 +1952,9 @@ impl Service {
                     exposes: ::std::vec::Vec::new(),
                     initialized: ::std::option::Option::None,
                     package_ident: ::protobuf::SingularField::none(),
+                    health: ::std::option::Option::None,
+                    suitability: ::std::option::Option::None,
+                    cfg: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2160,6 +2223,80 @@ impl Service {
             None => "",
         }
     }
+
+    // optional .Service.Health health = 10;
+
+    pub fn clear_health(&mut self) {
+        self.health = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        self.health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: Service_Health) {
+        self.health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health(&self) -> Service_Health {
+        self.health.unwrap_or(Service_Health::UNKNOWN)
+    }
+
+    // optional uint64 suitability = 11;
+
+    pub fn clear_suitability(&mut self) {
+        self.suitability = ::std::option::Option::None;
+    }
+
+    pub fn has_suitability(&self) -> bool {
+        self.suitability.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suitability(&mut self, v: u64) {
+        self.suitability = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_suitability(&self) -> u64 {
+        self.suitability.unwrap_or(0)
+    }
+
+    // optional bytes cfg = 12;
+
+    pub fn clear_cfg(&mut self) {
+        self.cfg.clear();
+    }
+
+    pub fn has_cfg(&self) -> bool {
+        self.cfg.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cfg(&mut self, v: ::std::vec::Vec<u8>) {
+        self.cfg = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cfg(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.cfg.is_none() {
+            self.cfg.set_default();
+        };
+        self.cfg.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cfg(&mut self) -> ::std::vec::Vec<u8> {
+        self.cfg.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_cfg(&self) -> &[u8] {
+        match self.cfg.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2210,6 +2347,23 @@ impl ::protobuf::Message for Service {
                 9 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package_ident));
                 },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.health = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.suitability = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.cfg));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2249,6 +2403,15 @@ impl ::protobuf::Message for Service {
         for value in &self.package_ident {
             my_size += ::protobuf::rt::string_size(9, &value);
         };
+        for value in &self.health {
+            my_size += ::protobuf::rt::enum_size(10, *value);
+        };
+        for value in &self.suitability {
+            my_size += ::protobuf::rt::value_size(11, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.cfg {
+            my_size += ::protobuf::rt::bytes_size(12, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2282,6 +2445,15 @@ impl ::protobuf::Message for Service {
         if let Some(v) = self.package_ident.as_ref() {
             try!(os.write_string(9, &v));
         };
+        if let Some(v) = self.health {
+            try!(os.write_enum(10, v.value()));
+        };
+        if let Some(v) = self.suitability {
+            try!(os.write_uint64(11, v));
+        };
+        if let Some(v) = self.cfg.as_ref() {
+            try!(os.write_bytes(12, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2368,6 +2540,21 @@ impl ::protobuf::MessageStatic for Service {
                     Service::has_package_ident,
                     Service::get_package_ident,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "health",
+                    Service::has_health,
+                    Service::get_health,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "suitability",
+                    Service::has_suitability,
+                    Service::get_suitability,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "cfg",
+                    Service::has_cfg,
+                    Service::get_cfg,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2389,6 +2576,9 @@ impl ::protobuf::Clear for Service {
         self.clear_exposes();
         self.clear_initialized();
         self.clear_package_ident();
+        self.clear_health();
+        self.clear_suitability();
+        self.clear_cfg();
         self.unknown_fields.clear();
     }
 }
@@ -2404,6 +2594,9 @@ impl ::std::cmp::PartialEq for Service {
         self.exposes == other.exposes &&
         self.initialized == other.initialized &&
         self.package_ident == other.package_ident &&
+        self.health == other.health &&
+        self.suitability == other.suitability &&
+        self.cfg == other.cfg &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -2414,6 +2607,55 @@ impl ::std::fmt::Debug for Service {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Service_Health {
+    OK = 1,
+    WARNING = 2,
+    CRITICAL = 3,
+    UNKNOWN = 4,
+}
+
+impl ::protobuf::ProtobufEnum for Service_Health {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Service_Health> {
+        match value {
+            1 => ::std::option::Option::Some(Service_Health::OK),
+            2 => ::std::option::Option::Some(Service_Health::WARNING),
+            3 => ::std::option::Option::Some(Service_Health::CRITICAL),
+            4 => ::std::option::Option::Some(Service_Health::UNKNOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Service_Health] = &[
+            Service_Health::OK,
+            Service_Health::WARNING,
+            Service_Health::CRITICAL,
+            Service_Health::UNKNOWN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Service_Health>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Service_Health", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Service_Health {
+}
+
 #[derive(Clone,Default)]
 pub struct ServiceConfig {
     // message fields
@@ -3107,43 +3349,33 @@ impl ::std::fmt::Debug for ServiceFile {
 }
 
 #[derive(Clone,Default)]
-pub struct Swim {
+pub struct Departure {
     // message fields
-    field_type: ::std::option::Option<Swim_Type>,
-    membership: ::protobuf::RepeatedField<Membership>,
-    // message oneof groups
-    payload: ::std::option::Option<Swim_oneof_payload>,
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Swim {}
-
-#[derive(Clone,PartialEq)]
-pub enum Swim_oneof_payload {
-    ping(Ping),
-    ack(Ack),
-    pingreq(PingReq),
-}
+unsafe impl ::std::marker::Sync for Departure {}
 
-impl Swim {
-    pub fn new() -> Swim {
+impl Departure {
+    pub fn new() -> Departure {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Swim {
-        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static Departure {
+        static mut instance: ::protobuf::lazy::Lazy<Departure> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Swim,
+            ptr: 0 as *const Departure,
         };
         unsafe {
             instance.get(|| {
-                Swim {
-                    field_type: ::std::option::Option::None,
-                    membership: ::protobuf::RepeatedField::new(),
-                    payload: ::std::option::Option::None,
+                Departure {
+                    member_id: ::protobuf::SingularField::none(),
+                    incarnation: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3151,73 +3383,308 @@ impl Swim {
         }
     }
 
-    // required .Swim.Type type = 1;
+    // optional string member_id = 1;
 
-    pub fn clear_field_type(&mut self) {
-        self.field_type = ::std::option::Option::None;
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
     }
 
-    pub fn has_field_type(&self) -> bool {
-        self.field_type.is_some()
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: Swim_Type) {
-        self.field_type = ::std::option::Option::Some(v);
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_field_type(&self) -> Swim_Type {
-        self.field_type.unwrap_or(Swim_Type::PING)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
     }
 
-    // optional .Ping ping = 2;
-
-    pub fn clear_ping(&mut self) {
-        self.payload = ::std::option::Option::None;
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn has_ping(&self) -> bool {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(..)) => true,
-            _ => false,
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // Param is passed by value, moved
-    pub fn set_ping(&mut self, v: Ping) {
-        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(v))
+    // optional uint64 incarnation = 2;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ping(&mut self) -> &mut Ping {
-        if let ::std::option::Option::Some(Swim_oneof_payload::ping(_)) = self.payload {
-        } else {
-            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(Ping::new()));
-        }
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
     }
 
-    // Take field
-    pub fn take_ping(&mut self) -> Ping {
-        if self.has_ping() {
-            match self.payload.take() {
-                ::std::option::Option::Some(Swim_oneof_payload::ping(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ping::new()
-        }
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
     }
 
-    pub fn get_ping(&self) -> &Ping {
-        match self.payload {
-            ::std::option::Option::Some(Swim_oneof_payload::ping(ref v)) => v,
-            _ => Ping::default_instance(),
-        }
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for Departure {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.member_id {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.member_id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Departure>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Departure {
+    fn new() -> Departure {
+        Departure::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Departure>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "member_id",
+                    Departure::has_member_id,
+                    Departure::get_member_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    Departure::has_incarnation,
+                    Departure::get_incarnation,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Departure>(
+                    "Departure",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Departure {
+    fn clear(&mut self) {
+        self.clear_member_id();
+        self.clear_incarnation();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Departure {
+    fn eq(&self, other: &Departure) -> bool {
+        self.member_id == other.member_id &&
+        self.incarnation == other.incarnation &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Departure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Swim {
+    // message fields
+    field_type: ::std::option::Option<Swim_Type>,
+    membership: ::protobuf::RepeatedField<Membership>,
+    // message oneof groups
+    payload: ::std::option::Option<Swim_oneof_payload>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Swim {}
+
+#[derive(Clone,PartialEq)]
+pub enum Swim_oneof_payload {
+    ping(Ping),
+    ack(Ack),
+    pingreq(PingReq),
+    nack(Nack),
+}
+
+impl Swim {
+    pub fn new() -> Swim {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Swim {
+        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Swim,
+        };
+        unsafe {
+            instance.get(|| {
+                Swim {
+                    field_type: ::std::option::Option::None,
+                    membership: ::protobuf::RepeatedField::new(),
+                    payload: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required .Swim.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Swim_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Swim_Type {
+        self.field_type.unwrap_or(Swim_Type::PING)
+    }
+
+    // optional .Ping ping = 2;
+
+    pub fn clear_ping(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ping(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: Ping) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ping(&mut self) -> &mut Ping {
+        if let ::std::option::Option::Some(Swim_oneof_payload::ping(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::ping(Ping::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ping(&mut self) -> Ping {
+        if self.has_ping() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::ping(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ping::new()
+        }
+    }
+
+    pub fn get_ping(&self) -> &Ping {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::ping(ref v)) => v,
+            _ => Ping::default_instance(),
+        }
     }
 
     // optional .Ack ack = 3;
@@ -3320,29 +3787,79 @@ impl Swim {
         }
     }
 
-    // repeated .Membership membership = 5;
+    // optional .Nack nack = 6;
 
-    pub fn clear_membership(&mut self) {
-        self.membership.clear();
+    pub fn clear_nack(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_nack(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::nack(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
-    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
-        self.membership = v;
+    pub fn set_nack(&mut self, v: Nack) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::nack(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
-        &mut self.membership
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nack(&mut self) -> &mut Nack {
+        if let ::std::option::Option::Some(Swim_oneof_payload::nack(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::nack(Nack::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::nack(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
     // Take field
-    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
-        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_membership(&self) -> &[Membership] {
-        &self.membership
+    pub fn take_nack(&mut self) -> Nack {
+        if self.has_nack() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::nack(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Nack::new()
+        }
+    }
+
+    pub fn get_nack(&self) -> &Nack {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::nack(ref v)) => v,
+            _ => Nack::default_instance(),
+        }
+    }
+
+    // repeated .Membership membership = 5;
+
+    pub fn clear_membership(&mut self) {
+        self.membership.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
+        self.membership = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
+        &mut self.membership
+    }
+
+    // Take field
+    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
+        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_membership(&self) -> &[Membership] {
+        &self.membership
     }
 }
 
@@ -3386,6 +3903,12 @@ impl ::protobuf::Message for Swim {
                 5 => {
                     try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership));
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::nack(try!(is.read_message())));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3419,6 +3942,10 @@ impl ::protobuf::Message for Swim {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Swim_oneof_payload::nack(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -3452,6 +3979,11 @@ impl ::protobuf::Message for Swim {
                     try!(os.write_raw_varint32(v.get_cached_size()));
                     try!(v.write_to_with_cached_sizes(os));
                 },
+                &Swim_oneof_payload::nack(ref v) => {
+                    try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
             };
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -3516,6 +4048,11 @@ impl ::protobuf::MessageStatic for Swim {
                     Swim::has_pingreq,
                     Swim::get_pingreq,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "nack",
+                    Swim::has_nack,
+                    Swim::get_nack,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
                     "membership",
                     Swim::get_membership,
@@ -3536,6 +4073,7 @@ impl ::protobuf::Clear for Swim {
         self.clear_ping();
         self.clear_ack();
         self.clear_pingreq();
+        self.clear_nack();
         self.clear_membership();
         self.unknown_fields.clear();
     }
@@ -3561,6 +4099,7 @@ pub enum Swim_Type {
     PING = 1,
     ACK = 2,
     PINGREQ = 3,
+    NACK = 4,
 }
 
 impl ::protobuf::ProtobufEnum for Swim_Type {
@@ -3573,6 +4112,7 @@ impl ::protobuf::ProtobufEnum for Swim_Type {
             1 => ::std::option::Option::Some(Swim_Type::PING),
             2 => ::std::option::Option::Some(Swim_Type::ACK),
             3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
+            4 => ::std::option::Option::Some(Swim_Type::NACK),
             _ => ::std::option::Option::None
         }
     }
@@ -3582,6 +4122,7 @@ impl ::protobuf::ProtobufEnum for Swim_Type {
             Swim_Type::PING,
             Swim_Type::ACK,
             Swim_Type::PINGREQ,
+            Swim_Type::NACK,
         ];
         values
     }
@@ -3625,6 +4166,8 @@ pub enum Rumor_oneof_payload {
     service_config(ServiceConfig),
     service_file(ServiceFile),
     election(Election),
+    departure(Departure),
+    tombstone(Tombstone),
 }
 
 impl Rumor {
@@ -3980,6 +4523,106 @@ impl Rumor {
             _ => Election::default_instance(),
         }
     }
+
+    // optional .Departure departure = 9;
+
+    pub fn clear_departure(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_departure(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_departure(&mut self, v: Departure) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_departure(&mut self) -> &mut Departure {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::departure(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(Departure::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_departure(&mut self) -> Departure {
+        if self.has_departure() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::departure(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Departure::new()
+        }
+    }
+
+    pub fn get_departure(&self) -> &Departure {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref v)) => v,
+            _ => Departure::default_instance(),
+        }
+    }
+
+    // optional .Tombstone tombstone = 10;
+
+    pub fn clear_tombstone(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_tombstone(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::tombstone(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tombstone(&mut self, v: Tombstone) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::tombstone(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tombstone(&mut self) -> &mut Tombstone {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::tombstone(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::tombstone(Tombstone::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::tombstone(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tombstone(&mut self) -> Tombstone {
+        if self.has_tombstone() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::tombstone(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Tombstone::new()
+        }
+    }
+
+    pub fn get_tombstone(&self) -> &Tombstone {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::tombstone(ref v)) => v,
+            _ => Tombstone::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for Rumor {
@@ -4037,6 +4680,18 @@ impl ::protobuf::Message for Rumor {
                     };
                     self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(try!(is.read_message())));
                 },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(try!(is.read_message())));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::tombstone(try!(is.read_message())));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -4080,6 +4735,14 @@ impl ::protobuf::Message for Rumor {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Rumor_oneof_payload::departure(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::tombstone(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -4124,6 +4787,16 @@ impl ::protobuf::Message for Rumor {
                     try!(os.write_raw_varint32(v.get_cached_size()));
                     try!(v.write_to_with_cached_sizes(os));
                 },
+                &Rumor_oneof_payload::departure(ref v) => {
+                    try!(os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &Rumor_oneof_payload::tombstone(ref v) => {
+                    try!(os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
             };
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -4207,6 +4880,16 @@ impl ::protobuf::MessageStatic for Rumor {
                     Rumor::has_election,
                     Rumor::get_election,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "departure",
+                    Rumor::has_departure,
+                    Rumor::get_departure,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "tombstone",
+                    Rumor::has_tombstone,
+                    Rumor::get_tombstone,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
                     "Rumor",
                     fields,
@@ -4227,6 +4910,8 @@ impl ::protobuf::Clear for Rumor {
         self.clear_service_config();
         self.clear_service_file();
         self.clear_election();
+        self.clear_departure();
+        self.clear_tombstone();
         self.unknown_fields.clear();
     }
 }
@@ -4257,6 +4942,8 @@ pub enum Rumor_Type {
     Fake = 6,
     Fake2 = 7,
     ElectionUpdate = 8,
+    Departure = 9,
+    Tombstone = 10,
 }
 
 impl ::protobuf::ProtobufEnum for Rumor_Type {
@@ -4274,70 +4961,1242 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             6 => ::std::option::Option::Some(Rumor_Type::Fake),
             7 => ::std::option::Option::Some(Rumor_Type::Fake2),
             8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
+            9 => ::std::option::Option::Some(Rumor_Type::Departure),
+            10 => ::std::option::Option::Some(Rumor_Type::Tombstone),
             _ => ::std::option::Option::None
         }
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [Rumor_Type] = &[
-            Rumor_Type::Member,
-            Rumor_Type::Service,
-            Rumor_Type::Election,
-            Rumor_Type::ServiceConfig,
-            Rumor_Type::ServiceFile,
-            Rumor_Type::Fake,
-            Rumor_Type::Fake2,
-            Rumor_Type::ElectionUpdate,
-        ];
-        values
+    fn values() -> &'static [Self] {
+        static values: &'static [Rumor_Type] = &[
+            Rumor_Type::Member,
+            Rumor_Type::Service,
+            Rumor_Type::Election,
+            Rumor_Type::ServiceConfig,
+            Rumor_Type::ServiceFile,
+            Rumor_Type::Fake,
+            Rumor_Type::Fake2,
+            Rumor_Type::ElectionUpdate,
+            Rumor_Type::Departure,
+            Rumor_Type::Tombstone,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Rumor_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Rumor_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Rumor_Type {
+}
+
+#[derive(Clone,Default)]
+pub struct Wire {
+    // message fields
+    encrypted: ::std::option::Option<bool>,
+    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Wire {}
+
+impl Wire {
+    pub fn new() -> Wire {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Wire {
+        static mut instance: ::protobuf::lazy::Lazy<Wire> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Wire,
+        };
+        unsafe {
+            instance.get(|| {
+                Wire {
+                    encrypted: ::std::option::Option::None,
+                    nonce: ::protobuf::SingularField::none(),
+                    payload: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional bool encrypted = 1;
+
+    pub fn clear_encrypted(&mut self) {
+        self.encrypted = ::std::option::Option::None;
+    }
+
+    pub fn has_encrypted(&self) -> bool {
+        self.encrypted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted(&mut self, v: bool) {
+        self.encrypted = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_encrypted(&self) -> bool {
+        self.encrypted.unwrap_or(false)
+    }
+
+    // optional bytes nonce = 2;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    pub fn has_nonce(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.nonce.is_none() {
+            self.nonce.set_default();
+        };
+        self.nonce.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // optional bytes payload = 3;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        };
+        self.payload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        match self.payload.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for Wire {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.encrypted = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.encrypted.is_some() {
+            my_size += 2;
+        };
+        for value in &self.nonce {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        for value in &self.payload {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.encrypted {
+            try!(os.write_bool(1, v));
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            try!(os.write_bytes(2, &v));
+        };
+        if let Some(v) = self.payload.as_ref() {
+            try!(os.write_bytes(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Wire>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Wire {
+    fn new() -> Wire {
+        Wire::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Wire>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "encrypted",
+                    Wire::has_encrypted,
+                    Wire::get_encrypted,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "nonce",
+                    Wire::has_nonce,
+                    Wire::get_nonce,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "payload",
+                    Wire::has_payload,
+                    Wire::get_payload,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Wire>(
+                    "Wire",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Wire {
+    fn clear(&mut self) {
+        self.clear_encrypted();
+        self.clear_nonce();
+        self.clear_payload();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Wire {
+    fn eq(&self, other: &Wire) -> bool {
+        self.encrypted == other.encrypted &&
+        self.nonce == other.nonce &&
+        self.payload == other.payload &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Wire {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct RumorDigest {
+    // message fields
+    field_type: ::std::option::Option<Rumor_Type>,
+    key: ::protobuf::SingularField<::std::string::String>,
+    id: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    checksum: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RumorDigest {}
+
+impl RumorDigest {
+    pub fn new() -> RumorDigest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RumorDigest {
+        static mut instance: ::protobuf::lazy::Lazy<RumorDigest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RumorDigest,
+        };
+        unsafe {
+            instance.get(|| {
+                RumorDigest {
+                    field_type: ::std::option::Option::None,
+                    key: ::protobuf::SingularField::none(),
+                    id: ::protobuf::SingularField::none(),
+                    incarnation: ::std::option::Option::None,
+                    checksum: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required .Rumor.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Rumor_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Rumor_Type {
+        self.field_type.unwrap_or(Rumor_Type::Member)
+    }
+
+    // optional string key = 2;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
+        };
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string id = 3;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 incarnation = 4;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    // optional string checksum = 5;
+
+    pub fn clear_checksum(&mut self) {
+        self.checksum.clear();
+    }
+
+    pub fn has_checksum(&self) -> bool {
+        self.checksum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checksum(&mut self, v: ::std::string::String) {
+        self.checksum = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checksum(&mut self) -> &mut ::std::string::String {
+        if self.checksum.is_none() {
+            self.checksum.set_default();
+        };
+        self.checksum.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_checksum(&mut self) -> ::std::string::String {
+        self.checksum.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_checksum(&self) -> &str {
+        match self.checksum.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for RumorDigest {
+    fn is_initialized(&self) -> bool {
+        if self.field_type.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.field_type = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.checksum));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.field_type {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in &self.key {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.id {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.incarnation {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.checksum {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_type {
+            try!(os.write_enum(1, v.value()));
+        };
+        if let Some(v) = self.key.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.checksum.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<RumorDigest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RumorDigest {
+    fn new() -> RumorDigest {
+        RumorDigest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RumorDigest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "type",
+                    RumorDigest::has_field_type,
+                    RumorDigest::get_field_type,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key",
+                    RumorDigest::has_key,
+                    RumorDigest::get_key,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    RumorDigest::has_id,
+                    RumorDigest::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    RumorDigest::has_incarnation,
+                    RumorDigest::get_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "checksum",
+                    RumorDigest::has_checksum,
+                    RumorDigest::get_checksum,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RumorDigest>(
+                    "RumorDigest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RumorDigest {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_key();
+        self.clear_id();
+        self.clear_incarnation();
+        self.clear_checksum();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for RumorDigest {
+    fn eq(&self, other: &RumorDigest) -> bool {
+        self.field_type == other.field_type &&
+        self.key == other.key &&
+        self.id == other.id &&
+        self.incarnation == other.incarnation &&
+        self.checksum == other.checksum &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for RumorDigest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct PushPull {
+    // message fields
+    digests: ::protobuf::RepeatedField<RumorDigest>,
+    rumors: ::protobuf::RepeatedField<Rumor>,
+    from_id: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PushPull {}
+
+impl PushPull {
+    pub fn new() -> PushPull {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PushPull {
+        static mut instance: ::protobuf::lazy::Lazy<PushPull> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PushPull,
+        };
+        unsafe {
+            instance.get(|| {
+                PushPull {
+                    digests: ::protobuf::RepeatedField::new(),
+                    rumors: ::protobuf::RepeatedField::new(),
+                    from_id: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .RumorDigest digests = 1;
+
+    pub fn clear_digests(&mut self) {
+        self.digests.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digests(&mut self, v: ::protobuf::RepeatedField<RumorDigest>) {
+        self.digests = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_digests(&mut self) -> &mut ::protobuf::RepeatedField<RumorDigest> {
+        &mut self.digests
+    }
+
+    // Take field
+    pub fn take_digests(&mut self) -> ::protobuf::RepeatedField<RumorDigest> {
+        ::std::mem::replace(&mut self.digests, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_digests(&self) -> &[RumorDigest] {
+        &self.digests
+    }
+
+    // repeated .Rumor rumors = 2;
+
+    pub fn clear_rumors(&mut self) {
+        self.rumors.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rumors(&mut self, v: ::protobuf::RepeatedField<Rumor>) {
+        self.rumors = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rumors(&mut self) -> &mut ::protobuf::RepeatedField<Rumor> {
+        &mut self.rumors
+    }
+
+    // Take field
+    pub fn take_rumors(&mut self) -> ::protobuf::RepeatedField<Rumor> {
+        ::std::mem::replace(&mut self.rumors, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_rumors(&self) -> &[Rumor] {
+        &self.rumors
+    }
+
+    // optional string from_id = 3;
+
+    pub fn clear_from_id(&mut self) {
+        self.from_id.clear();
+    }
+
+    pub fn has_from_id(&self) -> bool {
+        self.from_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_id(&mut self, v: ::std::string::String) {
+        self.from_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_id(&mut self) -> &mut ::std::string::String {
+        if self.from_id.is_none() {
+            self.from_id.set_default();
+        };
+        self.from_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_from_id(&mut self) -> ::std::string::String {
+        self.from_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_from_id(&self) -> &str {
+        match self.from_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for PushPull {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.digests));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rumors));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.from_id));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.digests {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.rumors {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.from_id {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.digests {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        for v in &self.rumors {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.from_id.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<PushPull>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PushPull {
+    fn new() -> PushPull {
+        PushPull::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PushPull>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "digests",
+                    PushPull::get_digests,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "rumors",
+                    PushPull::get_rumors,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "from_id",
+                    PushPull::has_from_id,
+                    PushPull::get_from_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PushPull>(
+                    "PushPull",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PushPull {
+    fn clear(&mut self) {
+        self.clear_digests();
+        self.clear_rumors();
+        self.clear_from_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for PushPull {
+    fn eq(&self, other: &PushPull) -> bool {
+        self.digests == other.digests &&
+        self.rumors == other.rumors &&
+        self.from_id == other.from_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for PushPull {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Nack {
+    // message fields
+    from: ::protobuf::SingularPtrField<Member>,
+    target: ::protobuf::SingularPtrField<Member>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Nack {}
+
+impl Nack {
+    pub fn new() -> Nack {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Nack {
+        static mut instance: ::protobuf::lazy::Lazy<Nack> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Nack,
+        };
+        unsafe {
+            instance.get(|| {
+                Nack {
+                    from: ::protobuf::SingularPtrField::none(),
+                    target: ::protobuf::SingularPtrField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional .Member from = 1;
+
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    pub fn has_from(&self) -> bool {
+        self.from.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: Member) {
+        self.from = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut Member {
+        if self.from.is_none() {
+            self.from.set_default();
+        };
+        self.from.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> Member {
+        self.from.take().unwrap_or_else(|| Member::new())
+    }
+
+    pub fn get_from(&self) -> &Member {
+        self.from.as_ref().unwrap_or_else(|| Member::default_instance())
+    }
+
+    // optional .Member target = 2;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: Member) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut Member {
+        if self.target.is_none() {
+            self.target.set_default();
+        };
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> Member {
+        self.target.take().unwrap_or_else(|| Member::new())
+    }
+
+    pub fn get_target(&self) -> &Member {
+        self.target.as_ref().unwrap_or_else(|| Member::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Nack {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.from));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.from {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.target {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.from.as_ref() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.target.as_ref() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Nack>()
     }
 
-    fn enum_descriptor_static(_: Option<Rumor_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Nack {
+    fn new() -> Nack {
+        Nack::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Nack>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Rumor_Type", file_descriptor_proto())
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "from",
+                    Nack::has_from,
+                    Nack::get_from,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "target",
+                    Nack::has_target,
+                    Nack::get_target,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Nack>(
+                    "Nack",
+                    fields,
+                    file_descriptor_proto()
+                )
             })
         }
     }
 }
 
-impl ::std::marker::Copy for Rumor_Type {
+impl ::protobuf::Clear for Nack {
+    fn clear(&mut self) {
+        self.clear_from();
+        self.clear_target();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Nack {
+    fn eq(&self, other: &Nack) -> bool {
+        self.from == other.from &&
+        self.target == other.target &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Nack {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
 }
 
 #[derive(Clone,Default)]
-pub struct Wire {
+pub struct Tombstone {
     // message fields
-    encrypted: ::std::option::Option<bool>,
-    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    field_type: ::std::option::Option<Rumor_Type>,
+    key: ::protobuf::SingularField<::std::string::String>,
+    id: ::protobuf::SingularField<::std::string::String>,
+    version: ::std::option::Option<u64>,
+    ttl_ms: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Wire {}
+unsafe impl ::std::marker::Sync for Tombstone {}
 
-impl Wire {
-    pub fn new() -> Wire {
+impl Tombstone {
+    pub fn new() -> Tombstone {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Wire {
-        static mut instance: ::protobuf::lazy::Lazy<Wire> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static Tombstone {
+        static mut instance: ::protobuf::lazy::Lazy<Tombstone> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Wire,
+            ptr: 0 as *const Tombstone,
         };
         unsafe {
             instance.get(|| {
-                Wire {
-                    encrypted: ::std::option::Option::None,
-                    nonce: ::protobuf::SingularField::none(),
-                    payload: ::protobuf::SingularField::none(),
+                Tombstone {
+                    field_type: ::std::option::Option::None,
+                    key: ::protobuf::SingularField::none(),
+                    id: ::protobuf::SingularField::none(),
+                    version: ::std::option::Option::None,
+                    ttl_ms: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4345,100 +6204,141 @@ impl Wire {
         }
     }
 
-    // optional bool encrypted = 1;
+    // required .Rumor.Type type = 1;
 
-    pub fn clear_encrypted(&mut self) {
-        self.encrypted = ::std::option::Option::None;
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
     }
 
-    pub fn has_encrypted(&self) -> bool {
-        self.encrypted.is_some()
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_encrypted(&mut self, v: bool) {
-        self.encrypted = ::std::option::Option::Some(v);
+    pub fn set_field_type(&mut self, v: Rumor_Type) {
+        self.field_type = ::std::option::Option::Some(v);
     }
 
-    pub fn get_encrypted(&self) -> bool {
-        self.encrypted.unwrap_or(false)
+    pub fn get_field_type(&self) -> Rumor_Type {
+        self.field_type.unwrap_or(Rumor_Type::Member)
     }
 
-    // optional bytes nonce = 2;
+    // optional string key = 2;
 
-    pub fn clear_nonce(&mut self) {
-        self.nonce.clear();
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
-    pub fn has_nonce(&self) -> bool {
-        self.nonce.is_some()
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
-        self.nonce = ::protobuf::SingularField::some(v);
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.nonce.is_none() {
-            self.nonce.set_default();
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
         };
-        self.nonce.as_mut().unwrap()
+        self.key.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
-        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_nonce(&self) -> &[u8] {
-        match self.nonce.as_ref() {
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
             Some(v) => &v,
-            None => &[],
+            None => "",
         }
     }
 
-    // optional bytes payload = 3;
+    // optional string id = 3;
 
-    pub fn clear_payload(&mut self) {
-        self.payload.clear();
+    pub fn clear_id(&mut self) {
+        self.id.clear();
     }
 
-    pub fn has_payload(&self) -> bool {
-        self.payload.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
-        self.payload = ::protobuf::SingularField::some(v);
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.payload.is_none() {
-            self.payload.set_default();
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
         };
-        self.payload.as_mut().unwrap()
+        self.id.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
-        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_payload(&self) -> &[u8] {
-        match self.payload.as_ref() {
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
             Some(v) => &v,
-            None => &[],
+            None => "",
         }
     }
+
+    // optional uint64 version = 4;
+
+    pub fn clear_version(&mut self) {
+        self.version = ::std::option::Option::None;
+    }
+
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_version(&self) -> u64 {
+        self.version.unwrap_or(0)
+    }
+
+    // optional uint64 ttl_ms = 5;
+
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_ttl_ms(&self) -> bool {
+        self.ttl_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms.unwrap_or(0)
+    }
 }
 
-impl ::protobuf::Message for Wire {
+impl ::protobuf::Message for Tombstone {
     fn is_initialized(&self) -> bool {
+        if self.field_type.is_none() {
+            return false;
+        };
         true
     }
 
@@ -4450,14 +6350,28 @@ impl ::protobuf::Message for Wire {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_bool());
-                    self.encrypted = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_enum());
+                    self.field_type = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key));
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.version = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.ttl_ms = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -4471,14 +6385,20 @@ impl ::protobuf::Message for Wire {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.encrypted.is_some() {
-            my_size += 2;
+        for value in &self.field_type {
+            my_size += ::protobuf::rt::enum_size(1, *value);
         };
-        for value in &self.nonce {
-            my_size += ::protobuf::rt::bytes_size(2, &value);
+        for value in &self.key {
+            my_size += ::protobuf::rt::string_size(2, &value);
         };
-        for value in &self.payload {
-            my_size += ::protobuf::rt::bytes_size(3, &value);
+        for value in &self.id {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.version {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.ttl_ms {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4486,14 +6406,20 @@ impl ::protobuf::Message for Wire {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.encrypted {
-            try!(os.write_bool(1, v));
+        if let Some(v) = self.field_type {
+            try!(os.write_enum(1, v.value()));
         };
-        if let Some(v) = self.nonce.as_ref() {
-            try!(os.write_bytes(2, &v));
+        if let Some(v) = self.key.as_ref() {
+            try!(os.write_string(2, &v));
         };
-        if let Some(v) = self.payload.as_ref() {
-            try!(os.write_bytes(3, &v));
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.version {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.ttl_ms {
+            try!(os.write_uint64(5, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -4512,7 +6438,7 @@ impl ::protobuf::Message for Wire {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Wire>()
+        ::std::any::TypeId::of::<Tombstone>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4524,12 +6450,12 @@ impl ::protobuf::Message for Wire {
     }
 }
 
-impl ::protobuf::MessageStatic for Wire {
-    fn new() -> Wire {
-        Wire::new()
+impl ::protobuf::MessageStatic for Tombstone {
+    fn new() -> Tombstone {
+        Tombstone::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Wire>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Tombstone>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4537,23 +6463,33 @@ impl ::protobuf::MessageStatic for Wire {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "encrypted",
-                    Wire::has_encrypted,
-                    Wire::get_encrypted,
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "type",
+                    Tombstone::has_field_type,
+                    Tombstone::get_field_type,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "nonce",
-                    Wire::has_nonce,
-                    Wire::get_nonce,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key",
+                    Tombstone::has_key,
+                    Tombstone::get_key,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "payload",
-                    Wire::has_payload,
-                    Wire::get_payload,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    Tombstone::has_id,
+                    Tombstone::get_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Wire>(
-                    "Wire",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "version",
+                    Tombstone::has_version,
+                    Tombstone::get_version,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "ttl_ms",
+                    Tombstone::has_ttl_ms,
+                    Tombstone::get_ttl_ms,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Tombstone>(
+                    "Tombstone",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4562,25 +6498,29 @@ impl ::protobuf::MessageStatic for Wire {
     }
 }
 
-impl ::protobuf::Clear for Wire {
+impl ::protobuf::Clear for Tombstone {
     fn clear(&mut self) {
-        self.clear_encrypted();
-        self.clear_nonce();
-        self.clear_payload();
+        self.clear_field_type();
+        self.clear_key();
+        self.clear_id();
+        self.clear_version();
+        self.clear_ttl_ms();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Wire {
-    fn eq(&self, other: &Wire) -> bool {
-        self.encrypted == other.encrypted &&
-        self.nonce == other.nonce &&
-        self.payload == other.payload &&
+impl ::std::cmp::PartialEq for Tombstone {
+    fn eq(&self, other: &Tombstone) -> bool {
+        self.field_type == other.field_type &&
+        self.key == other.key &&
+        self.id == other.id &&
+        self.version == other.version &&
+        self.ttl_ms == other.ttl_ms &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for Wire {
+impl ::std::fmt::Debug for Tombstone {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
@@ -4607,399 +6547,442 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x12, 0x15, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18,
     0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x17,
     0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07,
-    0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x7a, 0x0a, 0x0a, 0x4d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x17, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x22,
-    0x0a, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x12,
-    0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2e, 0x48, 0x65, 0x61, 0x6c,
-    0x74, 0x68, 0x22, 0x2f, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05,
-    0x41, 0x4c, 0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45,
-    0x43, 0x54, 0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45,
-    0x44, 0x10, 0x03, 0x22, 0xbb, 0x01, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x12, 0x11, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x09, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67,
-    0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x74, 0x65,
-    0x72, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x73, 0x75, 0x69, 0x74,
-    0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x20, 0x0a,
-    0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e,
-    0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12,
-    0x0d, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x22, 0x31,
-    0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0b, 0x0a, 0x07, 0x52, 0x75, 0x6e, 0x6e,
-    0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x4e, 0x6f, 0x51, 0x75, 0x6f, 0x72, 0x75,
-    0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x10,
-    0x03, 0x22, 0xb1, 0x01, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x11, 0x0a,
-    0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-    0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75,
-    0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72,
-    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0a, 0x0a, 0x02,
-    0x69, 0x70, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x68, 0x6f, 0x73, 0x74,
-    0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x70, 0x6f,
-    0x72, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x0f, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f,
-    0x73, 0x65, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0d, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x69,
-    0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x12, 0x15,
-    0x0a, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18,
-    0x09, 0x20, 0x01, 0x28, 0x09, 0x22, 0x5e, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
-    0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a,
-    0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18,
-    0x03, 0x20, 0x01, 0x28, 0x08, 0x12, 0x0e, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18,
-    0x04, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x6c, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x46, 0x69, 0x6c, 0x65, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f,
-    0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69,
-    0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
-    0x12, 0x11, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x08, 0x12, 0x10, 0x0a, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-    0x04, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20,
-    0x01, 0x28, 0x0c, 0x22, 0xbd, 0x01, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x18, 0x0a, 0x04,
+    0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x9e, 0x01, 0x0a, 0x0a, 0x4d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x17, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12,
+    0x22, 0x0a, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x12, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2e, 0x48, 0x65, 0x61,
+    0x6c, 0x74, 0x68, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x75, 0x73, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64,
+    0x5f, 0x62, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x22, 0x3d, 0x0a, 0x06, 0x48, 0x65, 0x61,
+    0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c, 0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x0b,
+    0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54, 0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x43,
+    0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10, 0x03, 0x12, 0x0c, 0x0a, 0x08, 0x44, 0x45,
+    0x50, 0x41, 0x52, 0x54, 0x45, 0x44, 0x10, 0x04, 0x22, 0xbb, 0x01, 0x0a, 0x08, 0x45, 0x6c, 0x65,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x11, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12,
+    0x0c, 0x0a, 0x04, 0x74, 0x65, 0x72, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x13, 0x0a,
+    0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x04, 0x12, 0x20, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0e, 0x32, 0x10, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x53, 0x74,
+    0x61, 0x74, 0x75, 0x73, 0x12, 0x0d, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20,
+    0x03, 0x28, 0x09, 0x22, 0x31, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0b, 0x0a,
+    0x07, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x4e, 0x6f,
+    0x51, 0x75, 0x6f, 0x72, 0x75, 0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x69, 0x6e, 0x69,
+    0x73, 0x68, 0x65, 0x64, 0x10, 0x03, 0x22, 0xb7, 0x02, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b,
+    0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x04, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x70, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a,
+    0x08, 0x68, 0x6f, 0x73, 0x74, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12,
+    0x0c, 0x0a, 0x04, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x0f, 0x0a,
+    0x07, 0x65, 0x78, 0x70, 0x6f, 0x73, 0x65, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0d, 0x12, 0x13,
+    0x0a, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x08, 0x12, 0x15, 0x0a, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x5f, 0x69,
+    0x64, 0x65, 0x6e, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x12, 0x28, 0x0a, 0x06, 0x68, 0x65,
+    0x61, 0x6c, 0x74, 0x68, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x3a, 0x07, 0x55, 0x4e, 0x4b,
+    0x4e, 0x4f, 0x57, 0x4e, 0x12, 0x13, 0x0a, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c,
+    0x69, 0x74, 0x79, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x63, 0x66, 0x67,
+    0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x38, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68,
+    0x12, 0x06, 0x0a, 0x02, 0x4f, 0x4b, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x41, 0x52, 0x4e,
+    0x49, 0x4e, 0x47, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x43, 0x52, 0x49, 0x54, 0x49, 0x43, 0x41,
+    0x4c, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x10, 0x04,
+    0x22, 0x5e, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69,
+    0x67, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f,
+    0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a,
+    0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08,
+    0x12, 0x0e, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c,
+    0x22, 0x6c, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12,
+    0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x65,
+    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x12, 0x10,
+    0x0a, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+    0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x33,
+    0x0a, 0x09, 0x44, 0x65, 0x70, 0x61, 0x72, 0x74, 0x75, 0x72, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13,
+    0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x04, 0x22, 0xde, 0x01, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x18, 0x0a, 0x04,
     0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x53, 0x77, 0x69,
     0x6d, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x15, 0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x02,
     0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x48, 0x00, 0x12, 0x13, 0x0a,
     0x03, 0x61, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x04, 0x2e, 0x41, 0x63, 0x6b,
     0x48, 0x00, 0x12, 0x1b, 0x0a, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71, 0x18, 0x04, 0x20,
     0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x48, 0x00, 0x12,
-    0x1f, 0x0a, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x18, 0x05, 0x20,
-    0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70,
-    0x22, 0x26, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47,
-    0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43, 0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50,
-    0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c,
-    0x6f, 0x61, 0x64, 0x22, 0xf2, 0x02, 0x0a, 0x05, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x19, 0x0a,
-    0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75,
-    0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18,
-    0x02, 0x20, 0x03, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69, 0x64,
-    0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x1d, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
-    0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73,
-    0x68, 0x69, 0x70, 0x48, 0x00, 0x12, 0x1b, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x48, 0x00, 0x12, 0x28, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x63, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x53, 0x65, 0x72,
-    0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00, 0x12, 0x24, 0x0a, 0x0c,
-    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07, 0x20, 0x01,
-    0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65,
-    0x48, 0x00, 0x12, 0x1d, 0x0a, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48,
-    0x00, 0x22, 0x7a, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x65, 0x6d,
-    0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0x03,
-    0x12, 0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69,
-    0x67, 0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69,
-    0x6c, 0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65, 0x10, 0x06, 0x12, 0x09,
-    0x0a, 0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x6c, 0x65,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x08, 0x42, 0x09, 0x0a,
-    0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0x39, 0x0a, 0x04, 0x57, 0x69, 0x72, 0x65,
-    0x12, 0x11, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x08, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x0c, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x0c, 0x4a, 0xce, 0x26, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x70, 0x01, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x09, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
-    0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04,
-    0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x20, 0x21,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06,
-    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04,
-    0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03,
-    0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x11,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x07, 0x1f, 0x20, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12,
-    0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x08, 0x12, 0x03, 0x08,
-    0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x07, 0x12, 0x03, 0x08, 0x2a, 0x2f,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00,
-    0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0c, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x0d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d,
-    0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x10, 0x00, 0x13, 0x01, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x10, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04,
-    0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x12, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
-    0x03, 0x12, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x15, 0x00, 0x18, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x15, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x16, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x19,
-    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x1a, 0x00,
-    0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x12, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x03, 0x1b, 0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04,
-    0x00, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x10, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x10, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x02, 0x12, 0x03, 0x1b, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x27, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x02, 0x12, 0x03, 0x1b, 0x25, 0x26, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02,
-    0x02, 0x12, 0x03, 0x1b, 0x28, 0x36, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x1b, 0x28, 0x31, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02,
-    0x02, 0x12, 0x03, 0x1b, 0x34, 0x35, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03,
-    0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1d, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x04, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12,
-    0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e,
-    0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x1b, 0x1c,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x21, 0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x05, 0x01, 0x12, 0x03, 0x21, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00,
-    0x12, 0x03, 0x22, 0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x22, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x22,
-    0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22,
-    0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x22,
-    0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x22, 0x1d,
-    0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x1d,
-    0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x22, 0x28,
-    0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x22, 0x2b, 0x38,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x2b, 0x33,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x22, 0x36, 0x37,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x24, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x25,
-    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x25, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x25, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
-    0x02, 0x02, 0x12, 0x03, 0x26, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04,
-    0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03,
-    0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x26, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x26, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x03, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
-    0x01, 0x12, 0x03, 0x27, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12,
-    0x03, 0x27, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02,
-    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x06, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x28, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x28, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
-    0x05, 0x12, 0x03, 0x29, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12,
-    0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x29, 0x12, 0x17,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x29, 0x1a, 0x1b, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2c, 0x00, 0x36, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06,
-    0x01, 0x12, 0x03, 0x2c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03,
-    0x2d, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x06, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e,
-    0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x22, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x2f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x30,
-    0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x30, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x30, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
-    0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04,
-    0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03,
-    0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x31, 0x12,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x31, 0x1d, 0x1e, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03, 0x32, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x05, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x32, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12,
-    0x03, 0x32, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x33, 0x02,
-    0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05, 0x12, 0x03, 0x33, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x33, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x33, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
-    0x07, 0x12, 0x03, 0x34, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x04, 0x12,
-    0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x03, 0x34,
-    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x34, 0x10, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x03, 0x12, 0x03, 0x34, 0x1e, 0x1f, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x06, 0x02, 0x08, 0x12, 0x03, 0x35, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x08, 0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x08, 0x05, 0x12, 0x03, 0x35, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01,
-    0x12, 0x03, 0x35, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12, 0x03,
-    0x35, 0x22, 0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x38, 0x00, 0x3d, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x38, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x07, 0x02, 0x00, 0x12, 0x03, 0x39, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39,
-    0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x22, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x3a, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x3a, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x3a, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x3b,
-    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3b, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3b, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3b, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
-    0x02, 0x03, 0x12, 0x03, 0x3c, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04,
-    0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03,
-    0x3c, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3c, 0x11,
-    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3c, 0x1a, 0x1b, 0x0a,
-    0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x3f, 0x00, 0x45, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x08, 0x01, 0x12, 0x03, 0x3f, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12,
-    0x03, 0x40, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x40,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x40, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x40, 0x12, 0x1f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x40, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x41, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x01, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x41, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x20,
-    0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x42, 0x02, 0x1e, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x42, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x42, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03,
-    0x43, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x43, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x43, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x43, 0x12, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x43, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x08, 0x02, 0x04, 0x12, 0x03, 0x44, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x44, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12,
-    0x03, 0x44, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x44,
-    0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x44, 0x18, 0x19,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x47, 0x00, 0x52, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x09, 0x01, 0x12, 0x03, 0x47, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x04, 0x00,
-    0x12, 0x03, 0x48, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x48, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x48,
-    0x0e, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x48,
-    0x0e, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x48,
-    0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x48, 0x18,
-    0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x48, 0x18,
-    0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x48, 0x1e,
-    0x1f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x48, 0x21, 0x2d,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x48, 0x21, 0x28,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x48, 0x2b, 0x2c,
-    0x0a, 0x33, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x4b, 0x02, 0x19, 0x1a, 0x26, 0x20,
-    0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
-    0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64,
-    0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x4b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4b, 0x0b,
-    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4b, 0x10, 0x14, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4b, 0x17, 0x18, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x09, 0x08, 0x00, 0x12, 0x04, 0x4c, 0x02, 0x50, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02,
-    0x01, 0x12, 0x03, 0x4d, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12,
-    0x03, 0x4d, 0x04, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4d,
-    0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4d, 0x10, 0x11,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x4e, 0x04, 0x10, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x02, 0x06, 0x12, 0x03, 0x4e, 0x04, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x4e, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12,
-    0x03, 0x4f, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4f,
-    0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4f, 0x0c, 0x13,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4f, 0x16, 0x17, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x09, 0x02, 0x04, 0x12, 0x03, 0x51, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x04, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x04, 0x06, 0x12, 0x03, 0x51, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01,
-    0x12, 0x03, 0x51, 0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03,
-    0x51, 0x23, 0x24, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x54, 0x00, 0x6a, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x54, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x0a, 0x04, 0x00, 0x12, 0x04, 0x55, 0x02, 0x5e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04,
-    0x00, 0x01, 0x12, 0x03, 0x55, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x56, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x56, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x56, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x57, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x57, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02,
-    0x12, 0x03, 0x57, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x58, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x58, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
-    0x03, 0x58, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
-    0x59, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x59, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
-    0x59, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x5a,
-    0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5a,
-    0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x5a,
-    0x12, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x5b, 0x04,
-    0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5b, 0x04,
-    0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x5b, 0x0b,
-    0x0c, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x5c, 0x04, 0x0e,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5c, 0x04, 0x09,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x5c, 0x0c, 0x0d,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x5d, 0x04, 0x17, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5d, 0x04, 0x12, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x5d, 0x15, 0x16, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x60, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x60, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x60, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x60, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x61, 0x02,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x61, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x61, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02,
-    0x02, 0x12, 0x03, 0x62, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12,
-    0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x62,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x62, 0x12, 0x19,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x62, 0x1c, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x0a, 0x08, 0x00, 0x12, 0x04, 0x63, 0x02, 0x69, 0x03, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x08, 0x00, 0x01, 0x12, 0x03, 0x63, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
-    0x02, 0x03, 0x12, 0x03, 0x64, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06,
-    0x12, 0x03, 0x64, 0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x64, 0x0f, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x64, 0x18,
-    0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x65, 0x04, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x03, 0x65, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x65, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x04, 0x03, 0x12, 0x03, 0x65, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05,
-    0x12, 0x03, 0x66, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03,
-    0x66, 0x04, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x66, 0x12,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x03, 0x66, 0x23, 0x24, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06, 0x12, 0x03, 0x67, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x06, 0x06, 0x12, 0x03, 0x67, 0x04, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x06, 0x01, 0x12, 0x03, 0x67, 0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06,
-    0x03, 0x12, 0x03, 0x67, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x03,
-    0x68, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x06, 0x12, 0x03, 0x68, 0x04,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x01, 0x12, 0x03, 0x68, 0x0d, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x03, 0x12, 0x03, 0x68, 0x18, 0x19, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x0b, 0x12, 0x04, 0x6c, 0x00, 0x70, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01,
-    0x12, 0x03, 0x6c, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6d,
-    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6d, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6d, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6d, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b,
-    0x02, 0x01, 0x12, 0x03, 0x6e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x6e, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x11,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6e, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x6f, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0b, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x6f, 0x11, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x6f, 0x1b, 0x1c,
+    0x15, 0x0a, 0x04, 0x6e, 0x61, 0x63, 0x6b, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e,
+    0x4e, 0x61, 0x63, 0x6b, 0x48, 0x00, 0x12, 0x1f, 0x0a, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x73, 0x68, 0x69, 0x70, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x22, 0x30, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12,
+    0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43, 0x4b,
+    0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03, 0x12,
+    0x08, 0x0a, 0x04, 0x4e, 0x41, 0x43, 0x4b, 0x10, 0x04, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79,
+    0x6c, 0x6f, 0x61, 0x64, 0x22, 0xd3, 0x03, 0x0a, 0x05, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x19,
+    0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52,
+    0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x74, 0x61, 0x67,
+    0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69,
+    0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x1d, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65,
+    0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x73, 0x68, 0x69, 0x70, 0x48, 0x00, 0x12, 0x1b, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x48, 0x00, 0x12, 0x28, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x63,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x53, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00, 0x12, 0x24, 0x0a,
+    0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c,
+    0x65, 0x48, 0x00, 0x12, 0x1d, 0x0a, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+    0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x48, 0x00, 0x12, 0x1f, 0x0a, 0x09, 0x64, 0x65, 0x70, 0x61, 0x72, 0x74, 0x75, 0x72, 0x65, 0x18,
+    0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x44, 0x65, 0x70, 0x61, 0x72, 0x74, 0x75, 0x72,
+    0x65, 0x48, 0x00, 0x12, 0x1f, 0x0a, 0x09, 0x74, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65,
+    0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x54, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f,
+    0x6e, 0x65, 0x48, 0x00, 0x22, 0x98, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a,
+    0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65,
+    0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a,
+    0x0e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10,
+    0x08, 0x12, 0x0d, 0x0a, 0x09, 0x44, 0x65, 0x70, 0x61, 0x72, 0x74, 0x75, 0x72, 0x65, 0x10, 0x09,
+    0x12, 0x0d, 0x0a, 0x09, 0x54, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65, 0x10, 0x0a, 0x42,
+    0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0x39, 0x0a, 0x04, 0x57, 0x69,
+    0x72, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x08, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0c, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x68, 0x0a, 0x0b, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x44, 0x69,
+    0x67, 0x65, 0x73, 0x74, 0x12, 0x19, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12,
+    0x0b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0a, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x10, 0x0a,
+    0x08, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x75, 0x6d, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x22,
+    0x52, 0x0a, 0x08, 0x50, 0x75, 0x73, 0x68, 0x50, 0x75, 0x6c, 0x6c, 0x12, 0x1d, 0x0a, 0x07, 0x64,
+    0x69, 0x67, 0x65, 0x73, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x52,
+    0x75, 0x6d, 0x6f, 0x72, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x72, 0x75,
+    0x6d, 0x6f, 0x72, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x52, 0x75, 0x6d,
+    0x6f, 0x72, 0x12, 0x0f, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x09, 0x22, 0x36, 0x0a, 0x04, 0x4e, 0x61, 0x63, 0x6b, 0x12, 0x15, 0x0a, 0x04, 0x66,
+    0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x12, 0x17, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x60, 0x0a, 0x09, 0x54,
+    0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65, 0x12, 0x19, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54,
+    0x79, 0x70, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0e, 0x0a,
+    0x06, 0x74, 0x74, 0x6c, 0x5f, 0x6d, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x4a, 0xce, 0x26,
+    0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x70, 0x01, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04,
+    0x02, 0x00, 0x09, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x0e,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x03, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04,
+    0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x12,
+    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x06, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02,
+    0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x11, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x07, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x05, 0x12, 0x03, 0x08, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12,
+    0x03, 0x08, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x08,
+    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x08, 0x10, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x08, 0x12, 0x03, 0x08, 0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x05, 0x07, 0x12, 0x03, 0x08, 0x2a, 0x2f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x0b, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b,
+    0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x0d, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x10, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x10, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x12, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12,
+    0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x1f, 0x20, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x15, 0x00, 0x18, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03,
+    0x01, 0x12, 0x03, 0x15, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03,
+    0x16, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x17, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17,
+    0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x1a, 0x00, 0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00,
+    0x12, 0x03, 0x1b, 0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03,
+    0x1b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1b,
+    0x10, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b,
+    0x10, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1b,
+    0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x1b,
+    0x27, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x1b,
+    0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1b, 0x25,
+    0x26, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x28, 0x36,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x28, 0x31,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x34, 0x35,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x1d, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1e,
+    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x04, 0x21, 0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x21,
+    0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00, 0x12, 0x03, 0x22, 0x02, 0x3a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x07, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x22, 0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x22, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x22, 0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x22, 0x2b, 0x38, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x2b, 0x33, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x22, 0x36, 0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x12,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x25, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x25, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x25, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x26, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x26, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x26, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
+    0x03, 0x12, 0x03, 0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12,
+    0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x27,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x27, 0x20, 0x21, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x04, 0x06, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x28, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03,
+    0x28, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x29, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x05, 0x03, 0x12, 0x03, 0x29, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
+    0x2c, 0x00, 0x36, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x2c, 0x08, 0x0f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x2e,
+    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x12,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2f, 0x20, 0x21, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x30, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x30, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x30, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x31, 0x02,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x31, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x31, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
+    0x05, 0x12, 0x03, 0x32, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12,
+    0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x32,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x32, 0x12, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x32, 0x19, 0x1a, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x33, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x06, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x06, 0x05, 0x12, 0x03, 0x33, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01,
+    0x12, 0x03, 0x33, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03,
+    0x33, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x07, 0x12, 0x03, 0x34, 0x02, 0x20,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x04, 0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x03, 0x34, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x34, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x34, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x08,
+    0x12, 0x03, 0x35, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x04, 0x12, 0x03,
+    0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x05, 0x12, 0x03, 0x35, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01, 0x12, 0x03, 0x35, 0x12, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12, 0x03, 0x35, 0x22, 0x23, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x07, 0x12, 0x04, 0x38, 0x00, 0x3d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01,
+    0x12, 0x03, 0x38, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x39,
+    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x01, 0x12, 0x03, 0x3a, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x3a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3a, 0x12,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3a, 0x20, 0x21, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x3b, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x3b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x3b, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x3b, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x3c, 0x02,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3c, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3c, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3c, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12,
+    0x04, 0x3f, 0x00, 0x45, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x3f, 0x08,
+    0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x40, 0x02, 0x24, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x40, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x40, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x40, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x40, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03,
+    0x41, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x41, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x12, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x08, 0x02, 0x02, 0x12, 0x03, 0x42, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x42, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x42,
+    0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x42, 0x1c, 0x1d,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x43, 0x02, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x43, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x43, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x43, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x43, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x44,
+    0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03, 0x44, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x44, 0x0b, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x44, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x44, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09,
+    0x12, 0x04, 0x47, 0x00, 0x52, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x47,
+    0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x04, 0x00, 0x12, 0x03, 0x48, 0x02, 0x2f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03, 0x48, 0x07, 0x0b, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x48, 0x0e, 0x17, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x48, 0x0e, 0x12, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x48, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x48, 0x18, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x09, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x48, 0x18, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x09, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x48, 0x1e, 0x1f, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x09, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x48, 0x21, 0x2d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09,
+    0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x48, 0x21, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x48, 0x2b, 0x2c, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x09,
+    0x02, 0x00, 0x12, 0x03, 0x4b, 0x02, 0x19, 0x1a, 0x26, 0x20, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69,
+    0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x20, 0x69, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4b, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x4b, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x08, 0x00, 0x12,
+    0x04, 0x4c, 0x02, 0x50, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00, 0x01, 0x12, 0x03,
+    0x4c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x04, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4d, 0x04, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4d, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4d, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09,
+    0x02, 0x02, 0x12, 0x03, 0x4e, 0x04, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x4e, 0x04, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x4e, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4e, 0x0e,
+    0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x4f, 0x04, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4f, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4f, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x4f, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x04,
+    0x12, 0x03, 0x51, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x04, 0x12, 0x03,
+    0x51, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12, 0x03, 0x51, 0x0b,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03, 0x51, 0x16, 0x20, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x51, 0x23, 0x24, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x0a, 0x12, 0x04, 0x54, 0x00, 0x6a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01,
+    0x12, 0x03, 0x54, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x04, 0x00, 0x12, 0x04, 0x55,
+    0x02, 0x5e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04, 0x00, 0x01, 0x12, 0x03, 0x55, 0x07,
+    0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x56, 0x04, 0x0f,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x56, 0x04, 0x0a,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x56, 0x0d, 0x0e,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x57, 0x04, 0x10, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x57, 0x04, 0x0b, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x57, 0x0e, 0x0f, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x58, 0x04, 0x11, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x58, 0x04, 0x0c, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x58, 0x0f, 0x10, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x59, 0x04, 0x16, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x59, 0x04, 0x11, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x59, 0x14, 0x15, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x5a, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5a, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x5a, 0x12, 0x13, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x5b, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x0a, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5b, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x0a, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x5b, 0x0b, 0x0c, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x0a, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x5c, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a,
+    0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5c, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a,
+    0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x5c, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a,
+    0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x5d, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04,
+    0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5d, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04,
+    0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x5d, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02,
+    0x00, 0x12, 0x03, 0x60, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x03, 0x60,
+    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x60, 0x10, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x60, 0x17, 0x18, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x61, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x61, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x61, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x62, 0x02, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x62, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x62, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x08, 0x00,
+    0x12, 0x04, 0x63, 0x02, 0x69, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x08, 0x00, 0x01, 0x12,
+    0x03, 0x63, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12, 0x03, 0x64, 0x04,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06, 0x12, 0x03, 0x64, 0x04, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x64, 0x0f, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x64, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0a, 0x02, 0x04, 0x12, 0x03, 0x65, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04,
+    0x06, 0x12, 0x03, 0x65, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x65, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x65,
+    0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x03, 0x66, 0x04, 0x25, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03, 0x66, 0x04, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x66, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x05, 0x03, 0x12, 0x03, 0x66, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02,
+    0x06, 0x12, 0x03, 0x67, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x06, 0x12,
+    0x03, 0x67, 0x04, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x03, 0x67,
+    0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x03, 0x67, 0x1f, 0x20,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x03, 0x68, 0x04, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x07, 0x06, 0x12, 0x03, 0x68, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x07, 0x01, 0x12, 0x03, 0x68, 0x0d, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x07, 0x03, 0x12, 0x03, 0x68, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x6c,
+    0x00, 0x70, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x6c, 0x08, 0x0c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6d, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x6d, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x6d, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x6d, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x6e, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x11, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6e, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x02, 0x12, 0x03, 0x6f, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6f,
+    0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6f, 0x11, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x1b, 0x1c,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -0,0 +1,175 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Departure rumor.
+//!
+//! Sent by a member that is leaving the ring on purpose, so that its peers can mark it as
+//! Departed right away rather than waiting for it to be suspected and confirmed. A departure
+//! carries the incarnation the member left at; once the member comes back with a higher one, the
+//! departure no longer applies to it.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use protobuf::Message;
+
+use error::Result;
+use message::swim::{Departure as ProtoDeparture, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+/// The departure rumor
+#[derive(Debug, Clone, RustcEncodable)]
+pub struct Departure {
+    pub proto: ProtoRumor,
+}
+
+impl PartialOrd for Departure {
+    fn partial_cmp(&self, other: &Departure) -> Option<Ordering> {
+        if self.get_member_id() != other.get_member_id() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for Departure {
+    fn eq(&self, other: &Departure) -> bool {
+        self.get_member_id() == other.get_member_id() &&
+        self.get_incarnation() == other.get_incarnation()
+    }
+}
+
+impl From<ProtoRumor> for Departure {
+    fn from(pr: ProtoRumor) -> Departure {
+        Departure { proto: pr }
+    }
+}
+
+impl From<Departure> for ProtoRumor {
+    fn from(departure: Departure) -> ProtoRumor {
+        departure.proto
+    }
+}
+
+impl Deref for Departure {
+    type Target = ProtoDeparture;
+
+    fn deref(&self) -> &ProtoDeparture {
+        self.proto.get_departure()
+    }
+}
+
+impl DerefMut for Departure {
+    fn deref_mut(&mut self) -> &mut ProtoDeparture {
+        self.proto.mut_departure()
+    }
+}
+
+impl Departure {
+    /// Creates a new Departure for the given member, leaving at the given incarnation.
+    pub fn new<S1>(member_id: S1, incarnation: u64) -> Self
+        where S1: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::Departure);
+
+        let mut proto = ProtoDeparture::new();
+        proto.set_member_id(from_id);
+        proto.set_incarnation(incarnation);
+        rumor.set_departure(proto);
+        Departure { proto: rumor }
+    }
+}
+
+impl Rumor for Departure {
+    /// Follow the member to its latest departure.
+    fn merge(&mut self, mut other: Departure) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::Departure
+    }
+
+    fn id(&self) -> &str {
+        self.get_member_id()
+    }
+
+    fn key(&self) -> &str {
+        "departure"
+    }
+
+    fn incarnation(&self) -> u64 {
+        self.get_incarnation()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.proto.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Departure;
+    use rumor::Rumor;
+
+    #[test]
+    fn departures_for_the_same_member_and_incarnation_are_equal() {
+        assert_eq!(Departure::new("adam", 1), Departure::new("adam", 1));
+    }
+
+    #[test]
+    fn departures_for_different_members_are_not_equal() {
+        assert!(Departure::new("adam", 1) != Departure::new("shanku", 1));
+    }
+
+    #[test]
+    fn departures_at_different_incarnations_are_not_equal() {
+        assert!(Departure::new("adam", 1) != Departure::new("adam", 2));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut d1 = Departure::new("adam", 1);
+        let d2 = Departure::new("adam", 2);
+        assert_eq!(d1.merge(d2), true);
+        assert_eq!(d1.get_incarnation(), 2);
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let mut d1 = Departure::new("adam", 2);
+        assert_eq!(d1.merge(Departure::new("adam", 2)), false);
+        assert_eq!(d1.merge(Departure::new("adam", 1)), false);
+        assert_eq!(d1.get_incarnation(), 2);
+    }
+
+    #[test]
+    fn departures_are_keyed_by_member() {
+        let d1 = Departure::new("adam", 1);
+        assert_eq!(d1.key(), "departure");
+        assert_eq!(d1.id(), "adam");
+        assert_eq!(d1.incarnation(), 1);
+    }
+}
@@ -210,6 +210,11 @@ impl Rumor for Election {
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         Ok(try!(self.proto.write_to_bytes()))
     }
+
+    /// Elections move forward by term.
+    fn incarnation(&self) -> u64 {
+        self.get_term()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -280,6 +285,10 @@ impl Rumor for ElectionUpdate {
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         self.0.write_to_bytes()
     }
+
+    fn incarnation(&self) -> u64 {
+        self.0.incarnation()
+    }
 }
 
 #[cfg(test)]
@@ -23,10 +23,12 @@
 //! New rumors need to implement the `From` trait for `RumorKey`, and then can track the arrival of
 //! new rumors, and dispatch them according to their `kind`.
 
+pub mod departure;
 pub mod election;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod tombstone;
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
@@ -35,9 +37,10 @@ use std::result;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use habitat_core::crypto::hash;
 use rustc_serialize::{Encoder, Encodable};
 
-use message::swim::Rumor_Type;
+use message::swim::{Rumor as ProtoRumor, RumorDigest, Rumor_Type};
 use error::{Result, Error};
 
 /// The description of a `RumorKey`.
@@ -74,6 +77,12 @@ pub trait Rumor: Encodable {
     fn id(&self) -> &str;
     fn merge(&mut self, other: Self) -> bool;
     fn write_to_bytes(&self) -> Result<Vec<u8>>;
+
+    /// How far along this rumor is; a rumor with a higher incarnation supersedes a lower one.
+    /// Rumors that never change can keep the default.
+    fn incarnation(&self) -> u64 {
+        0
+    }
 }
 
 impl<'a, T: Rumor + Clone> From<&'a T> for RumorKey {
@@ -82,6 +91,54 @@ impl<'a, T: Rumor + Clone> From<&'a T> for RumorKey {
     }
 }
 
+/// The digests another member sent us, keyed by the rumor they describe.
+pub type DigestMap = HashMap<RumorKey, RumorDigest>;
+
+/// Index a list of digests by the rumor they describe.
+pub fn digest_map(digests: &[RumorDigest]) -> DigestMap {
+    let mut map = HashMap::new();
+    for digest in digests.iter() {
+        let rk = RumorKey::new(digest.get_field_type(), digest.get_id(), digest.get_key());
+        map.insert(rk, digest.clone());
+    }
+    map
+}
+
+/// Create a digest of a rumor: enough to tell whether another member's copy is the same as ours,
+/// without sending the rumor itself.
+pub fn digest<S1, S2>(kind: Rumor_Type,
+                      id: S1,
+                      key: S2,
+                      incarnation: u64,
+                      bytes: &[u8])
+                      -> Result<RumorDigest>
+    where S1: Into<String>,
+          S2: Into<String>
+{
+    let mut digest = RumorDigest::new();
+    digest.set_field_type(kind);
+    digest.set_id(id.into());
+    digest.set_key(key.into());
+    digest.set_incarnation(incarnation);
+    digest.set_checksum(try!(hash::hash_bytes(bytes)));
+    Ok(digest)
+}
+
+/// Whether a member whose copy of a rumor is described by `theirs` should be sent ours. They
+/// should if they don't have it at all, if ours is a newer incarnation, or if we are on the same
+/// incarnation but disagree about its contents; in that case, merging sorts out who wins. Rumors
+/// older than theirs are never sent.
+pub fn is_wanted(theirs: Option<&RumorDigest>, ours: &RumorDigest) -> bool {
+    match theirs {
+        None => true,
+        Some(theirs) => {
+            ours.get_incarnation() > theirs.get_incarnation() ||
+            (ours.get_incarnation() == theirs.get_incarnation() &&
+             ours.get_checksum() != theirs.get_checksum())
+        }
+    }
+}
+
 /// Storage for Rumors. It takes a rumor and stores it according to the member that produced it,
 /// and the service group it is related to.
 ///
@@ -157,9 +214,22 @@ impl<T: Rumor + Clone> RumorStore<T> {
         result
     }
 
+    /// Remove a rumor from the store. Keys left without any rumors are removed as well, so that
+    /// every key we hand out through `with_keys` has at least one rumor.
     pub fn remove(&self, key: &str, id: &str) {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
-        list.get_mut(key).and_then(|mut r| r.remove(id));
+        let empty = match list.get_mut(key) {
+            Some(rumors) => {
+                if rumors.remove(id).is_some() {
+                    self.increment_update_counter();
+                }
+                rumors.is_empty()
+            }
+            None => false,
+        };
+        if empty {
+            list.remove(key);
+        }
     }
 
     pub fn with_keys<F>(&self, mut with_closure: F)
@@ -204,6 +274,52 @@ impl<T: Rumor + Clone> RumorStore<T> {
             None => false,
         }
     }
+
+    /// A digest of every rumor in the store. Rumors that cannot be serialized are left out.
+    pub fn digests(&self) -> Vec<RumorDigest> {
+        let list = self.list.read().expect("Rumor store lock poisoned");
+        let mut digests = Vec::new();
+        for rumors in list.values() {
+            for rumor in rumors.values() {
+                match digest_of(rumor) {
+                    Ok(digest) => digests.push(digest),
+                    Err(e) => {
+                        error!("Cannot create a digest of rumor {}-{}: {}",
+                               rumor.id(),
+                               rumor.key(),
+                               e)
+                    }
+                }
+            }
+        }
+        digests
+    }
+
+    /// Every rumor in the store that the member who sent us `theirs` is missing, or only has an
+    /// older copy of.
+    pub fn wanted_by(&self, theirs: &DigestMap) -> Vec<ProtoRumor>
+        where T: Into<ProtoRumor>
+    {
+        let list = self.list.read().expect("Rumor store lock poisoned");
+        let mut wanted = Vec::new();
+        for rumors in list.values() {
+            for rumor in rumors.values() {
+                let ours = match digest_of(rumor) {
+                    Ok(digest) => digest,
+                    Err(_) => continue,
+                };
+                if is_wanted(theirs.get(&RumorKey::from(rumor)), &ours) {
+                    wanted.push(rumor.clone().into());
+                }
+            }
+        }
+        wanted
+    }
+}
+
+fn digest_of<T: Rumor>(rumor: &T) -> Result<RumorDigest> {
+    let bytes = try!(rumor.write_to_bytes());
+    digest(rumor.kind(), rumor.id(), rumor.key(), rumor.incarnation(), &bytes)
 }
 
 /// The number of times a rumor will be shared before it goes cold for that member.
@@ -253,6 +369,12 @@ impl RumorList {
         rumor_vec
     }
 
+    /// Remove a rumor from the list, so that it is no longer shared.
+    pub fn remove(&self, rk: &RumorKey) {
+        let mut rumors = self.rumor_list.write().expect("Rumor Map lock poisoned");
+        rumors.remove(rk);
+    }
+
     /// Take a certain amount of rumors.
     pub fn take(&self, id: &str, amount: usize) -> RumorVec {
         self.rumors(id).into_iter().take(amount).collect()
@@ -470,6 +592,59 @@ mod tests {
             let rs = create_rumor_store();
             rs.with_rumor("bar", "foo", |o| assert!(o.is_none()));
         }
+
+        #[test]
+        fn digests_cover_every_rumor() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let f1_id = f1.id.clone();
+            rs.insert(f1);
+            rs.insert(FakeRumor::default());
+            let digests = rs.digests();
+            assert_eq!(digests.len(), 2);
+            assert!(digests.iter().any(|d| d.get_id() == f1_id && d.get_key() == "fakerton"));
+            assert!(digests.iter().all(|d| d.get_checksum().len() > 0));
+        }
+    }
+
+    mod digests {
+        use message::swim::Rumor_Type;
+        use rumor::{digest, digest_map, is_wanted, RumorKey};
+
+        #[test]
+        fn missing_rumors_are_wanted() {
+            let ours = digest(Rumor_Type::Fake, "a", "fakerton", 1, b"a").unwrap();
+            assert!(is_wanted(None, &ours));
+        }
+
+        #[test]
+        fn newer_rumors_are_wanted() {
+            let ours = digest(Rumor_Type::Fake, "a", "fakerton", 2, b"a").unwrap();
+            let theirs = digest(Rumor_Type::Fake, "a", "fakerton", 1, b"a").unwrap();
+            assert!(is_wanted(Some(&theirs), &ours));
+        }
+
+        #[test]
+        fn differing_rumors_on_the_same_incarnation_are_wanted() {
+            let ours = digest(Rumor_Type::Fake, "a", "fakerton", 1, b"a").unwrap();
+            let theirs = digest(Rumor_Type::Fake, "a", "fakerton", 1, b"b").unwrap();
+            assert!(is_wanted(Some(&theirs), &ours));
+        }
+
+        #[test]
+        fn identical_and_older_rumors_are_not_wanted() {
+            let ours = digest(Rumor_Type::Fake, "a", "fakerton", 1, b"a").unwrap();
+            assert!(!is_wanted(Some(&ours.clone()), &ours));
+            let theirs = digest(Rumor_Type::Fake, "a", "fakerton", 2, b"b").unwrap();
+            assert!(!is_wanted(Some(&theirs), &ours));
+        }
+
+        #[test]
+        fn digest_map_indexes_by_rumor_key() {
+            let d = digest(Rumor_Type::Fake, "a", "fakerton", 1, b"a").unwrap();
+            let map = digest_map(&[d]);
+            assert!(map.contains_key(&RumorKey::new(Rumor_Type::Fake, "a", "fakerton")));
+        }
     }
 
     mod rumor_list {
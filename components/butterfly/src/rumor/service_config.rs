@@ -147,6 +147,10 @@ impl Rumor for ServiceConfig {
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         Ok(try!(self.proto.write_to_bytes()))
     }
+
+    fn incarnation(&self) -> u64 {
+        self.get_incarnation()
+    }
 }
 
 #[cfg(test)]
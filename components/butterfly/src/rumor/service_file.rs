@@ -153,6 +153,10 @@ impl Rumor for ServiceFile {
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         Ok(try!(self.proto.write_to_bytes()))
     }
+
+    fn incarnation(&self) -> u64 {
+        self.get_incarnation()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,270 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tombstones for reaped members and rumors.
+//!
+//! When a member has been confirmed dead (or has departed) for long enough, it is reaped: the
+//! member and its rumors are removed from every store. Other members may not have reaped it yet,
+//! and will happily gossip the same data back to us. A tombstone remembers the incarnation (or
+//! term) of what we removed, so that anything no newer than it is ignored until the tombstone
+//! expires.
+//!
+//! A member that stops running a service withdraws its service rumor the same way: the rumor is
+//! removed and buried, and the tombstone is gossiped, so the rest of the ring drops it too.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use time::{Duration, SteadyTime};
+
+use message::swim::{Rumor as ProtoRumor, Rumor_Type, Tombstone as ProtoTombstone};
+use rumor::RumorKey;
+
+/// The tombstones for reaped members, and for the rumors of service groups that were left empty.
+#[derive(Debug, Clone)]
+pub struct Tombstones {
+    members: Arc<RwLock<HashMap<String, (u64, SteadyTime)>>>,
+    rumors: Arc<RwLock<HashMap<RumorKey, (u64, SteadyTime)>>>,
+}
+
+impl Default for Tombstones {
+    fn default() -> Tombstones {
+        Tombstones {
+            members: Arc::new(RwLock::new(HashMap::new())),
+            rumors: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Tombstones {
+    /// Bury a member at the given incarnation, for `ttl`.
+    pub fn bury_member(&self, member_id: &str, incarnation: u64, ttl: Duration) {
+        self.members
+            .write()
+            .expect("Member tombstones lock is poisoned")
+            .insert(String::from(member_id), (incarnation, SteadyTime::now() + ttl));
+    }
+
+    /// Bury a rumor at the given incarnation or term, for `ttl`.
+    pub fn bury_rumor(&self, rk: RumorKey, version: u64, ttl: Duration) {
+        self.rumors
+            .write()
+            .expect("Rumor tombstones lock is poisoned")
+            .insert(rk, (version, SteadyTime::now() + ttl));
+    }
+
+    /// Returns true if the member has a live tombstone at an incarnation at least as new as
+    /// `incarnation`.
+    pub fn member_is_buried(&self, member_id: &str, incarnation: u64) -> bool {
+        match self.members.read().expect("Member tombstones lock is poisoned").get(member_id) {
+            Some(&(buried, expires)) => incarnation <= buried && SteadyTime::now() < expires,
+            None => false,
+        }
+    }
+
+    /// Returns true if the member has a live tombstone, whatever its incarnation. Used for the
+    /// rumors a member produces, which do not carry the member's incarnation.
+    pub fn has_member(&self, member_id: &str) -> bool {
+        match self.members.read().expect("Member tombstones lock is poisoned").get(member_id) {
+            Some(&(_, expires)) => SteadyTime::now() < expires,
+            None => false,
+        }
+    }
+
+    /// Returns true if the rumor has a live tombstone at a version at least as new as `version`.
+    pub fn rumor_is_buried(&self, rk: &RumorKey, version: u64) -> bool {
+        match self.rumors.read().expect("Rumor tombstones lock is poisoned").get(rk) {
+            Some(&(buried, expires)) => version <= buried && SteadyTime::now() < expires,
+            None => false,
+        }
+    }
+
+    /// The incarnation or term of a rumor's live tombstone, and how long it has left to live.
+    pub fn buried_rumor(&self, rk: &RumorKey) -> Option<(u64, Duration)> {
+        let now = SteadyTime::now();
+        match self.rumors.read().expect("Rumor tombstones lock is poisoned").get(rk) {
+            Some(&(version, expires)) if now < expires => Some((version, expires - now)),
+            _ => None,
+        }
+    }
+
+    /// Remove the tombstone for a member that has proven it is alive.
+    pub fn exhume_member(&self, member_id: &str) {
+        self.members.write().expect("Member tombstones lock is poisoned").remove(member_id);
+    }
+
+    /// Remove the tombstones for every rumor of a service group that has a member again. The
+    /// tombstones of withdrawn service rumors belong to a single member, and are left alone.
+    pub fn exhume_service_group(&self, service_group: &str) {
+        let mut rumors = self.rumors.write().expect("Rumor tombstones lock is poisoned");
+        let exhumed: Vec<RumorKey> = rumors.keys()
+            .filter(|rk| rk.key == service_group && rk.kind != Rumor_Type::Service)
+            .cloned()
+            .collect();
+        for rk in exhumed.iter() {
+            rumors.remove(rk);
+        }
+    }
+
+    /// Drop every tombstone that has expired.
+    pub fn expire(&self) {
+        let now = SteadyTime::now();
+        {
+            let mut members = self.members.write().expect("Member tombstones lock is poisoned");
+            let expired: Vec<String> = members.iter()
+                .filter(|&(_, &(_, expires))| expires <= now)
+                .map(|(member_id, _)| member_id.clone())
+                .collect();
+            for member_id in expired.iter() {
+                members.remove(member_id);
+            }
+        }
+        let mut rumors = self.rumors.write().expect("Rumor tombstones lock is poisoned");
+        let expired: Vec<RumorKey> = rumors.iter()
+            .filter(|&(_, &(_, expires))| expires <= now)
+            .map(|(rk, _)| rk.clone())
+            .collect();
+        for rk in expired.iter() {
+            rumors.remove(rk);
+        }
+    }
+
+    /// Every live member tombstone: the member's id, its incarnation, and how long the tombstone
+    /// has left to live.
+    pub fn buried_members(&self) -> Vec<(String, u64, Duration)> {
+        let now = SteadyTime::now();
+        self.members
+            .read()
+            .expect("Member tombstones lock is poisoned")
+            .iter()
+            .filter(|&(_, &(_, expires))| now < expires)
+            .map(|(member_id, &(incarnation, expires))| {
+                (member_id.clone(), incarnation, expires - now)
+            })
+            .collect()
+    }
+
+    /// Every live rumor tombstone: the rumor's key, its incarnation or term, and how long the
+    /// tombstone has left to live.
+    pub fn buried_rumors(&self) -> Vec<(RumorKey, u64, Duration)> {
+        let now = SteadyTime::now();
+        self.rumors
+            .read()
+            .expect("Rumor tombstones lock is poisoned")
+            .iter()
+            .filter(|&(_, &(_, expires))| now < expires)
+            .map(|(rk, &(version, expires))| (rk.clone(), version, expires - now))
+            .collect()
+    }
+
+    /// The number of tombstones, for members and rumors.
+    pub fn len(&self) -> usize {
+        self.members.read().expect("Member tombstones lock is poisoned").len() +
+        self.rumors.read().expect("Rumor tombstones lock is poisoned").len()
+    }
+}
+
+/// A `Tombstone` rumor from `from_id` for a buried member or rumor, carrying the time the
+/// tombstone has left to live.
+pub fn tombstone_rumor(from_id: &str, rk: RumorKey, version: u64, ttl: Duration) -> ProtoRumor {
+    let mut tombstone = ProtoTombstone::new();
+    tombstone.set_field_type(rk.kind);
+    tombstone.set_key(rk.key);
+    tombstone.set_id(rk.id);
+    tombstone.set_version(version);
+    tombstone.set_ttl_ms(ttl.num_milliseconds() as u64);
+    let mut rumor = ProtoRumor::new();
+    rumor.set_field_type(Rumor_Type::Tombstone);
+    rumor.set_tombstone(tombstone);
+    rumor.set_from_id(String::from(from_id));
+    rumor
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use message::swim::Rumor_Type;
+    use rumor::RumorKey;
+    use super::Tombstones;
+
+    #[test]
+    fn buried_members_hide_incarnations_up_to_their_own() {
+        let tombstones = Tombstones::default();
+        tombstones.bury_member("adam", 2, Duration::minutes(1));
+        assert!(tombstones.member_is_buried("adam", 1));
+        assert!(tombstones.member_is_buried("adam", 2));
+        assert!(!tombstones.member_is_buried("adam", 3));
+        assert!(!tombstones.member_is_buried("shanku", 0));
+        assert!(tombstones.has_member("adam"));
+    }
+
+    #[test]
+    fn exhumed_members_are_not_buried() {
+        let tombstones = Tombstones::default();
+        tombstones.bury_member("adam", 2, Duration::minutes(1));
+        tombstones.exhume_member("adam");
+        assert!(!tombstones.member_is_buried("adam", 0));
+    }
+
+    #[test]
+    fn buried_rumors_are_exhumed_by_service_group() {
+        let tombstones = Tombstones::default();
+        let rk = RumorKey::new(Rumor_Type::Election, "election", "redis.default");
+        tombstones.bury_rumor(rk.clone(), 4, Duration::minutes(1));
+        assert!(tombstones.rumor_is_buried(&rk, 4));
+        assert!(!tombstones.rumor_is_buried(&rk, 5));
+        tombstones.exhume_service_group("redis.default");
+        assert!(!tombstones.rumor_is_buried(&rk, 0));
+    }
+
+    #[test]
+    fn withdrawn_services_stay_buried_when_their_service_group_is_exhumed() {
+        let tombstones = Tombstones::default();
+        let rk = RumorKey::new(Rumor_Type::Service, "adam", "redis.default");
+        tombstones.bury_rumor(rk.clone(), 4, Duration::minutes(1));
+        tombstones.exhume_service_group("redis.default");
+        assert!(tombstones.rumor_is_buried(&rk, 4));
+        assert_eq!(tombstones.buried_rumor(&rk).map(|(version, _)| version), Some(4));
+    }
+
+    #[test]
+    fn only_live_tombstones_are_listed() {
+        let tombstones = Tombstones::default();
+        let rk = RumorKey::new(Rumor_Type::Election, "election", "redis.default");
+        tombstones.bury_member("adam", 2, Duration::minutes(1));
+        tombstones.bury_member("shanku", 3, Duration::zero());
+        tombstones.bury_rumor(rk.clone(), 4, Duration::minutes(1));
+        let members = tombstones.buried_members();
+        assert_eq!(members.len(), 1);
+        assert_eq!((members[0].0.as_str(), members[0].1), ("adam", 2));
+        assert!(members[0].2 <= Duration::minutes(1));
+        let rumors = tombstones.buried_rumors();
+        assert_eq!(rumors.len(), 1);
+        assert_eq!((&rumors[0].0, rumors[0].1), (&rk, 4));
+    }
+
+    #[test]
+    fn expired_tombstones_are_dropped() {
+        let tombstones = Tombstones::default();
+        tombstones.bury_member("adam", 2, Duration::zero());
+        tombstones.bury_rumor(RumorKey::new(Rumor_Type::ServiceFile, "foo.toml", "redis.default"),
+                              0,
+                              Duration::zero());
+        assert!(!tombstones.member_is_buried("adam", 0));
+        assert_eq!(tombstones.len(), 2);
+        tombstones.expire();
+        assert_eq!(tombstones.len(), 0);
+    }
+}
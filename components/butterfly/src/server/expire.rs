@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Expire suspected members.
+//! Expire suspected members, and reap gone ones.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. How long is long enough shrinks as other members independently confirm
+//! the suspicion; see `Timing::suspicion_timeout_duration`. Members that stay confirmed or
+//! departed for longer than the reap timeout are removed, along with their rumors, and their
+//! tombstones are expired once they have served their purpose.
 
 use std::thread;
 use std::time::Duration;
@@ -43,26 +46,44 @@ impl<'a> Expire<'a> {
         }
     }
 
+    /// Reap the members that have been gone longer than the reap timeout, and drop expired
+    /// tombstones. Does nothing if reaping is turned off.
+    pub fn reap(&self) {
+        if let Some(timeout) = self.timing.reap_timeout_duration() {
+            for member_id in self.server.member_list.reapable(timeout).iter() {
+                self.server.reap_member(member_id, timeout);
+            }
+            self.server.tombstones.expire();
+        }
+    }
+
     /// Run the expire thread.
     pub fn run(&self) {
         loop {
             let mut expired_list: Vec<String> = Vec::new();
-            self.server.member_list.with_suspects(|(id, suspect)| {
-                let now = SteadyTime::now();
-                if *suspect + self.timing.suspicion_timeout_duration() > now {
+            let members = self.server.member_list.len();
+            self.server.member_list.with_suspects(|(id, suspicion)| {
+                let timeout = self.timing
+                    .suspicion_timeout_duration(members, suspicion.confirmations.len());
+                if suspicion.since + timeout <= SteadyTime::now() {
                     expired_list.push(String::from(id));
-                    self.server.member_list.insert_health_by_id(id, Health::Confirmed);
-                    self.server.member_list.with_member(id, |has_member| {
-                        let member = has_member.expect("Member does not exist when expiring it");
-                        debug!("Marking {:?} as Confirmed", member);
-                        trace_it!(PROBE: &self.server, TraceKind::ProbeConfirmed, member.get_id(), member.get_address());
-                    });
                 }
             });
             for mid in expired_list.iter() {
+                // It may have refuted the suspicion since we looked.
+                if !self.server.member_list.check_health_of_by_id(mid, Health::Suspect) {
+                    continue;
+                }
+                self.server.member_list.insert_health_by_id(mid, Health::Confirmed);
+                self.server.member_list.with_member(mid, |has_member| {
+                    let member = has_member.expect("Member does not exist when expiring it");
+                    debug!("Marking {:?} as Confirmed", member);
+                    trace_it!(PROBE: &self.server, TraceKind::ProbeConfirmed, member.get_id(), member.get_address());
+                });
                 self.server.member_list.expire(mid);
                 self.server.rumor_list.insert(RumorKey::new(Rumor_Type::Member, mid.clone(), ""));
             }
+            self.reap();
             thread::sleep(Duration::from_millis(500));
         }
     }
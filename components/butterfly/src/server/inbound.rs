@@ -15,6 +15,10 @@
 //! The inbound thread.
 //!
 //! This module handles all the inbound SWIM messages.
+//!
+//! When another member asks us to ping someone on its behalf, we remember it until the Ack comes
+//! back through us. If it doesn't come back in time, we send that member a Nack, which tells it
+//! that we, at least, can still hear it.
 
 use std::sync::mpsc;
 use std::sync::atomic::Ordering;
@@ -23,40 +27,54 @@ use std::thread;
 use std::time::Duration;
 
 use protobuf;
+use time::SteadyTime;
 
 use member::{Member, Health};
 use message::swim::{Swim, Swim_Type};
 use server::{Server, outbound};
+use server::timing::Timing;
 use trace::TraceKind;
 
-/// Takes the Server and a channel to send received Acks to the outbound thread.
+/// A ping we sent because another member asked us to, waiting for the target's Ack.
+struct ForwardedPing {
+    requester: Member,
+    target: Member,
+    expires: SteadyTime,
+}
+
+/// Takes the Server and a channel to send received Acks and Nacks to the outbound thread.
 pub struct Inbound<'a> {
     pub server: &'a Server,
     pub socket: UdpSocket,
     pub tx_outbound: mpsc::Sender<(SocketAddr, Swim)>,
+    pub timing: Timing,
 }
 
 impl<'a> Inbound<'a> {
     /// Create a new Inbound.
     pub fn new(server: &'a Server,
                socket: UdpSocket,
-               tx_outbound: mpsc::Sender<(SocketAddr, Swim)>)
+               tx_outbound: mpsc::Sender<(SocketAddr, Swim)>,
+               timing: Timing)
                -> Inbound {
         Inbound {
             server: server,
             socket: socket,
             tx_outbound: tx_outbound,
+            timing: timing,
         }
     }
 
     /// Run the thread. Listens for messages up to 1k in size, and then processes them accordingly.
     pub fn run(&self) {
         let mut recv_buffer: Vec<u8> = vec![0; 1024];
+        let mut forwarded: Vec<ForwardedPing> = Vec::new();
         loop {
             if self.server.pause.load(Ordering::Relaxed) {
                 thread::sleep(Duration::from_millis(100));
                 continue;
             }
+            self.send_nacks(&mut forwarded);
             match self.socket.recv_from(&mut recv_buffer[..]) {
                 Ok((length, addr)) => {
                     let swim_payload = match self.server.unwrap_wire(&recv_buffer[0..length]) {
@@ -95,7 +113,7 @@ impl<'a> Inbound<'a> {
                                        msg.get_ack().get_from().get_id());
                                 continue;
                             }
-                            self.process_ack(addr, msg);
+                            self.process_ack(addr, msg, &mut forwarded);
                         }
                         Swim_Type::PINGREQ => {
                             if self.server.check_blacklist(msg.get_pingreq().get_from().get_id()) {
@@ -103,7 +121,15 @@ impl<'a> Inbound<'a> {
                                        msg.get_pingreq().get_from().get_id());
                                 continue;
                             }
-                            self.process_pingreq(addr, msg);
+                            self.process_pingreq(addr, msg, &mut forwarded);
+                        }
+                        Swim_Type::NACK => {
+                            if self.server.check_blacklist(msg.get_nack().get_from().get_id()) {
+                                debug!("Not processing message from {} - it is blacklisted",
+                                       msg.get_nack().get_from().get_id());
+                                continue;
+                            }
+                            self.process_nack(addr, msg);
                         }
                     }
                 }
@@ -125,8 +151,22 @@ impl<'a> Inbound<'a> {
         }
     }
 
+    /// Send a Nack for every ping we forwarded that has gone unanswered for too long.
+    fn send_nacks(&self, forwarded: &mut Vec<ForwardedPing>) {
+        let now = SteadyTime::now();
+        let (expired, waiting): (Vec<ForwardedPing>, Vec<ForwardedPing>) =
+            forwarded.drain(..).partition(|f| f.expires <= now);
+        *forwarded = waiting;
+        for f in expired.iter() {
+            outbound::nack(self.server, &self.socket, &f.requester, &f.target);
+        }
+    }
+
     /// Process pingreq messages.
-    fn process_pingreq(&self, addr: SocketAddr, mut msg: Swim) {
+    fn process_pingreq(&self,
+                       addr: SocketAddr,
+                       mut msg: Swim,
+                       forwarded: &mut Vec<ForwardedPing>) {
         trace_it!(SWIM: &self.server, TraceKind::RecvPingReq, msg.get_pingreq().get_from().get_id(), addr, &msg);
         // We need to get msg to be owned by the closure, so we're going to have to
         // allocate here to get the id. Kind of a bummer, but life goes on.
@@ -142,16 +182,38 @@ impl<'a> Inbound<'a> {
             // Set the route-back address to the one we received the pingreq from
             let mut from = msg.mut_pingreq().take_from();
             from.set_address(format!("{}", addr.ip()));
+            let requester: Member = from.into();
             outbound::ping(self.server,
                            &self.socket,
                            target,
                            target.swim_socket_address(),
-                           Some(from.into()));
+                           Some(requester.clone()));
+            forwarded.push(ForwardedPing {
+                requester: requester,
+                target: target.clone(),
+                expires: self.timing.nack_timeout(),
+            });
         });
     }
 
+    /// Process nack messages; forwards to the outbound thread.
+    fn process_nack(&self, addr: SocketAddr, mut msg: Swim) {
+        trace_it!(SWIM: &self.server, TraceKind::RecvNack, msg.get_nack().get_from().get_id(), addr, &msg);
+        info!("Nack from {}@{} for {}",
+              msg.get_nack().get_from().get_id(),
+              addr,
+              msg.get_nack().get_target().get_id());
+        let membership = msg.take_membership().into_vec();
+        let from_id = String::from(msg.get_nack().get_from().get_id());
+        match self.tx_outbound.send((addr, msg)) {
+            Ok(()) => {}
+            Err(e) => panic!("Outbound thread has died - this shouldn't happen: #{:?}", e),
+        }
+        self.server.insert_member_from_rumors(&from_id, membership);
+    }
+
     /// Process ack messages; forwards to the outbound thread.
-    fn process_ack(&self, addr: SocketAddr, mut msg: Swim) {
+    fn process_ack(&self, addr: SocketAddr, mut msg: Swim, forwarded: &mut Vec<ForwardedPing>) {
         trace_it!(SWIM: &self.server, TraceKind::RecvAck, msg.get_ack().get_from().get_id(), addr, &msg);
         info!("Ack from {}@{}", msg.get_ack().get_from().get_id(), addr);
         if msg.get_ack().has_forward_to() {
@@ -174,23 +236,22 @@ impl<'a> Inbound<'a> {
                       msg.get_ack().get_forward_to().get_id(),
                       msg.get_ack().get_forward_to().get_address(),
                       );
+                forwarded.retain(|f| {
+                    f.target.get_id() != msg.get_ack().get_from().get_id() ||
+                    f.requester.get_id() != msg.get_ack().get_forward_to().get_id()
+                });
                 msg.mut_ack().mut_from().set_address(format!("{}", addr.ip()));
                 outbound::forward_ack(self.server, &self.socket, forward_to_addr, msg);
                 return;
             }
         }
-        let membership = {
-            let membership: Vec<(Member, Health)> = msg.take_membership()
-                .iter()
-                .map(|m| (Member::from(m.get_member()), Health::from(m.get_health())))
-                .collect();
-            membership
-        };
+        let membership = msg.take_membership().into_vec();
+        let from_id = String::from(msg.get_ack().get_from().get_id());
         match self.tx_outbound.send((addr, msg)) {
             Ok(()) => {}
             Err(e) => panic!("Outbound thread has died - this shouldn't happen: #{:?}", e),
         }
-        self.server.insert_member_from_rumors(membership);
+        self.server.insert_member_from_rumors(&from_id, membership);
     }
 
     /// Process ping messages.
@@ -214,11 +275,8 @@ impl<'a> Inbound<'a> {
             from
         };
         info!("Ping from {}@{}", from.get_id(), addr);
+        let from_id = String::from(from.get_id());
         self.server.insert_member(from.into(), Health::Alive);
-        let membership: Vec<(Member, Health)> = msg.take_membership()
-            .iter()
-            .map(|m| (Member::from(m.get_member()), Health::from(m.get_health())))
-            .collect();
-        self.server.insert_member_from_rumors(membership);
+        self.server.insert_member_from_rumors(&from_id, msg.take_membership().into_vec());
     }
 }
@@ -0,0 +1,217 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ring keys a server knows about.
+//!
+//! We encrypt everything we send with the primary key, and decrypt what we receive with whichever
+//! of our keys it was encrypted with. That lets a new ring key be rolled out without partitioning
+//! the ring: add the new key to every member, then make it the primary on every member, then
+//! remove the old key once nobody uses it any more.
+
+use std::result;
+
+use habitat_core::crypto::SymKey;
+use protobuf;
+use rustc_serialize::{Encoder, Encodable};
+
+use error::{Error, Result};
+use message::swim::Wire;
+
+#[derive(Debug, Clone, Default)]
+pub struct Keyring {
+    primary: Option<SymKey>,
+    keys: Vec<SymKey>,
+}
+
+impl Keyring {
+    /// Create a new Keyring that encrypts with the given key; with `None`, nothing is encrypted.
+    pub fn new(primary: Option<SymKey>) -> Keyring {
+        Keyring {
+            keys: primary.iter().cloned().collect(),
+            primary: primary,
+        }
+    }
+
+    /// The key we encrypt with, if any.
+    pub fn primary(&self) -> &Option<SymKey> {
+        &self.primary
+    }
+
+    /// The name and revision of every key we can decrypt with, the primary first.
+    pub fn names(&self) -> Vec<String> {
+        self.keys.iter().map(|k| k.name_with_rev()).collect()
+    }
+
+    /// Whether we know the key with the given name and revision.
+    pub fn contains(&self, name_with_rev: &str) -> bool {
+        self.keys.iter().any(|k| k.name_with_rev() == name_with_rev)
+    }
+
+    /// Add a key to decrypt with. Returns false if we already knew it.
+    pub fn add(&mut self, key: SymKey) -> bool {
+        if self.contains(&key.name_with_rev()) {
+            return false;
+        }
+        self.keys.push(key);
+        true
+    }
+
+    /// Start encrypting with a key we already know.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::UnknownRingKey` if we don't know the key
+    pub fn set_primary(&mut self, name_with_rev: &str) -> Result<()> {
+        match self.keys.iter().position(|k| k.name_with_rev() == name_with_rev) {
+            Some(index) => {
+                let key = self.keys.remove(index);
+                self.primary = Some(key.clone());
+                self.keys.insert(0, key);
+                Ok(())
+            }
+            None => Err(Error::UnknownRingKey(String::from(name_with_rev))),
+        }
+    }
+
+    /// Forget a key, so that messages encrypted with it are no longer accepted.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::UnknownRingKey` if we don't know the key
+    /// * Returns `Error::PrimaryRingKey` if it is the key we encrypt with
+    pub fn remove(&mut self, name_with_rev: &str) -> Result<()> {
+        if self.primary.as_ref().map_or(false, |k| k.name_with_rev() == name_with_rev) {
+            return Err(Error::PrimaryRingKey(String::from(name_with_rev)));
+        }
+        match self.keys.iter().position(|k| k.name_with_rev() == name_with_rev) {
+            Some(index) => {
+                self.keys.remove(index);
+                Ok(())
+            }
+            None => Err(Error::UnknownRingKey(String::from(name_with_rev))),
+        }
+    }
+
+    /// Unwrap a message, decrypting it with whichever of our keys it was encrypted with. Messages
+    /// that are not encrypted are only accepted if we don't encrypt either.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::UndecryptableWire` if none of our keys decrypt the message, or if it is
+    /// not encrypted when it should be
+    pub fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
+        if !wire.get_encrypted() {
+            return match self.primary {
+                Some(_) => Err(Error::UndecryptableWire),
+                None => Ok(wire.take_payload()),
+            };
+        }
+        for key in self.keys.iter() {
+            if let Ok(decrypted) = key.decrypt(wire.get_nonce(), wire.get_payload()) {
+                return Ok(decrypted);
+            }
+        }
+        Err(Error::UndecryptableWire)
+    }
+}
+
+impl Encodable for Keyring {
+    fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
+        let primary = self.primary.as_ref().map(|k| k.name_with_rev());
+        try!(s.emit_struct("keyring", 2, |s| {
+            try!(s.emit_struct_field("primary", 0, |s| primary.encode(s)));
+            try!(s.emit_struct_field("keys", 1, |s| self.names().encode(s)));
+            Ok(())
+        }));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use error::Error;
+    use message;
+    use server::keyring::Keyring;
+
+    fn keys() -> (SymKey, SymKey) {
+        (SymKey::generate_in_memory("wolverine").unwrap(),
+         SymKey::generate_in_memory("cyclops").unwrap())
+    }
+
+    #[test]
+    fn decrypts_with_any_known_key() {
+        let (old, new) = keys();
+        let mut keyring = Keyring::new(Some(old.clone()));
+        keyring.add(new.clone());
+        let from_old = message::generate_wire(Vec::from("old"), &Some(old)).unwrap();
+        let from_new = message::generate_wire(Vec::from("new"), &Some(new)).unwrap();
+        assert_eq!(keyring.unwrap_wire(&from_old).unwrap(), Vec::from("old"));
+        assert_eq!(keyring.unwrap_wire(&from_new).unwrap(), Vec::from("new"));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let (old, new) = keys();
+        let keyring = Keyring::new(Some(old));
+        let from_new = message::generate_wire(Vec::from("new"), &Some(new)).unwrap();
+        match keyring.unwrap_wire(&from_new) {
+            Err(Error::UndecryptableWire) => {}
+            r => panic!("Expected an undecryptable wire, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn rejects_plaintext_once_we_encrypt() {
+        let (old, new) = keys();
+        let plaintext = message::generate_wire(Vec::from("plain"), &None).unwrap();
+
+        let mut keyring = Keyring::new(None);
+        keyring.add(new.clone());
+        assert_eq!(keyring.unwrap_wire(&plaintext).unwrap(), Vec::from("plain"));
+
+        let keyring = Keyring::new(Some(old));
+        assert!(keyring.unwrap_wire(&plaintext).is_err());
+    }
+
+    #[test]
+    fn set_primary_puts_the_key_first() {
+        let (old, new) = keys();
+        let mut keyring = Keyring::new(Some(old.clone()));
+        assert!(keyring.set_primary(&new.name_with_rev()).is_err());
+        assert!(keyring.add(new.clone()));
+        assert!(!keyring.add(new.clone()));
+        keyring.set_primary(&new.name_with_rev()).unwrap();
+        assert_eq!(keyring.primary().as_ref().unwrap().name_with_rev(),
+                   new.name_with_rev());
+        assert_eq!(keyring.names(), vec![new.name_with_rev(), old.name_with_rev()]);
+    }
+
+    #[test]
+    fn remove_keeps_the_primary() {
+        let (old, new) = keys();
+        let mut keyring = Keyring::new(Some(old.clone()));
+        keyring.add(new.clone());
+        keyring.set_primary(&new.name_with_rev()).unwrap();
+        match keyring.remove(&new.name_with_rev()) {
+            Err(Error::PrimaryRingKey(_)) => {}
+            r => panic!("Expected the primary to stay, got {:?}", r),
+        }
+        keyring.remove(&old.name_with_rev()).unwrap();
+        assert_eq!(keyring.names(), vec![new.name_with_rev()]);
+        assert!(keyring.remove(&old.name_with_rev()).is_err());
+    }
+}
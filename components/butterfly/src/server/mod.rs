@@ -15,42 +15,57 @@
 //! The Butterfly server.
 //!
 //! Creates `Server` structs, that hold everything we need to run the SWIM and Gossip protocol.
-//! Winds up with 5 separate threads - inbound (incoming connections), outbound (the Probe
+//! Winds up with 7 separate threads - inbound (incoming connections), outbound (the Probe
 //! protocol), expire (turning Suspect members into Confirmed members), push (the fan-out rumors),
-//! and pull (the inbound receipt of rumors.).
+//! pull (the inbound receipt of rumors.), and push-pull and push-pull-listener (periodic full
+//! state exchanges with a random member).
 
 pub mod expire;
 pub mod inbound;
+pub mod keyring;
 pub mod outbound;
 pub mod pull;
 pub mod push;
+pub mod push_pull;
+pub mod snapshot;
 pub mod timing;
 
 use std::collections::{HashSet, HashMap};
 use std::fmt;
 use std::io;
-use std::net::{ToSocketAddrs, UdpSocket, SocketAddr};
+use std::net::{ToSocketAddrs, TcpListener, UdpSocket, SocketAddr};
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
 use std::sync::mpsc::channel;
 use std::time::Duration;
 use std::thread;
 
 use habitat_core::service::ServiceGroup;
 use habitat_core::crypto::SymKey;
+use time::Duration as TimeDuration;
 use rustc_serialize::{Encoder, Encodable};
 
 use error::{Result, Error};
 use member::{Member, Health, MemberList};
 use trace::{Trace, TraceKind};
 use rumor::{Rumor, RumorStore, RumorList, RumorKey};
+use rumor::departure::Departure;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
+use rumor::tombstone::Tombstones;
 use rumor::election::{Election, ElectionUpdate};
 use message;
+use message::swim::{Membership as ProtoMembership, Rumor_Type};
+
+/// The highest our local health multiplier goes; probe timeouts stretch to at most this many
+/// times their configured length, plus one.
+const LOCAL_HEALTH_MULTIPLIER_MAX: usize = 8;
+
+/// How long the service rumor of a service we stopped running stays buried, in milliseconds.
+const WITHDRAWN_SERVICE_TTL_MS: i64 = 3_600_000;
 
 /// The server struct. Is thread-safe.
 #[derive(Debug, Clone)]
@@ -59,13 +74,17 @@ pub struct Server {
     pub member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    pub ring_key: Arc<Option<SymKey>>,
+    pub keyring: Arc<RwLock<keyring::Keyring>>,
     pub rumor_list: RumorList,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
     pub service_file_store: RumorStore<ServiceFile>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    pub departure_store: RumorStore<Departure>,
+    pub departed: Arc<AtomicBool>,
+    pub tombstones: Tombstones,
+    pub local_health: Arc<AtomicUsize>,
     pub swim_addr: Arc<RwLock<SocketAddr>>,
     pub gossip_addr: Arc<RwLock<SocketAddr>>,
     // These are all here for testing support
@@ -95,13 +114,17 @@ impl Server {
                 member_id: Arc::new(String::from(member.get_id())),
                 member: Arc::new(RwLock::new(member)),
                 member_list: MemberList::new(),
-                ring_key: Arc::new(ring_key),
+                keyring: Arc::new(RwLock::new(keyring::Keyring::new(ring_key))),
                 rumor_list: RumorList::default(),
                 service_store: RumorStore::default(),
                 service_config_store: RumorStore::default(),
                 service_file_store: RumorStore::default(),
                 election_store: RumorStore::default(),
                 update_store: RumorStore::default(),
+                departure_store: RumorStore::default(),
+                departed: Arc::new(AtomicBool::new(false)),
+                tombstones: Tombstones::default(),
+                local_health: Arc::new(AtomicUsize::new(0)),
                 swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                 gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                 pause: Arc::new(AtomicBool::new(false)),
@@ -163,12 +186,36 @@ impl Server {
         }
     }
 
+    /// Our local health multiplier, from zero to `LOCAL_HEALTH_MULTIPLIER_MAX`. It rises when we
+    /// miss acks or have to refute suspicion about ourselves, and falls as probes succeed; the
+    /// outbound thread stretches its probe timeouts by it, so that a member that is slow to
+    /// process acks does not blame its peers for it.
+    pub fn local_health_multiplier(&self) -> usize {
+        self.local_health.load(Ordering::Relaxed)
+    }
+
+    /// Raise the local health multiplier by one, up to `LOCAL_HEALTH_MULTIPLIER_MAX`.
+    pub fn increase_local_health_multiplier(&self) {
+        let current = self.local_health.load(Ordering::Relaxed);
+        if current < LOCAL_HEALTH_MULTIPLIER_MAX {
+            self.local_health.compare_and_swap(current, current + 1, Ordering::Relaxed);
+        }
+    }
+
+    /// Lower the local health multiplier by one, down to zero.
+    pub fn decrease_local_health_multiplier(&self) {
+        let current = self.local_health.load(Ordering::Relaxed);
+        if current > 0 {
+            self.local_health.compare_and_swap(current, current - 1, Ordering::Relaxed);
+        }
+    }
+
     /// Start the server, along with a `Timing` for outbound connections. Spawns the `inbound`,
-    /// `outbound`, and `expire` threads.
+    /// `outbound`, `expire`, `pull`, `push` and `push_pull` threads.
     ///
     /// # Errors
     ///
-    /// * Returns `Error::CannotBind` if the socket cannot be bound
+    /// * Returns `Error::CannotBind` if the socket, or the push-pull listener, cannot be bound
     /// * Returns `Error::SocketSetReadTimeout` if the socket read timeout cannot be set
     /// * Returns `Error::SocketSetWriteTimeout` if the socket write timeout cannot be set
     pub fn start(&self, timing: timing::Timing) -> Result<()> {
@@ -179,7 +226,8 @@ impl Server {
                 Ok(socket) => socket,
                 Err(e) => return Err(Error::CannotBind(e)),
             };
-        try!(socket.set_read_timeout(Some(Duration::from_millis(1000)))
+        // The inbound thread wakes up at least this often to send the Nacks that are due.
+        try!(socket.set_read_timeout(Some(Duration::from_millis(100)))
             .map_err(|e| Error::SocketSetReadTimeout(e)));
         try!(socket.set_write_timeout(Some(Duration::from_millis(1000)))
             .map_err(|e| Error::SocketSetReadTimeout(e)));
@@ -190,8 +238,9 @@ impl Server {
             Ok(socket_a) => socket_a,
             Err(_) => return Err(Error::SocketCloneError),
         };
+        let timing_a = timing.clone();
         let _ = thread::Builder::new().name(format!("inbound-{}", self.name())).spawn(move || {
-            inbound::Inbound::new(&server_a, socket_a, tx_outbound).run();
+            inbound::Inbound::new(&server_a, socket_a, tx_outbound, timing_a).run();
             panic!("You should never, ever get here, judy");
         });
 
@@ -219,12 +268,32 @@ impl Server {
             panic!("You should never, ever get here, davey");
         });
 
+        let listener = match TcpListener::bind(self.swim_addr()) {
+            Ok(listener) => listener,
+            Err(e) => return Err(Error::CannotBind(e)),
+        };
+
         let server_e = self.clone();
+        let timing_e = timing.clone();
         let _ = thread::Builder::new().name(format!("push-{}", self.name())).spawn(move || {
-            push::Push::new(&server_e, timing).run();
+            push::Push::new(&server_e, timing_e).run();
             panic!("You should never, ever get here, liu");
         });
 
+        let server_f = self.clone();
+        let _ = thread::Builder::new()
+            .name(format!("push-pull-listener-{}", self.name()))
+            .spawn(move || {
+                push_pull::Listener::new(&server_f, listener).run();
+                panic!("You should never, ever get here, margaret");
+            });
+
+        let server_g = self.clone();
+        let _ = thread::Builder::new().name(format!("push-pull-{}", self.name())).spawn(move || {
+            push_pull::PushPull::new(&server_g, timing).run();
+            panic!("You should never, ever get here, oscar");
+        });
+
         Ok(())
     }
 
@@ -261,6 +330,40 @@ impl Server {
         self.pause.load(Ordering::Relaxed)
     }
 
+    /// Add a ring key we accept messages encrypted with, without encrypting with it yet. Returns
+    /// false if we already knew it.
+    pub fn add_ring_key(&self, key: SymKey) -> bool {
+        self.keyring.write().expect("Keyring lock is poisoned").add(key)
+    }
+
+    /// Start encrypting with a ring key, which we must already know. Messages encrypted with our
+    /// other ring keys are still accepted.
+    pub fn set_primary_ring_key(&self, name_with_rev: &str) -> Result<()> {
+        self.keyring.write().expect("Keyring lock is poisoned").set_primary(name_with_rev)
+    }
+
+    /// Stop accepting messages encrypted with a ring key. The primary ring key cannot be removed.
+    pub fn remove_ring_key(&self, name_with_rev: &str) -> Result<()> {
+        self.keyring.write().expect("Keyring lock is poisoned").remove(name_with_rev)
+    }
+
+    /// Announce that this member is leaving the ring on purpose. Peers mark us as Departed as soon
+    /// as the rumor reaches them, rather than suspecting and confirming us over the course of the
+    /// suspicion timeout. Once we have departed, we no longer refute rumors about our own health.
+    pub fn depart(&self) {
+        self.departed.store(true, Ordering::SeqCst);
+        let incarnation = self.member.read().expect("Member lock is poisoned").get_incarnation();
+        let departure = Departure::new(self.member_id(), incarnation);
+        let rk = RumorKey::from(&departure);
+        self.departure_store.insert(departure);
+        self.rumor_list.insert(rk);
+    }
+
+    /// Whether this server has announced its departure.
+    pub fn departed(&self) -> bool {
+        self.departed.load(Ordering::SeqCst)
+    }
+
     /// Return the swim address we are bound to
     pub fn swim_addr(&self) -> SocketAddr {
         let sa = self.swim_addr.read().expect("Swim Address lock poisoned");
@@ -294,8 +397,11 @@ impl Server {
         &self.name
     }
 
-    /// Insert a member to the `MemberList`, and update its `RumorKey` appropriately.
+    /// Insert a member to the `MemberList`, and update its `RumorKey` appropriately. We only call
+    /// this when we have heard from the member directly, which proves it is alive even if we had
+    /// reaped it.
     pub fn insert_member(&self, member: Member, health: Health) {
+        self.tombstones.exhume_member(member.get_id());
         let rk: RumorKey = RumorKey::from(&member);
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
         // of just how the logic goes. The value of the trace is really high, though, so we suck it
@@ -304,6 +410,9 @@ impl Server {
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
         if self.member_list.insert(member, health) {
+            if health == Health::Alive {
+                self.forget_departure(&trace_member_id, trace_incarnation);
+            }
             trace_it!(MEMBERSHIP: self,
                       TraceKind::MemberUpdate,
                       trace_member_id,
@@ -333,17 +442,31 @@ impl Server {
     }
 
     /// Given a membership record and some health, insert it into the Member List.
-    pub fn insert_member_from_rumor(&self, member: Member, mut health: Health) {
+    ///
+    /// Rumors that we are anything other than alive are refuted by bumping our incarnation, and
+    /// the refutation is gossiped like any other membership change. Rumors about an incarnation
+    /// older than our current one have already been refuted, so they are simply ignored.
+    pub fn insert_member_from_rumor(&self, mut member: Member, mut health: Health) {
+        if self.tombstones.member_is_buried(member.get_id(), member.get_incarnation()) {
+            debug!("Ignoring rumor about reaped member {}", member.get_id());
+            return;
+        }
+        self.tombstones.exhume_member(member.get_id());
         let mut incremented_incarnation = false;
         let rk: RumorKey = RumorKey::from(&member);
         if member.get_id() == self.member_id() {
-            if health != Health::Alive {
+            if health != Health::Alive && !self.departed() {
                 let mut me = self.member.write().expect("Member lock is poisoned");
-                let mut incarnation = me.get_incarnation();
-                incarnation += 1;
-                me.set_incarnation(incarnation);
+                if member.get_incarnation() >= me.get_incarnation() {
+                    me.set_incarnation(member.get_incarnation() + 1);
+                    incremented_incarnation = true;
+                    // Being suspected is a hint that we are the slow one.
+                    if health == Health::Suspect {
+                        self.increase_local_health_multiplier();
+                    }
+                }
+                member = me.clone();
                 health = Health::Alive;
-                incremented_incarnation = true;
             }
         }
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
@@ -354,6 +477,9 @@ impl Server {
         let trace_health = health.clone();
 
         if self.member_list.insert(member, health) || incremented_incarnation {
+            if health == Health::Alive {
+                self.forget_departure(&trace_member_id, trace_incarnation);
+            }
             trace_it!(MEMBERSHIP: self,
                       TraceKind::MemberUpdate,
                       trace_member_id,
@@ -363,24 +489,102 @@ impl Server {
         }
     }
 
-    /// Insert members from a list of received rumors.
-    pub fn insert_member_from_rumors(&self, members: Vec<(Member, Health)>) {
-        for (member, health) in members.into_iter() {
+    /// Insert members from a list of membership rumors received from `from_id`. A suspect rumor
+    /// names the member that started the suspicion, which is kept as the rumor is relayed; when
+    /// it does not, `from_id` started it. Each distinct member that suspects a member we already
+    /// suspect counts as an independent confirmation of our suspicion, which shortens how long it
+    /// takes to confirm. Relays of the same suspicion never count twice.
+    pub fn insert_member_from_rumors(&self, from_id: &str, memberships: Vec<ProtoMembership>) {
+        for mut membership in memberships.into_iter() {
+            let health = Health::from(membership.get_health());
+            let suspected_by = if membership.has_suspected_by() {
+                membership.take_suspected_by()
+            } else {
+                String::from(from_id)
+            };
+            let member = Member::from(membership.take_member());
+            let member_id = String::from(member.get_id());
             self.insert_member_from_rumor(member, health);
+            if health == Health::Suspect &&
+               self.member_list.check_health_of_by_id(&member_id, Health::Suspect) {
+                self.member_list.confirm_suspicion(&member_id, &suspected_by);
+            }
         }
     }
 
     /// Insert a service rumor into the service store.
     pub fn insert_service(&self, service: Service) {
+        if self.tombstones.has_member(service.get_member_id()) {
+            debug!("Ignoring service rumor from reaped member {}", service.get_member_id());
+            return;
+        }
         let rk = RumorKey::from(&service);
+        if self.tombstones.rumor_is_buried(&rk, service.get_incarnation()) {
+            debug!("Ignoring withdrawn service rumor {}", rk.key());
+            return;
+        }
+        let service_group = String::from(service.get_service_group());
         if self.service_store.insert(service) {
+            self.tombstones.exhume_service_group(&service_group);
             self.rumor_list.insert(rk);
         }
     }
 
+    /// Withdraw our service rumor for a service group we no longer run. The rumor is removed and
+    /// buried, and the tombstone is gossiped, so that the rest of the ring drops it too rather
+    /// than gossiping it back to us.
+    pub fn withdraw_service(&self, service_group: &str) {
+        let mut incarnation = None;
+        self.service_store.with_rumor(service_group, self.member_id(), |s| {
+            incarnation = s.map(|s| s.get_incarnation());
+        });
+        if let Some(incarnation) = incarnation {
+            self.bury_service(service_group,
+                              self.member_id(),
+                              incarnation,
+                              TimeDuration::milliseconds(WITHDRAWN_SERVICE_TTL_MS));
+        }
+    }
+
+    /// Remove and bury a member's service rumor, as of `incarnation`, for `ttl`, and gossip the
+    /// tombstone. A rumor newer than the tombstone is kept; the member has run the service again
+    /// since it withdrew it.
+    pub fn bury_service(&self,
+                        service_group: &str,
+                        member_id: &str,
+                        incarnation: u64,
+                        ttl: TimeDuration) {
+        let rk = RumorKey::new(Rumor_Type::Service, member_id, service_group);
+        if self.tombstones.rumor_is_buried(&rk, incarnation) {
+            return;
+        }
+        let mut newer = false;
+        self.service_store.with_rumor(service_group, member_id, |s| {
+            newer = s.map_or(false, |s| s.get_incarnation() > incarnation);
+        });
+        if newer {
+            return;
+        }
+        debug!("Burying service rumor {}", rk.key());
+        self.service_store.remove(service_group, member_id);
+        self.rumor_list.remove(&rk);
+        self.tombstones.bury_rumor(rk, incarnation, ttl);
+        self.rumor_list.insert(RumorKey::new(Rumor_Type::Tombstone, member_id, service_group));
+    }
+
+    /// The incarnation our service rumor for a service group was withdrawn at, if it is still
+    /// buried. Running the service again has to come in above it.
+    pub fn withdrawn_service_incarnation(&self, service_group: &str) -> Option<u64> {
+        let rk = RumorKey::new(Rumor_Type::Service, self.member_id(), service_group);
+        self.tombstones.buried_rumor(&rk).map(|(incarnation, _)| incarnation)
+    }
+
     /// Insert a service file rumor into the service store.
     pub fn insert_service_config(&self, service_config: ServiceConfig) {
         let rk = RumorKey::from(&service_config);
+        if self.tombstones.rumor_is_buried(&rk, service_config.get_incarnation()) {
+            return;
+        }
         if self.service_config_store.insert(service_config) {
             self.rumor_list.insert(rk);
         }
@@ -389,11 +593,119 @@ impl Server {
     /// Insert a service file rumor into the service store.
     pub fn insert_service_file(&self, service_file: ServiceFile) {
         let rk = RumorKey::from(&service_file);
+        if self.tombstones.rumor_is_buried(&rk, service_file.get_incarnation()) {
+            return;
+        }
         if self.service_file_store.insert(service_file) {
             self.rumor_list.insert(rk);
         }
     }
 
+    /// Insert a departure rumor into the departure store, and mark the departing member as
+    /// Departed. A departure naming us that arrives before we have departed is left over from a
+    /// previous run; the Departed membership rumors that come with it are refuted as usual. A
+    /// departure from an incarnation older than the member's current one is left over too: the
+    /// member has come back since, so it is ignored.
+    pub fn insert_departure(&self, departure: Departure) {
+        if departure.get_member_id() == self.member_id() && !self.departed() {
+            return;
+        }
+        if self.tombstones.has_member(departure.get_member_id()) {
+            debug!("Ignoring departure of reaped member {}", departure.get_member_id());
+            return;
+        }
+        let mut current_incarnation = None;
+        self.member_list.with_member(departure.get_member_id(), |has_member| {
+            current_incarnation = has_member.map(|m| m.get_incarnation());
+        });
+        if current_incarnation.map_or(false, |i| i > departure.get_incarnation()) {
+            debug!("Ignoring departure of {} from an older incarnation",
+                   departure.get_member_id());
+            return;
+        }
+        let rk = RumorKey::from(&departure);
+        let member_id = String::from(departure.get_member_id());
+        if self.departure_store.insert(departure) {
+            self.rumor_list.insert(rk);
+            if self.member_list.contains_member(&member_id) &&
+               self.member_list.insert_health_by_id(&member_id, Health::Departed) {
+                self.rumor_list.insert(RumorKey::new(Rumor_Type::Member, member_id, ""));
+            }
+        }
+    }
+
+    /// Forget the departure of a member that has come back at a higher incarnation, so that it
+    /// isn't gossiped to members that would mark it as Departed again.
+    fn forget_departure(&self, member_id: &str, incarnation: u64) {
+        let mut stale = false;
+        self.departure_store.with_rumor("departure", member_id, |has_departure| {
+            stale = has_departure.map_or(false, |d| d.get_incarnation() < incarnation);
+        });
+        if stale {
+            self.departure_store.remove("departure", member_id);
+            self.rumor_list.remove(&RumorKey::new(Rumor_Type::Departure, member_id, "departure"));
+        }
+    }
+
+    /// Reap a member that has been confirmed or departed for too long. The member, its departure
+    /// and its service rumors are removed from every store; a service group it leaves without any
+    /// members loses its elections, service configs and service files too. Everything removed is
+    /// buried for `ttl`, so that it isn't gossiped back in by members that haven't reaped it yet.
+    pub fn reap_member(&self, member_id: &str, ttl: TimeDuration) {
+        let member = match self.member_list.remove(member_id) {
+            Some(member) => member,
+            None => return,
+        };
+        info!("Reaping member {}", member_id);
+        self.tombstones.bury_member(member_id, member.get_incarnation(), ttl);
+        self.rumor_list.remove(&RumorKey::from(&member));
+        self.departure_store.remove("departure", member_id);
+        self.rumor_list.remove(&RumorKey::new(Rumor_Type::Departure, member_id, "departure"));
+
+        let mut service_groups = Vec::new();
+        self.service_store.with_keys(|(service_group, rumors)| {
+            if rumors.contains_key(member_id) {
+                service_groups.push(service_group.clone());
+            }
+        });
+        for service_group in service_groups.iter() {
+            self.service_store.remove(service_group, member_id);
+            self.rumor_list
+                .remove(&RumorKey::new(Rumor_Type::Service, member_id, &service_group[..]));
+            if self.service_store.len_for_key(service_group) == 0 {
+                self.reap_service_group(service_group, ttl);
+            }
+        }
+    }
+
+    /// Remove, and bury, the elections, service configs and service files of a service group with
+    /// no members left.
+    fn reap_service_group(&self, service_group: &str, ttl: TimeDuration) {
+        let mut buried = Vec::new();
+        self.election_store.with_rumors(service_group, |e| {
+            buried.push((RumorKey::from(e), e.get_term()));
+        });
+        self.update_store.with_rumors(service_group, |e| {
+            buried.push((RumorKey::from(e), e.get_term()));
+        });
+        self.service_config_store.with_rumors(service_group, |c| {
+            buried.push((RumorKey::from(c), c.get_incarnation()));
+        });
+        self.service_file_store.with_rumors(service_group, |f| {
+            buried.push((RumorKey::from(f), f.get_incarnation()));
+        });
+        for (rk, version) in buried.into_iter() {
+            match rk.kind {
+                Rumor_Type::Election => self.election_store.remove(&rk.key, &rk.id),
+                Rumor_Type::ElectionUpdate => self.update_store.remove(&rk.key, &rk.id),
+                Rumor_Type::ServiceConfig => self.service_config_store.remove(&rk.key, &rk.id),
+                _ => self.service_file_store.remove(&rk.key, &rk.id),
+            }
+            self.rumor_list.remove(&rk);
+            self.tombstones.bury_rumor(rk, version, ttl);
+        }
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     pub fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -409,11 +721,17 @@ impl Server {
     /// Check if a given service group has quorum to run an election.
     ///
     /// A given group has quorum if, from this servers perspective, it has an alive population that
-    /// is over 50%, and at least 3 members.
+    /// is over 50%, and at least 3 members. Members that have departed left on purpose, so they
+    /// no longer count towards the population.
     pub fn check_quorum(&self, key: &str) -> bool {
         let electorate = self.get_electorate(key);
 
-        let total_population = self.service_store.len_for_key(key);
+        let mut total_population = 0;
+        self.service_store.with_rumors(key, |s| {
+            if !self.member_list.check_health_of_by_id(s.get_member_id(), Health::Departed) {
+                total_population += 1;
+            }
+        });
         let alive_population = electorate.len();
 
         if total_population < 3 {
@@ -439,6 +757,18 @@ impl Server {
         self.rumor_list.insert(ek);
     }
 
+    /// Returns the suitability this member declares for the given service group in its service
+    /// rumor, or 0 if it has declared none.
+    pub fn suitability_for(&self, service_group: &str) -> u64 {
+        let mut suitability = 0;
+        self.service_store.with_rumor(service_group, self.member_id(), |service| {
+            if let Some(service) = service {
+                suitability = service.get_suitability();
+            }
+        });
+        suitability
+    }
+
     pub fn start_update_election(&self, sg: ServiceGroup, suitability: u64, term: u64) {
         let mut e = ElectionUpdate::new(self.member_id(), sg, suitability);
         e.set_term(term);
@@ -451,10 +781,17 @@ impl Server {
         self.rumor_list.insert(ek);
     }
 
+    /// Whether the given leader is confirmed dead, or has departed.
+    fn leader_is_gone(&self, member_id: &str) -> bool {
+        self.member_list.check_health_of_by_id(member_id, Health::Confirmed) ||
+        self.member_list.check_health_of_by_id(member_id, Health::Departed)
+    }
+
     /// Check to see if this server needs to restart a given election. This happens when:
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
-    /// b) We are not the leader, and we have detected that the leader is confirmed dead.
+    /// b) We are not the leader, and we have detected that the leader is confirmed dead or has
+    ///    departed.
     pub fn restart_elections(&self) {
         let mut elections_to_restart = vec![];
         let mut update_elections_to_restart = vec![];
@@ -473,8 +810,7 @@ impl Server {
 
                     }
                 } else if election.is_finished() {
-                    if self.member_list
-                        .check_health_of_by_id(election.get_member_id(), Health::Confirmed) {
+                    if self.leader_is_gone(election.get_member_id()) {
                             warn!("Restarting election with a new term as the leader is dead {}: {:?}", self.member_id(), election);
                             elections_to_restart.push((String::from(&service_group[..]), election.get_term()));
                     }
@@ -496,8 +832,7 @@ impl Server {
 
                     }
                 } else if election.is_finished() {
-                    if self.member_list
-                        .check_health_of_by_id(election.get_member_id(), Health::Confirmed) {
+                    if self.leader_is_gone(election.get_member_id()) {
                             warn!("Restarting election with a new term as the leader is dead {}: {:?}", self.member_id(), election);
                             update_elections_to_restart.push((String::from(&service_group[..]), election.get_term()));
                     }
@@ -518,7 +853,8 @@ impl Server {
             let term = old_term + 1;
             warn!("Starting a new election for {} {}", sg, term);
             self.election_store.remove(&service_group, "election");
-            self.start_election(sg, 0, term);
+            let suitability = self.suitability_for(&service_group);
+            self.start_election(sg, suitability, term);
         }
 
         for (service_group, old_term) in update_elections_to_restart {
@@ -543,6 +879,9 @@ impl Server {
     /// stopping the election if we are the winner and we have enough votes.
     pub fn insert_election(&self, mut election: Election) {
         let rk = RumorKey::from(&election);
+        if self.tombstones.rumor_is_buried(&rk, election.get_term()) {
+            return;
+        }
 
         // If this is an election for a service group we care about
         if self.service_store.contains_rumor(election.get_service_group(), self.member_id()) {
@@ -561,7 +900,8 @@ impl Server {
                             return;
                         }
                     };
-                    self.start_election(sg, 0, election.get_term());
+                    let suitability = self.suitability_for(election.get_service_group());
+                    self.start_election(sg, suitability, election.get_term());
                 }
                 // If we are the member that this election is voting for, then check to see if the election
                 // is over! If it is, mark this election as final before you process it.
@@ -597,7 +937,8 @@ impl Server {
                         return;
                     }
                 };
-                self.start_election(sg, 0, election.get_term());
+                let suitability = self.suitability_for(election.get_service_group());
+                self.start_election(sg, suitability, election.get_term());
             }
             if !election.is_finished() {
                 let has_quorum = self.check_quorum(election.key());
@@ -615,6 +956,9 @@ impl Server {
 
     pub fn insert_update_election(&self, mut election: ElectionUpdate) {
         let rk = RumorKey::from(&election);
+        if self.tombstones.rumor_is_buried(&rk, election.get_term()) {
+            return;
+        }
 
         // If this is an election for a service group we care about
         if self.service_store.contains_rumor(election.get_service_group(), self.member_id()) {
@@ -732,22 +1076,24 @@ impl Server {
     }
 
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, &self.ring_key)
+        let keyring = self.keyring.read().expect("Keyring lock is poisoned");
+        message::generate_wire(payload, keyring.primary())
     }
 
     fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, &self.ring_key)
+        self.keyring.read().expect("Keyring lock is poisoned").unwrap_wire(payload)
     }
 }
 
 impl Encodable for Server {
     fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
-        try!(s.emit_struct("butterfly", 5, |s| {
+        try!(s.emit_struct("butterfly", 6, |s| {
             try!(s.emit_struct_field("service", 0, |s| self.service_store.encode(s)));
             try!(s.emit_struct_field("service_config", 1, |s| self.service_config_store.encode(s)));
             try!(s.emit_struct_field("service_file", 2, |s| self.service_file_store.encode(s)));
             try!(s.emit_struct_field("election", 3, |s| self.election_store.encode(s)));
             try!(s.emit_struct_field("election_update", 4, |s| self.update_store.encode(s)));
+            try!(s.emit_struct_field("departure", 5, |s| self.departure_store.encode(s)));
             Ok(())
         }));
         Ok(())
@@ -767,9 +1113,24 @@ impl fmt::Display for Server {
 #[cfg(test)]
 mod tests {
     mod server {
-        use server::Server;
+        use std::str::FromStr;
+
+        use habitat_core::package::PackageIdent;
+        use habitat_core::service::ServiceGroup;
+        use time::Duration;
+
+        use rumor::RumorKey;
+        use rumor::departure::Departure;
+        use rumor::election::Election;
+        use rumor::service::Service;
+        use rumor::service_config::ServiceConfig;
+        use rumor::service_file::ServiceFile;
+        use rumor::tombstone::tombstone_rumor;
+        use message::swim::{Membership, Membership_Health, Rumor_Type, Swim};
+        use server::{Server, LOCAL_HEALTH_MULTIPLIER_MAX};
+        use server::{outbound, pull};
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
         use trace::Trace;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
@@ -818,5 +1179,278 @@ mod tests {
             let server = start_server();
             server.start(Timing::default()).expect("Server failed to start");
         }
+
+        #[test]
+        fn suitability_for_comes_from_our_service_rumor() {
+            let server = start_server();
+            let ident = PackageIdent::from_str("core/redis/3.2.4/20161215000000").unwrap();
+            assert_eq!(server.suitability_for("redis.default"), 0);
+            let mut service = Service::new(server.member_id(),
+                                           &ident,
+                                           "default",
+                                           None,
+                                           "localhost",
+                                           "127.0.0.1",
+                                           vec![6379]);
+            service.set_suitability(42);
+            server.insert_service(service);
+            assert_eq!(server.suitability_for("redis.default"), 42);
+        }
+
+        #[test]
+        fn departed_members_leave_the_quorum() {
+            let server = start_server();
+            let ident = PackageIdent::from_str("core/redis/3.2.4/20161215000000").unwrap();
+            let mut members = vec![String::from(server.member_id())];
+            for _ in 0..4 {
+                let member = Member::new();
+                members.push(String::from(member.get_id()));
+                server.insert_member(member, Health::Alive);
+            }
+            for member_id in members.iter() {
+                server.insert_service(Service::new(&member_id[..],
+                                                   &ident,
+                                                   "default",
+                                                   None,
+                                                   "localhost",
+                                                   "127.0.0.1",
+                                                   vec![6379]));
+            }
+            server.member_list.insert_health_by_id(server.member_id(), Health::Alive);
+            for member_id in members[1..4].iter() {
+                server.member_list.insert_health_by_id(member_id, Health::Confirmed);
+            }
+            assert_eq!(server.check_quorum("redis.default"), false);
+
+            server.insert_departure(Departure::new(&members[1][..], 0));
+            server.insert_departure(Departure::new(&members[2][..], 0));
+            assert_eq!(server.member_list.health_of_by_id(&members[1]),
+                       Some(Health::Departed));
+            assert!(server.check_quorum("redis.default"));
+        }
+
+        #[test]
+        fn reaped_members_and_their_rumors_stay_gone() {
+            let server = start_server();
+            let ident = PackageIdent::from_str("core/redis/3.2.4/20161215000000").unwrap();
+            let sg = ServiceGroup::new("redis", "default", None);
+            let member = Member::new();
+            let member_id = String::from(member.get_id());
+            let service = Service::new(&member_id[..],
+                                       &ident,
+                                       "default",
+                                       None,
+                                       "localhost",
+                                       "127.0.0.1",
+                                       vec![6379]);
+            let service_file = ServiceFile::new(&member_id[..], sg.clone(), "foo.toml", vec![]);
+            let service_config = ServiceConfig::new(&member_id[..], sg.clone(), vec![]);
+            server.insert_member(member.clone(), Health::Confirmed);
+            server.insert_service(service.clone());
+            server.insert_service_file(service_file.clone());
+            server.insert_service_config(service_config.clone());
+            server.election_store.insert(Election::new(&member_id[..], sg.clone(), 0));
+
+            server.reap_member(&member_id, Duration::minutes(1));
+            assert!(!server.member_list.contains_member(&member_id));
+            assert!(!server.service_store.contains_rumor("redis.default", &member_id));
+            assert!(!server.service_file_store.contains_rumor("redis.default", "foo.toml"));
+            assert!(!server.service_config_store.contains_rumor("redis.default", "service_config"));
+            assert!(!server.election_store.contains_rumor("redis.default", "election"));
+
+            server.insert_member_from_rumor(member.clone(), Health::Confirmed);
+            server.insert_service(service);
+            server.insert_service_file(service_file);
+            server.insert_service_config(service_config);
+            server.insert_departure(Departure::new(&member_id[..], 0));
+            assert!(!server.member_list.contains_member(&member_id));
+            assert!(!server.departure_store.contains_rumor("departure", &member_id));
+            assert!(!server.service_config_store.contains_rumor("redis.default", "service_config"));
+            assert!(!server.service_store.contains_rumor("redis.default", &member_id));
+            assert!(!server.service_file_store.contains_rumor("redis.default", "foo.toml"));
+
+            let mut returned = member.clone();
+            returned.set_incarnation(1);
+            server.insert_member_from_rumor(returned, Health::Alive);
+            assert!(server.member_list.check_health_of_by_id(&member_id, Health::Alive));
+        }
+
+        #[test]
+        fn withdrawn_services_stay_gone_until_they_come_back_newer() {
+            let server = start_server();
+            let ident = PackageIdent::from_str("core/redis/3.2.4/20161215000000").unwrap();
+            let mut service = Service::new(server.member_id(),
+                                           &ident,
+                                           "default",
+                                           None,
+                                           "localhost",
+                                           "127.0.0.1",
+                                           vec![6379]);
+            server.insert_service(service.clone());
+
+            server.withdraw_service("redis.default");
+            assert!(!server.service_store.contains_rumor("redis.default", server.member_id()));
+            assert_eq!(server.withdrawn_service_incarnation("redis.default"), Some(0));
+            server.insert_service(service.clone());
+            assert!(!server.service_store.contains_rumor("redis.default", server.member_id()));
+
+            service.set_incarnation(1);
+            server.insert_service(service);
+            assert!(server.service_store.contains_rumor("redis.default", server.member_id()));
+        }
+
+        #[test]
+        fn gossiped_tombstones_withdraw_services() {
+            let server = start_server();
+            let ident = PackageIdent::from_str("core/redis/3.2.4/20161215000000").unwrap();
+            let member = Member::new();
+            let service = Service::new(member.get_id(),
+                                       &ident,
+                                       "default",
+                                       None,
+                                       "localhost",
+                                       "127.0.0.1",
+                                       vec![6379]);
+            server.insert_service(service);
+            let rk = RumorKey::new(Rumor_Type::Service, member.get_id(), "redis.default");
+            pull::insert_rumor(&server,
+                               tombstone_rumor(member.get_id(), rk, 0, Duration::minutes(1)));
+            assert!(!server.service_store.contains_rumor("redis.default", member.get_id()));
+        }
+
+        #[test]
+        fn departures_about_us_are_ignored_until_we_depart() {
+            let server = start_server();
+            server.insert_departure(Departure::new(server.member_id(), 0));
+            assert_eq!(server.departed(), false);
+            assert!(!server.departure_store.contains_rumor("departure", server.member_id()));
+
+            server.depart();
+            assert!(server.departed());
+            assert!(server.departure_store.contains_rumor("departure", server.member_id()));
+        }
+
+        #[test]
+        fn departures_do_not_outlive_the_incarnation_they_were_for() {
+            let server = start_server();
+            let mut member = Member::new();
+            member.set_incarnation(1);
+            let member_id = String::from(member.get_id());
+            server.insert_member(member.clone(), Health::Alive);
+
+            server.insert_departure(Departure::new(&member_id[..], 0));
+            assert!(!server.departure_store.contains_rumor("departure", &member_id));
+            assert!(server.member_list.check_health_of_by_id(&member_id, Health::Alive));
+
+            server.insert_departure(Departure::new(&member_id[..], 1));
+            assert!(server.member_list.check_health_of_by_id(&member_id, Health::Departed));
+
+            member.set_incarnation(2);
+            server.insert_member_from_rumor(member, Health::Alive);
+            assert!(server.member_list.check_health_of_by_id(&member_id, Health::Alive));
+            assert!(!server.departure_store.contains_rumor("departure", &member_id));
+        }
+
+        #[test]
+        fn local_health_multiplier_stays_in_bounds() {
+            let server = start_server();
+            server.decrease_local_health_multiplier();
+            assert_eq!(server.local_health_multiplier(), 0);
+            for _ in 0..20 {
+                server.increase_local_health_multiplier();
+            }
+            assert_eq!(server.local_health_multiplier(), LOCAL_HEALTH_MULTIPLIER_MAX);
+            server.decrease_local_health_multiplier();
+            assert_eq!(server.local_health_multiplier(), LOCAL_HEALTH_MULTIPLIER_MAX - 1);
+        }
+
+        #[test]
+        fn refuting_suspicion_outbids_the_rumor_and_raises_local_health() {
+            let server = start_server();
+            let mut suspected = server.member.read().expect("Member lock is poisoned").clone();
+            suspected.set_incarnation(3);
+            server.insert_member_from_rumor(suspected.clone(), Health::Suspect);
+            assert_eq!(server.member.read().expect("Member lock is poisoned").get_incarnation(),
+                       4);
+            assert_eq!(server.local_health_multiplier(), 1);
+            assert!(server.member_list.check_health_of_by_id(server.member_id(), Health::Alive));
+
+            // Already refuted; nothing more to do.
+            server.insert_member_from_rumor(suspected, Health::Suspect);
+            assert_eq!(server.member.read().expect("Member lock is poisoned").get_incarnation(),
+                       4);
+            assert_eq!(server.local_health_multiplier(), 1);
+        }
+
+        fn suspect_rumor(member: &Member, suspected_by: Option<&str>) -> Membership {
+            let mut membership = Membership::new();
+            membership.set_member(member.proto.clone());
+            membership.set_health(Membership_Health::SUSPECT);
+            if let Some(suspected_by) = suspected_by {
+                membership.set_suspected_by(String::from(suspected_by));
+            }
+            membership
+        }
+
+        #[test]
+        fn suspicion_from_others_confirms_our_own() {
+            let server = start_server();
+            let member = Member::new();
+            server.insert_member(member.clone(), Health::Alive);
+            server.insert_member_from_rumors("foo", vec![suspect_rumor(&member, None)]);
+            server.insert_member_from_rumors("bar", vec![suspect_rumor(&member, None)]);
+            server.insert_member_from_rumors("bar", vec![suspect_rumor(&member, None)]);
+            // The first rumor started the suspicion; only "bar" confirmed it.
+            server.member_list
+                .with_suspects(|(_, suspicion)| assert_eq!(suspicion.confirmations.len(), 1));
+        }
+
+        #[test]
+        fn relayed_suspicion_does_not_shorten_the_timeout() {
+            let server = start_server();
+            let timing = Timing::default();
+            for _ in 0..9 {
+                server.insert_member(Member::new(), Health::Alive);
+            }
+            let member = Member::new();
+            server.insert_member(member.clone(), Health::Alive);
+            let members = server.member_list.len();
+            let lone_timeout = timing.suspicion_timeout_duration(members, 0);
+
+            for relay in ["foo", "bar", "baz", "quux"].iter() {
+                server.insert_member_from_rumors(relay, vec![suspect_rumor(&member, Some("adam"))]);
+            }
+            assert_eq!(server.member_list.suspected_by(member.get_id()),
+                       Some(String::from("adam")));
+            server.member_list.with_suspects(|(_, suspicion)| {
+                assert_eq!(timing.suspicion_timeout_duration(members,
+                                                             suspicion.confirmations.len()),
+                           lone_timeout);
+            });
+
+            // Members that suspect it on their own do shorten it, however they reach us.
+            for originator in ["foo", "bar"].iter() {
+                server.insert_member_from_rumors("baz",
+                                                 vec![suspect_rumor(&member, Some(*originator))]);
+            }
+            server.member_list.with_suspects(|(_, suspicion)| {
+                assert_eq!(suspicion.confirmations.len(), 2);
+                assert!(timing.suspicion_timeout_duration(members, suspicion.confirmations.len()) <
+                        lone_timeout);
+            });
+        }
+
+        #[test]
+        fn pings_to_suspects_carry_the_suspicion() {
+            let server = start_server();
+            let member = Member::new();
+            server.insert_member(member.clone(), Health::Alive);
+            server.insert_member(member.clone(), Health::Suspect);
+            let mut swim = Swim::new();
+            outbound::populate_membership_rumors(&server, &member, &mut swim);
+            let buddy = &swim.get_membership()[0];
+            assert_eq!(buddy.get_member().get_id(), member.get_id());
+            assert_eq!(buddy.get_health(), Membership_Health::SUSPECT);
+        }
     }
 }
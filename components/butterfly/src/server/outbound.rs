@@ -26,7 +26,7 @@ use std::fmt;
 use time::SteadyTime;
 use protobuf::{Message, RepeatedField};
 
-use message::swim::{Ack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
+use message::swim::{Ack, Nack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
 use server::Server;
 use server::timing::Timing;
 use member::{Member, Health};
@@ -107,7 +107,7 @@ impl<'a> Outbound<'a> {
 
             self.server.update_swim_round();
 
-            let long_wait = self.timing.next_protocol_period();
+            let long_wait = self.scaled_timing().next_protocol_period();
 
             let check_list = self.server
                 .member_list
@@ -122,7 +122,7 @@ impl<'a> Outbound<'a> {
                     // This is the timeout for the next protocol period - if we
                     // complete faster than this, we want to wait in the end
                     // until this timer expires.
-                    let next_protocol_period = self.timing.next_protocol_period();
+                    let next_protocol_period = self.scaled_timing().next_protocol_period();
 
                     self.probe(member);
 
@@ -145,6 +145,11 @@ impl<'a> Outbound<'a> {
         }
     }
 
+    /// Our timing, stretched by the server's local health multiplier.
+    fn scaled_timing(&self) -> Timing {
+        self.timing.scaled(self.server.local_health_multiplier())
+    }
+
     ///
     /// Probe Loop
     ///
@@ -161,6 +166,12 @@ impl<'a> Outbound<'a> {
     /// PING_RECV_QUEUE_EMPTY_SLEEP_MS, and try again.
     ///
     /// If we don't receive anything at all in the Ping/PingReq loop, we mark the member as Suspect.
+    ///
+    /// A direct Ack lowers our local health multiplier; an Ack that only arrives through PingReq
+    /// leaves it alone. A PingReq target that can't reach the member sends us a Nack instead, so
+    /// when a probe fails, every PingReq target we heard nothing at all from raises it by one: if
+    /// they can't get through to us, we are the likely problem, not the member. With no PingReq
+    /// targets to judge by, a failed probe doesn't count against us.
     fn probe(&mut self, member: Member) {
         let addr = member.swim_socket_address();
 
@@ -168,38 +179,62 @@ impl<'a> Outbound<'a> {
 
         // Ping the member, and wait for the ack.
         ping(self.server, &self.socket, &member, addr, None);
-        if self.recv_ack(&member, addr, AckFrom::Ping) {
+        // Nacks only answer PingReqs; any that arrive now are left over from an earlier probe.
+        if self.recv_ack(&member, addr, AckFrom::Ping, &mut 0) {
+            self.server.decrease_local_health_multiplier();
             trace_it!(PROBE: &self.server, TraceKind::ProbeAckReceived, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             return;
         }
 
+        let mut pingreq_targets = 0;
         self.server.member_list.with_pingreq_targets(self.server.member_id(), member.get_id(), |pingreq_target| {
             trace_it!(PROBE: &self.server, TraceKind::ProbePingReq, pingreq_target.get_id(), pingreq_target.get_address());
             pingreq(self.server, &self.socket, &pingreq_target, &member);
+            pingreq_targets += 1;
         });
-        if !self.recv_ack(&member, addr, AckFrom::PingReq) {
+        let mut nacks = 0;
+        if !self.recv_ack(&member, addr, AckFrom::PingReq, &mut nacks) {
             // We mark as suspect when we fail to get a response from the PingReq. That moves us
             // into the suspicion phase, where anyone marked as suspect has a certain number of
             // protocol periods to recover.
             warn!("Marking {} as Suspect", member.get_id());
             trace_it!(PROBE: &self.server, TraceKind::ProbeSuspect, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
+            for _ in nacks..pingreq_targets {
+                self.server.increase_local_health_multiplier();
+            }
+            let member_id = String::from(member.get_id());
             self.server.insert_member(member, Health::Suspect);
+            // Our own suspicion starts it, or confirms one we had only heard about.
+            self.server.member_list.confirm_suspicion(&member_id, self.server.member_id());
         } else {
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
         }
     }
 
-    /// Listen for an ack from the `Inbound` thread.
-    fn recv_ack(&mut self, member: &Member, addr: SocketAddr, ack_from: AckFrom) -> bool {
+    /// Listen for an ack from the `Inbound` thread, counting the Nacks about the same member that
+    /// arrive while we wait.
+    fn recv_ack(&mut self,
+                member: &Member,
+                addr: SocketAddr,
+                ack_from: AckFrom,
+                nacks: &mut usize)
+                -> bool {
+        let timing = self.scaled_timing();
         let timeout = match ack_from {
-            AckFrom::Ping => self.timing.ping_timeout(),
-            AckFrom::PingReq => self.timing.pingreq_timeout(),
+            AckFrom::Ping => timing.ping_timeout(),
+            AckFrom::PingReq => timing.pingreq_timeout(),
         };
         loop {
             match self.rx_inbound.try_recv() {
                 Ok((real_addr, mut swim)) => {
+                    if swim.get_field_type() == Swim_Type::NACK {
+                        if member.get_id() == swim.get_nack().get_target().get_id() {
+                            *nacks += 1;
+                        }
+                        continue;
+                    }
                     let mut ack_from = swim.mut_ack().take_from();
                     if member.get_id() != ack_from.get_id() {
                         error!("Discarding ack from {}@{}; expected {}",
@@ -241,7 +276,9 @@ pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut S
     let mut membership_entries = RepeatedField::new();
     // If this isn't the first time we are communicating with this target, we want to include this
     // targets current status. This ensures that members always get a "Confirmed" rumor, before we
-    // have the chance to flip it to "Alive", which helps make sure we heal from a partition.
+    // have the chance to flip it to "Alive", which helps make sure we heal from a partition. The
+    // same entry, always first, already gives us Lifeguard's buddy system for free: a member we
+    // suspect hears about it on our next ping, and can refute it straight away.
     if server.member_list.contains_member(target.get_id()) {
         let always_target = server.member_list.membership_for(target.get_id());
         membership_entries.push(always_target);
@@ -310,6 +347,50 @@ pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, tar
               &swim);
 }
 
+/// Send a Nack to the member that asked us to ping `target`, which never answered.
+pub fn nack(server: &Server, socket: &UdpSocket, requester: &Member, target: &Member) {
+    let addr = requester.swim_socket_address();
+    let mut swim = Swim::new();
+    swim.set_field_type(Swim_Type::NACK);
+    let mut nack = Nack::new();
+    {
+        let member = server.member.read().unwrap();
+        nack.set_from(member.proto.clone());
+    }
+    nack.set_target(target.proto.clone());
+    swim.set_nack(nack);
+    populate_membership_rumors(server, requester, &mut swim);
+    let bytes = match swim.write_to_bytes() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!("Generating protobuf failed: {}", e);
+            return;
+        }
+    };
+    let payload = match server.generate_wire(bytes) {
+        Ok(payload) => payload,
+        Err(e) => {
+            error!("Generating protobuf failed: {}", e);
+            return;
+        }
+    };
+    match socket.send_to(&payload, addr) {
+        Ok(_s) => info!("Sent Nack to {}@{} for {}", requester.get_id(), addr, target.get_id()),
+        Err(e) => {
+            error!("Failed Nack to {}@{} for {}: {}",
+                   requester.get_id(),
+                   addr,
+                   target.get_id(),
+                   e)
+        }
+    }
+    trace_it!(SWIM: server,
+              TraceKind::SendNack,
+              requester.get_id(),
+              addr,
+              &swim);
+}
+
 /// Send a Ping.
 pub fn ping(server: &Server,
             socket: &UdpSocket,
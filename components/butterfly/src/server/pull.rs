@@ -22,6 +22,7 @@ use std::time::Duration;
 
 use habitat_net::server::ZMQ_CONTEXT;
 use protobuf;
+use time::Duration as TimeDuration;
 use zmq;
 
 use server::Server;
@@ -72,7 +73,7 @@ impl<'a> Pull<'a> {
                     continue;
                 }
             };
-            let mut proto: Rumor = match protobuf::parse_from_bytes(&payload) {
+            let proto: Rumor = match protobuf::parse_from_bytes(&payload) {
                 Ok(proto) => proto,
                 Err(e) => {
                     error!("Error parsing protobuf: {:?}", e);
@@ -85,29 +86,48 @@ impl<'a> Pull<'a> {
                 continue 'recv;
             }
             trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
-            match proto.get_field_type() {
-                Rumor_Type::Member => {
-                    let member = proto.mut_member().take_member().into();
-                    let health = proto.mut_member().get_health().into();
-                    self.server.insert_member_from_rumor(member, health);
-                }
+            insert_rumor(self.server, proto);
+        }
+    }
+}
+
+/// Insert a rumor received from another member into the matching store on the server.
+pub fn insert_rumor(server: &Server, mut proto: Rumor) {
+    match proto.get_field_type() {
+        Rumor_Type::Member => {
+            let membership = proto.take_member();
+            server.insert_member_from_rumors(proto.get_from_id(), vec![membership]);
+        }
+        Rumor_Type::Service => {
+            server.insert_service(proto.into());
+        }
+        Rumor_Type::ServiceConfig => {
+            server.insert_service_config(proto.into());
+        }
+        Rumor_Type::ServiceFile => {
+            server.insert_service_file(proto.into());
+        }
+        Rumor_Type::Election => {
+            server.insert_election(proto.into());
+        }
+        Rumor_Type::ElectionUpdate => {
+            server.insert_update_election(proto.into());
+        }
+        Rumor_Type::Departure => {
+            server.insert_departure(proto.into());
+        }
+        Rumor_Type::Fake |
+        Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
+        Rumor_Type::Tombstone => {
+            let tombstone = proto.get_tombstone();
+            match tombstone.get_field_type() {
                 Rumor_Type::Service => {
-                    self.server.insert_service(proto.into());
-                }
-                Rumor_Type::ServiceConfig => {
-                    self.server.insert_service_config(proto.into());
-                }
-                Rumor_Type::ServiceFile => {
-                    self.server.insert_service_file(proto.into());
-                }
-                Rumor_Type::Election => {
-                    self.server.insert_election(proto.into());
-                }
-                Rumor_Type::ElectionUpdate => {
-                    self.server.insert_update_election(proto.into());
+                    server.bury_service(tombstone.get_key(),
+                                        tombstone.get_id(),
+                                        tombstone.get_version(),
+                                        TimeDuration::milliseconds(tombstone.get_ttl_ms() as i64))
                 }
-                Rumor_Type::Fake |
-                Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
+                _ => debug!("Ignoring a tombstone; only withdrawn services are gossiped"),
             }
         }
     }
@@ -26,9 +26,9 @@ use protobuf::Message;
 use time::SteadyTime;
 use zmq;
 
-use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Member as ProtoMember,
-                    Membership as ProtoMembership};
+use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
 use rumor::{RumorKey, RumorVec};
+use rumor::tombstone::tombstone_rumor;
 use member::Member;
 use server::Server;
 use server::timing::Timing;
@@ -249,11 +249,45 @@ impl PushWorker {
                             }
                         }
                 }
+                ProtoRumor_Type::Departure => {
+                    match self.server
+                        .departure_store
+                        .write_to_bytes(&rumor_key.key, &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Fake |
                 ProtoRumor_Type::Fake2 => {
                     debug!("You have fake rumors; how odd!");
                     continue 'rumorlist;
                 }
+                ProtoRumor_Type::Tombstone => {
+                    // Only the tombstones of withdrawn services are gossiped.
+                    let buried = RumorKey::new(ProtoRumor_Type::Service,
+                                               &rumor_key.id[..],
+                                               &rumor_key.key[..]);
+                    let (version, ttl) = match self.server.tombstones.buried_rumor(&buried) {
+                        Some(tombstone) => tombstone,
+                        None => continue 'rumorlist,
+                    };
+                    let send_rumor =
+                        tombstone_rumor(self.server.member_id(), buried, version, ttl);
+                    match send_rumor.write_to_bytes() {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
             };
             let payload = match self.server.generate_wire(rumor_as_bytes) {
                 Ok(payload) => payload,
@@ -272,14 +306,9 @@ impl PushWorker {
 
     /// Given a rumorkey, creates a protobuf rumor for sharing.
     fn create_member_rumor(&self, rumor_key: &RumorKey) -> ProtoRumor {
-        let mut member: ProtoMember = ProtoMember::new();
-        self.server.member_list.with_member(&rumor_key.key(), |m| {
-            // TODO: This should not stand
-            member = m.unwrap().proto.clone();
-        });
-        let mut membership = ProtoMembership::new();
-        membership.set_member(member);
-        membership.set_health(self.server.member_list.health_of_by_id(&rumor_key.key()).unwrap().into());
+        // The membership names who started a suspicion, so relaying it never counts as
+        // confirming it.
+        let membership = self.server.member_list.membership_for(&rumor_key.key());
         let mut rumor = ProtoRumor::new();
         rumor.set_field_type(ProtoRumor_Type::Member);
         rumor.set_member(membership);
@@ -0,0 +1,538 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The push-pull threads.
+//!
+//! Gossip only spreads a handful of hot rumors at a time, so a member that joins a large ring can
+//! take many rounds to hear about everything. Every `Timing::push_pull_period_ms`, and once as
+//! soon as we start, we pick a random member and exchange our full state with it over TCP, on the
+//! same port as the SWIM socket. Each side sends a digest of every rumor it knows about, and gets
+//! back only the rumors it is missing or has an older copy of. This is modelled on the push-pull
+//! exchange in Hashicorp's memberlist.
+//!
+//! The exchange is three messages long:
+//!
+//! 1. The initiator sends its digests.
+//! 2. The responder sends the rumors the initiator wants, along with its own digests.
+//! 3. The initiator sends the rumors the responder wants.
+//!
+//! Every message is a `PushPull` protobuf, wrapped in a `Wire` like every other message we send,
+//! and prefixed with its length as a big-endian u32.
+//!
+//! Anyone who can reach the SWIM port can connect to the listener, and nothing they send is
+//! authenticated until it has been read in full and unwrapped. We answer at most
+//! `MAX_CONCURRENT_EXCHANGES` exchanges at a time, dropping connections beyond that, and never
+//! read more than `MAX_MESSAGE_BYTES` of a message.
+
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use protobuf::{self, Message, RepeatedField};
+use rand::{thread_rng, Rng};
+use time::SteadyTime;
+
+use error::{Error, Result};
+use member::{Health, Member};
+use message::swim::{Membership as ProtoMembership, PushPull as ProtoPushPull,
+                    Rumor as ProtoRumor, RumorDigest, Rumor_Type as ProtoRumor_Type};
+use rumor::{self, DigestMap, RumorKey};
+use server::{pull, Server};
+use server::timing::Timing;
+
+/// The largest message we will accept during an exchange. Members and rumors are a few hundred
+/// bytes each, so this leaves room for rings of several thousand members.
+pub const MAX_MESSAGE_BYTES: u32 = 4 * 1024 * 1024;
+/// How many exchanges started by other members we answer at once.
+const MAX_CONCURRENT_EXCHANGES: usize = 4;
+/// How long we wait on a peer to read or write before giving up on the exchange.
+const EXCHANGE_TIMEOUT_MS: u64 = 10_000;
+
+/// The periodic push-pull thread.
+pub struct PushPull<'a> {
+    pub server: &'a Server,
+    pub timing: Timing,
+}
+
+impl<'a> PushPull<'a> {
+    /// Creates a new PushPull instance from a Server and Timing
+    pub fn new(server: &'a Server, timing: Timing) -> PushPull {
+        PushPull {
+            server: server,
+            timing: timing,
+        }
+    }
+
+    /// Run the thread. Exchanges state with a random member right away, so that we catch up with
+    /// the ring as soon as we join it, and then once every push-pull period.
+    pub fn run(&self) {
+        loop {
+            let next_push_pull = self.timing.push_pull_timeout();
+            if !self.server.paused() {
+                if let Some(addr) = self.target() {
+                    match exchange(self.server, addr) {
+                        Ok(()) => debug!("Exchanged state with {}", addr),
+                        Err(e) => warn!("Failed to exchange state with {}: {}", addr, e),
+                    }
+                }
+            }
+            while SteadyTime::now() <= next_push_pull {
+                thread::sleep(Duration::from_millis(100));
+            }
+        }
+    }
+
+    /// A random alive member to exchange state with. If we don't know of any yet, we fall back to
+    /// a random initial member, which is how a freshly started member catches up.
+    fn target(&self) -> Option<SocketAddr> {
+        let alive = self.server
+            .member_list
+            .check_list(self.server.member_id())
+            .into_iter()
+            .find(|m| {
+                !self.server.check_blacklist(m.get_id()) &&
+                self.server.member_list.check_health_of(m, Health::Alive)
+            });
+        if let Some(member) = alive {
+            return Some(member.swim_socket_address());
+        }
+        let mut initial_members: Vec<Member> = Vec::new();
+        self.server.member_list.with_initial_members(|m| initial_members.push(m.clone()));
+        thread_rng().shuffle(&mut initial_members);
+        initial_members.first().map(|m| m.swim_socket_address())
+    }
+}
+
+/// The thread that answers push-pull exchanges started by other members.
+pub struct Listener<'a> {
+    pub server: &'a Server,
+    pub listener: TcpListener,
+    active: Arc<AtomicUsize>,
+}
+
+impl<'a> Listener<'a> {
+    /// Create a new Listener from a Server and a bound TcpListener.
+    pub fn new(server: &'a Server, listener: TcpListener) -> Listener {
+        Listener {
+            server: server,
+            listener: listener,
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Run the thread. Each exchange is answered by its own worker thread, so that a slow peer
+    /// doesn't hold up everyone else; connections that arrive while every worker is busy are
+    /// dropped, and the member on the other end will try again next period.
+    pub fn run(&self) {
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    error!("Error accepting a push-pull connection: {}", e);
+                    continue;
+                }
+            };
+            if self.server.paused() {
+                continue;
+            }
+            let slot = match ExchangeSlot::take(&self.active) {
+                Some(slot) => slot,
+                None => {
+                    debug!("Dropping a push-pull connection - {} exchanges in progress",
+                           MAX_CONCURRENT_EXCHANGES);
+                    continue;
+                }
+            };
+            let server = self.server.clone();
+            match thread::Builder::new()
+                .name(String::from("push-pull-worker"))
+                .spawn(move || {
+                    if let Err(e) = respond(&server, stream) {
+                        warn!("Failed to answer a state exchange: {}", e);
+                    }
+                    drop(slot);
+                }) {
+                Ok(_) => {}
+                Err(e) => error!("Could not spawn push-pull worker: {:?}", e),
+            }
+        }
+    }
+}
+
+/// One of the `MAX_CONCURRENT_EXCHANGES` slots for answering an exchange. The slot is given back
+/// when it is dropped.
+struct ExchangeSlot(Arc<AtomicUsize>);
+
+impl ExchangeSlot {
+    /// Take a slot, unless they are all in use.
+    fn take(active: &Arc<AtomicUsize>) -> Option<ExchangeSlot> {
+        let mut current = active.load(Ordering::SeqCst);
+        loop {
+            if current >= MAX_CONCURRENT_EXCHANGES {
+                return None;
+            }
+            let previous = active.compare_and_swap(current, current + 1, Ordering::SeqCst);
+            if previous == current {
+                return Some(ExchangeSlot(active.clone()));
+            }
+            current = previous;
+        }
+    }
+}
+
+impl Drop for ExchangeSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Exchange state with the member listening at `addr`, as the initiator.
+pub fn exchange(server: &Server, addr: SocketAddr) -> Result<()> {
+    let mut stream = try!(TcpStream::connect(addr).map_err(Error::PushPullIo));
+    try!(set_timeouts(&stream));
+
+    let mut request = ProtoPushPull::new();
+    request.set_from_id(String::from(server.member_id()));
+    request.set_digests(RepeatedField::from_vec(digests(server)));
+    try!(send(server, &mut stream, &request));
+
+    let mut response = try!(recv(server, &mut stream));
+    if server.check_blacklist(response.get_from_id()) {
+        debug!("Not exchanging state with {} - it is blacklisted",
+               response.get_from_id());
+        return Ok(());
+    }
+    insert_rumors(server, response.take_rumors().into_vec());
+
+    let mut reply = ProtoPushPull::new();
+    reply.set_from_id(String::from(server.member_id()));
+    reply.set_rumors(RepeatedField::from_vec(wanted_by(server, response.get_digests())));
+    send(server, &mut stream, &reply)
+}
+
+/// Answer an exchange started by another member.
+fn respond(server: &Server, mut stream: TcpStream) -> Result<()> {
+    try!(set_timeouts(&stream));
+
+    let request = try!(recv(server, &mut stream));
+    if server.check_blacklist(request.get_from_id()) {
+        debug!("Not exchanging state with {} - it is blacklisted",
+               request.get_from_id());
+        return Ok(());
+    }
+
+    let mut response = ProtoPushPull::new();
+    response.set_from_id(String::from(server.member_id()));
+    response.set_digests(RepeatedField::from_vec(digests(server)));
+    response.set_rumors(RepeatedField::from_vec(wanted_by(server, request.get_digests())));
+    try!(send(server, &mut stream, &response));
+
+    let mut reply = try!(recv(server, &mut stream));
+    insert_rumors(server, reply.take_rumors().into_vec());
+    Ok(())
+}
+
+/// A digest of everything we know: every member, ourselves included, and every rumor in every
+/// store.
+pub fn digests(server: &Server) -> Vec<RumorDigest> {
+    let mut digests = Vec::new();
+    for membership in memberships(server).iter() {
+        match membership_digest(membership) {
+            Ok(digest) => digests.push(digest),
+            Err(e) => {
+                error!("Cannot create a digest of member {}: {}",
+                       membership.get_member().get_id(),
+                       e)
+            }
+        }
+    }
+    digests.extend(server.service_store.digests());
+    digests.extend(server.service_config_store.digests());
+    digests.extend(server.service_file_store.digests());
+    digests.extend(server.election_store.digests());
+    digests.extend(server.update_store.digests());
+    digests.extend(server.departure_store.digests());
+    digests
+}
+
+/// Every rumor we have that the member who sent us `digests` is missing, or only has an older
+/// copy of.
+pub fn wanted_by(server: &Server, digests: &[RumorDigest]) -> Vec<ProtoRumor> {
+    let theirs = rumor::digest_map(digests);
+    let mut rumors = member_rumors_wanted_by(server, &theirs);
+    rumors.extend(server.service_store.wanted_by(&theirs));
+    rumors.extend(server.service_config_store.wanted_by(&theirs));
+    rumors.extend(server.service_file_store.wanted_by(&theirs));
+    rumors.extend(server.election_store.wanted_by(&theirs));
+    rumors.extend(server.update_store.wanted_by(&theirs));
+    rumors.extend(server.departure_store.wanted_by(&theirs));
+    rumors
+}
+
+/// Insert the rumors we received during an exchange, exactly as if they had been gossiped to us.
+pub fn insert_rumors(server: &Server, rumors: Vec<ProtoRumor>) {
+    for rumor in rumors.into_iter() {
+        pull::insert_rumor(server, rumor);
+    }
+}
+
+fn member_rumors_wanted_by(server: &Server, theirs: &DigestMap) -> Vec<ProtoRumor> {
+    let mut rumors = Vec::new();
+    for membership in memberships(server).into_iter() {
+        let ours = match membership_digest(&membership) {
+            Ok(digest) => digest,
+            Err(_) => continue,
+        };
+        let rk = RumorKey::new(ProtoRumor_Type::Member, ours.get_id(), "");
+        if rumor::is_wanted(theirs.get(&rk), &ours) {
+            let mut rumor = ProtoRumor::new();
+            rumor.set_field_type(ProtoRumor_Type::Member);
+            rumor.set_member(membership);
+            rumor.set_from_id(String::from(server.member_id()));
+            rumors.push(rumor);
+        }
+    }
+    rumors
+}
+
+fn membership_digest(membership: &ProtoMembership) -> Result<RumorDigest> {
+    // Who started a suspicion is not part of the rumor's content; members that heard it from
+    // different originators still agree on it.
+    let mut membership = membership.clone();
+    membership.clear_suspected_by();
+    let bytes = try!(membership.write_to_bytes());
+    let member = membership.get_member();
+    rumor::digest(ProtoRumor_Type::Member,
+                  member.get_id(),
+                  "",
+                  member.get_incarnation(),
+                  &bytes)
+}
+
+/// The membership of everyone we know about. We are not always in our own member list, but we
+/// want the other side to hear about us too, unless we have departed.
+fn memberships(server: &Server) -> Vec<ProtoMembership> {
+    let mut memberships = server.member_list.memberships();
+    if !server.departed() && !server.member_list.contains_member(server.member_id()) {
+        let mut me = ProtoMembership::new();
+        me.set_member(server.member.read().expect("Member lock is poisoned").proto.clone());
+        me.set_health(Health::Alive.into());
+        memberships.push(me);
+    }
+    memberships
+}
+
+fn set_timeouts(stream: &TcpStream) -> Result<()> {
+    let timeout = Some(Duration::from_millis(EXCHANGE_TIMEOUT_MS));
+    try!(stream.set_read_timeout(timeout).map_err(Error::SocketSetReadTimeout));
+    try!(stream.set_write_timeout(timeout).map_err(Error::SocketSetWriteTimeout));
+    Ok(())
+}
+
+fn send(server: &Server, stream: &mut TcpStream, message: &ProtoPushPull) -> Result<()> {
+    let payload = try!(server.generate_wire(try!(message.write_to_bytes())));
+    let len = payload.len() as u32;
+    let header = [(len >> 24) as u8, (len >> 16) as u8, (len >> 8) as u8, len as u8];
+    try!(stream.write_all(&header).map_err(Error::PushPullIo));
+    try!(stream.write_all(&payload).map_err(Error::PushPullIo));
+    Ok(())
+}
+
+fn recv(server: &Server, stream: &mut TcpStream) -> Result<ProtoPushPull> {
+    let mut header = [0u8; 4];
+    try!(stream.read_exact(&mut header).map_err(Error::PushPullIo));
+    let len = (header[0] as u32) << 24 | (header[1] as u32) << 16 | (header[2] as u32) << 8 |
+              header[3] as u32;
+    if len > MAX_MESSAGE_BYTES {
+        return Err(Error::PushPullIo(io::Error::new(io::ErrorKind::InvalidData,
+                                                    format!("message of {} bytes is too large",
+                                                            len))));
+    }
+    // Grow the buffer as the bytes arrive, rather than trusting the length up front.
+    let mut payload = Vec::new();
+    try!(stream.by_ref()
+        .take(len as u64)
+        .read_to_end(&mut payload)
+        .map_err(Error::PushPullIo));
+    if payload.len() != len as usize {
+        return Err(Error::PushPullIo(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                                    "connection closed mid-message")));
+    }
+    let bytes = try!(server.unwrap_wire(&payload));
+    Ok(try!(protobuf::parse_from_bytes(&bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::net::{TcpListener, TcpStream};
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::thread;
+
+    use habitat_core::package::PackageIdent;
+
+    use member::{Health, Member};
+    use message::swim::Rumor_Type;
+    use rumor::service::Service;
+    use server::Server;
+    use server::push_pull::{digests, exchange, insert_rumors, recv, respond, wanted_by,
+                            ExchangeSlot, MAX_CONCURRENT_EXCHANGES};
+    use trace::Trace;
+
+    fn create_server() -> Server {
+        Server::new("127.0.0.1:0",
+                    "127.0.0.1:0",
+                    Member::new(),
+                    Trace::default(),
+                    None,
+                    None)
+            .unwrap()
+    }
+
+    fn create_service(server: &Server, group: &str) -> Service {
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20161215000000").unwrap();
+        Service::new(server.member_id(),
+                     &ident,
+                     group,
+                     None,
+                     "localhost",
+                     "127.0.0.1",
+                     vec![6379])
+    }
+
+    #[test]
+    fn digests_include_ourselves() {
+        let server = create_server();
+        assert!(digests(&server)
+            .iter()
+            .any(|d| d.get_field_type() == Rumor_Type::Member && d.get_id() == server.member_id()));
+    }
+
+    #[test]
+    fn digests_leave_us_out_once_we_have_departed() {
+        let server = create_server();
+        server.depart();
+        let digests = digests(&server);
+        assert!(!digests.iter().any(|d| d.get_field_type() == Rumor_Type::Member));
+        assert!(digests.iter().any(|d| d.get_field_type() == Rumor_Type::Departure));
+    }
+
+    #[test]
+    fn only_missing_rumors_are_wanted() {
+        let a = create_server();
+        let b = create_server();
+        a.insert_service(create_service(&a, "default"));
+
+        let wanted = wanted_by(&a, &digests(&b));
+        assert_eq!(wanted.len(), 2);
+        insert_rumors(&b, wanted);
+        assert!(b.service_store.contains_rumor("redis.default", a.member_id()));
+        assert!(b.member_list.check_health_of_by_id(a.member_id(), Health::Alive));
+
+        assert!(wanted_by(&a, &digests(&b)).is_empty());
+    }
+
+    #[test]
+    fn newer_incarnations_are_wanted_and_older_ones_are_not() {
+        let a = create_server();
+        let b = create_server();
+        a.insert_service(create_service(&a, "default"));
+        insert_rumors(&b, wanted_by(&a, &digests(&b)));
+
+        let mut newer = create_service(&a, "default");
+        newer.set_incarnation(1);
+        a.insert_service(newer);
+        let wanted = wanted_by(&a, &digests(&b));
+        assert_eq!(wanted.len(), 1);
+        assert_eq!(wanted[0].get_service().get_incarnation(), 1);
+
+        assert!(wanted_by(&b, &digests(&a))
+            .iter()
+            .all(|r| r.get_field_type() != Rumor_Type::Service));
+    }
+
+    #[test]
+    fn exchange_syncs_both_sides() {
+        let a = create_server();
+        let b = create_server();
+        a.insert_service(create_service(&a, "default"));
+        b.insert_service(create_service(&b, "other"));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let responder = b.clone();
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            respond(&responder, stream).unwrap();
+        });
+        exchange(&a, addr).unwrap();
+        handle.join().unwrap();
+
+        assert!(a.service_store.contains_rumor("redis.other", b.member_id()));
+        assert!(a.member_list.contains_member(b.member_id()));
+        assert!(b.service_store.contains_rumor("redis.default", a.member_id()));
+        assert!(b.member_list.contains_member(a.member_id()));
+    }
+
+    #[test]
+    fn exchange_ignores_blacklisted_members() {
+        let a = create_server();
+        let b = create_server();
+        a.insert_service(create_service(&a, "default"));
+        b.add_to_blacklist(String::from(a.member_id()));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let responder = b.clone();
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            respond(&responder, stream).unwrap();
+        });
+        assert!(exchange(&a, addr).is_err());
+        handle.join().unwrap();
+
+        assert!(!b.service_store.contains_rumor("redis.default", a.member_id()));
+    }
+
+    #[test]
+    fn concurrent_exchanges_are_bounded() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let mut slots = Vec::new();
+        for _ in 0..MAX_CONCURRENT_EXCHANGES {
+            slots.push(ExchangeSlot::take(&active).expect("A slot should be free"));
+        }
+        assert!(ExchangeSlot::take(&active).is_none());
+        slots.pop();
+        assert!(ExchangeSlot::take(&active).is_some());
+    }
+
+    #[test]
+    fn oversized_messages_are_refused_before_they_are_read() {
+        let server = create_server();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(&[0xff, 0xff, 0xff, 0xff]).unwrap();
+        });
+        let (mut stream, _) = listener.accept().unwrap();
+        assert!(recv(&server, &mut stream).is_err());
+        handle.join().unwrap();
+    }
+}
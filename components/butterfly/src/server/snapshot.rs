@@ -0,0 +1,371 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshots of a server's state on disk.
+//!
+//! A snapshot holds the member list, every rumor store and the tombstones of what was reaped, so
+//! that a restarted server can come back with what it knew rather than waiting for gossip to tell
+//! it everything again. Each entry is a `Rumor` protobuf, exactly as it would be gossiped,
+//! prefixed with its length as a big endian `u32`. The first entry is always the membership rumor
+//! of the server that wrote the snapshot.
+//!
+//! Tombstones are written as `Tombstone` rumors right after it. They carry the time they had left
+//! to live, since a `SteadyTime` means nothing to the next process; the time we spend down is not
+//! counted against them.
+//!
+//! Snapshots hold everything we know about the ring, so they are only readable by us.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use protobuf::{self, Message};
+use time::Duration;
+
+use error::{Error, Result};
+use member::{Health, Member};
+use message::swim::{Membership as ProtoMembership, Rumor as ProtoRumor, Rumor_Type};
+use rumor::{Rumor, RumorKey, RumorStore};
+use rumor::departure::Departure;
+use rumor::election::{Election, ElectionUpdate};
+use rumor::tombstone::tombstone_rumor;
+use server::Server;
+use server::push_pull::MAX_MESSAGE_BYTES;
+
+/// The permissions a snapshot is created with.
+const SNAPSHOT_PERMISSIONS: u32 = 0o600;
+
+/// Write a snapshot of the server to `path`. The snapshot is written next to the destination
+/// first, and then moved into place.
+pub fn write<P: AsRef<Path>>(server: &Server, path: P) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        try!(fs::create_dir_all(parent).map_err(|e| Error::SnapshotIo(e)));
+    }
+    let new_filename = format!("{}.write", path.to_string_lossy());
+    {
+        let file = try!(create_file(&new_filename).map_err(|e| Error::SnapshotIo(e)));
+        let mut writer = BufWriter::new(file);
+
+        let me = server.member.read().expect("Member lock is poisoned").clone();
+        try!(write_entry(&mut writer, &member_rumor(server, me, Health::Alive)));
+
+        for (member_id, incarnation, ttl) in server.tombstones.buried_members().into_iter() {
+            let rk = RumorKey::new(Rumor_Type::Member, member_id, "");
+            let rumor = tombstone_rumor(server.member_id(), rk, incarnation, ttl);
+            try!(write_entry(&mut writer, &rumor));
+        }
+        for (rk, version, ttl) in server.tombstones.buried_rumors().into_iter() {
+            let rumor = tombstone_rumor(server.member_id(), rk, version, ttl);
+            try!(write_entry(&mut writer, &rumor));
+        }
+
+        let mut members = Vec::new();
+        server.member_list.with_members(|member| {
+            if member.get_id() != server.member_id() {
+                if let Some(health) = server.member_list.health_of(member) {
+                    members.push(member_rumor(server, member.clone(), health));
+                }
+            }
+        });
+        for rumor in members.iter() {
+            try!(write_entry(&mut writer, rumor));
+        }
+
+        try!(write_store(&mut writer, &server.service_store));
+        try!(write_store(&mut writer, &server.service_config_store));
+        try!(write_store(&mut writer, &server.service_file_store));
+        try!(write_store(&mut writer, &server.election_store));
+        try!(write_store(&mut writer, &server.update_store));
+        try!(write_store(&mut writer, &server.departure_store));
+        try!(writer.flush().map_err(|e| Error::SnapshotIo(e)));
+    }
+    try!(fs::rename(&new_filename, path).map_err(|e| Error::SnapshotIo(e)));
+    Ok(())
+}
+
+/// Read the member that wrote the snapshot at `path`, if there is one. A restarting server uses
+/// it to come back with the same identity it had before.
+pub fn read_member<P: AsRef<Path>>(path: P) -> Result<Option<Member>> {
+    if !path.as_ref().exists() {
+        return Ok(None);
+    }
+    let mut entries = try!(read_entries(path));
+    if entries.is_empty() {
+        return Ok(None);
+    }
+    let mut rumor = entries.remove(0);
+    if rumor.get_field_type() != Rumor_Type::Member {
+        return Err(Error::SnapshotCorrupt(String::from("first entry is not a membership rumor")));
+    }
+    Ok(Some(rumor.mut_member().take_member().into()))
+}
+
+/// Load every member, rumor and tombstone from the snapshot at `path` into the server, skipping
+/// the server's own membership. Returns the number of entries loaded.
+///
+/// Members we had suspected are loaded as alive; their suspicion timers did not survive the
+/// restart, so they have to earn their suspicion again through the probe cycle.
+pub fn rehydrate<P: AsRef<Path>>(server: &Server, path: P) -> Result<usize> {
+    if !path.as_ref().exists() {
+        return Ok(0);
+    }
+    let mut loaded = 0;
+    for mut rumor in try!(read_entries(path)).into_iter() {
+        match rumor.get_field_type() {
+            Rumor_Type::Member => {
+                let member: Member = rumor.mut_member().take_member().into();
+                if member.get_id() == server.member_id() {
+                    continue;
+                }
+                let health = match Health::from(rumor.get_member().get_health()) {
+                    Health::Suspect => Health::Alive,
+                    health => health,
+                };
+                server.insert_member(member, health);
+            }
+            Rumor_Type::Service => server.insert_service(rumor.into()),
+            Rumor_Type::ServiceConfig => server.insert_service_config(rumor.into()),
+            Rumor_Type::ServiceFile => server.insert_service_file(rumor.into()),
+            Rumor_Type::Election => {
+                insert_into_store(server, &server.election_store, Election::from(rumor))
+            }
+            Rumor_Type::ElectionUpdate => {
+                insert_into_store(server, &server.update_store, ElectionUpdate::from(rumor))
+            }
+            Rumor_Type::Departure => {
+                if rumor.get_departure().get_member_id() == server.member_id() {
+                    continue;
+                }
+                insert_into_store(server, &server.departure_store, Departure::from(rumor))
+            }
+            Rumor_Type::Tombstone => {
+                let tombstone = rumor.get_tombstone();
+                let ttl = Duration::milliseconds(tombstone.get_ttl_ms() as i64);
+                match tombstone.get_field_type() {
+                    Rumor_Type::Member => {
+                        server.tombstones
+                            .bury_member(tombstone.get_id(), tombstone.get_version(), ttl)
+                    }
+                    kind => {
+                        server.tombstones.bury_rumor(RumorKey::new(kind,
+                                                                   tombstone.get_id(),
+                                                                   tombstone.get_key()),
+                                                     tombstone.get_version(),
+                                                     ttl)
+                    }
+                }
+            }
+            Rumor_Type::Fake |
+            Rumor_Type::Fake2 => continue,
+        }
+        loaded += 1;
+    }
+    Ok(loaded)
+}
+
+// Elections are put straight into their stores; running them through `insert_election` would
+// start new elections before we know which services this server is running.
+fn insert_into_store<T: Rumor + Clone>(server: &Server, store: &RumorStore<T>, rumor: T) {
+    let rk = RumorKey::from(&rumor);
+    if store.insert(rumor) {
+        server.rumor_list.insert(rk);
+    }
+}
+
+fn member_rumor(server: &Server, member: Member, health: Health) -> ProtoRumor {
+    let mut membership = ProtoMembership::new();
+    membership.set_member(member.proto);
+    membership.set_health(health.into());
+    let mut rumor = ProtoRumor::new();
+    rumor.set_field_type(Rumor_Type::Member);
+    rumor.set_member(membership);
+    rumor.set_from_id(String::from(server.member_id()));
+    rumor
+}
+
+fn write_store<W: Write, T: Rumor + Clone>(writer: &mut W, store: &RumorStore<T>) -> Result<()> {
+    let mut entries = Vec::new();
+    store.with_keys(|(_key, rumors)| for rumor in rumors.values() {
+        entries.push(rumor.write_to_bytes());
+    });
+    for bytes in entries.into_iter() {
+        try!(write_bytes(writer, &try!(bytes)));
+    }
+    Ok(())
+}
+
+fn write_entry<W: Write>(writer: &mut W, rumor: &ProtoRumor) -> Result<()> {
+    write_bytes(writer, &try!(rumor.write_to_bytes()))
+}
+
+/// Create a file for a snapshot, only readable by us. A file left over from a failed write is
+/// removed first, since the permissions only apply to a file we create.
+#[cfg(unix)]
+fn create_file(path: &str) -> io::Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let _ = fs::remove_file(path);
+    OpenOptions::new().write(true).create_new(true).mode(SNAPSHOT_PERMISSIONS).open(path)
+}
+
+#[cfg(not(unix))]
+fn create_file(path: &str) -> io::Result<File> {
+    OpenOptions::new().write(true).create(true).truncate(true).open(path)
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<()> {
+    let len = bytes.len() as u32;
+    let header = [(len >> 24) as u8, (len >> 16) as u8, (len >> 8) as u8, len as u8];
+    try!(writer.write_all(&header).map_err(|e| Error::SnapshotIo(e)));
+    try!(writer.write_all(bytes).map_err(|e| Error::SnapshotIo(e)));
+    Ok(())
+}
+
+fn read_entries<P: AsRef<Path>>(path: P) -> Result<Vec<ProtoRumor>> {
+    let file = try!(File::open(path.as_ref()).map_err(|e| Error::SnapshotIo(e)));
+    let mut reader = BufReader::new(file);
+    let mut entries = Vec::new();
+    loop {
+        let mut header = [0u8; 4];
+        match reader.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(Error::SnapshotIo(e)),
+        }
+        let len = (header[0] as u32) << 24 | (header[1] as u32) << 16 | (header[2] as u32) << 8 |
+                  header[3] as u32;
+        if len > MAX_MESSAGE_BYTES {
+            return Err(Error::SnapshotCorrupt(format!("entry of {} bytes is too large", len)));
+        }
+        let mut bytes = vec![0u8; len as usize];
+        try!(reader.read_exact(&mut bytes)
+            .map_err(|_| Error::SnapshotCorrupt(String::from("truncated entry"))));
+        entries.push(try!(protobuf::parse_from_bytes::<ProtoRumor>(&bytes)));
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::str::FromStr;
+
+    use habitat_core::package::PackageIdent;
+    use habitat_core::service::ServiceGroup;
+    use time::Duration;
+    use uuid::Uuid;
+
+    use error::Error;
+    use member::{Health, Member};
+    use message::swim::Rumor_Type;
+    use rumor::RumorKey;
+    use rumor::election::Election;
+    use rumor::service::Service;
+    use server::Server;
+    use trace::Trace;
+    use super::{read_member, rehydrate, write};
+
+    fn server() -> Server {
+        Server::new("127.0.0.1:0", "127.0.0.1:0", Member::new(), Trace::default(), None, None)
+            .unwrap()
+    }
+
+    fn service_for(member_id: &str) -> Service {
+        let package = PackageIdent::from_str("core/redis/3.2.4/20161026000000").unwrap();
+        Service::new(member_id,
+                     &package,
+                     "default",
+                     None,
+                     "localhost",
+                     "127.0.0.1",
+                     vec![6379])
+    }
+
+    #[test]
+    fn snapshot_round_trips_members_and_rumors() {
+        let path = env::temp_dir().join(format!("butterfly-{}.dat", Uuid::new_v4().simple()));
+        let original = server();
+        let peer = Member::new();
+        let peer_id = String::from(peer.get_id());
+        original.insert_member(peer, Health::Suspect);
+        original.insert_service(service_for(original.member_id()));
+        original.election_store.insert(Election::new(original.member_id(),
+                                                     ServiceGroup::new("redis", "default", None),
+                                                     0));
+        write(&original, &path).unwrap();
+
+        let me = read_member(&path).unwrap().unwrap();
+        assert_eq!(me.get_id(), original.member_id());
+
+        let restored = server();
+        assert_eq!(rehydrate(&restored, &path).unwrap(), 3);
+        assert_eq!(restored.member_list.health_of_by_id(&peer_id), Some(Health::Alive));
+        assert!(restored.service_store.contains_rumor("redis.default", original.member_id()));
+        assert!(restored.election_store.contains_rumor("redis.default", "election"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn snapshot_round_trips_tombstones_with_their_ttls() {
+        let path = env::temp_dir().join(format!("butterfly-{}.dat", Uuid::new_v4().simple()));
+        let original = server();
+        let rk = RumorKey::new(Rumor_Type::Election, "election", "redis.default");
+        original.tombstones.bury_member("adam", 2, Duration::minutes(10));
+        original.tombstones.bury_member("shanku", 1, Duration::zero());
+        original.tombstones.bury_rumor(rk.clone(), 4, Duration::minutes(10));
+        write(&original, &path).unwrap();
+
+        let restored = server();
+        assert_eq!(rehydrate(&restored, &path).unwrap(), 2);
+        assert!(restored.tombstones.member_is_buried("adam", 2));
+        assert!(!restored.tombstones.member_is_buried("adam", 3));
+        assert!(!restored.tombstones.has_member("shanku"));
+        assert!(restored.tombstones.rumor_is_buried(&rk, 4));
+        let (_, _, ttl) = restored.tombstones.buried_members().remove(0);
+        assert!(ttl <= Duration::minutes(10) && ttl > Duration::minutes(9));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn oversized_entries_are_corrupt() {
+        let path = env::temp_dir().join(format!("butterfly-{}.dat", Uuid::new_v4().simple()));
+        File::create(&path).unwrap().write_all(&[0xff, 0xff, 0xff, 0xff]).unwrap();
+        match rehydrate(&server(), &path) {
+            Err(Error::SnapshotCorrupt(_)) => {}
+            other => panic!("Expected a corrupt snapshot, got {:?}", other),
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn snapshots_are_only_readable_by_us() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = env::temp_dir().join(format!("butterfly-{}.dat", Uuid::new_v4().simple()));
+        write(&server(), &path).unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn missing_snapshot_is_empty() {
+        let path = env::temp_dir().join(format!("butterfly-{}.dat", Uuid::new_v4().simple()));
+        assert!(read_member(&path).unwrap().is_none());
+        assert_eq!(rehydrate(&server(), &path).unwrap(), 0);
+    }
+}
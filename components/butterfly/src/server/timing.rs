@@ -18,10 +18,18 @@ use time::{SteadyTime, Duration as TimeDuration};
 const PING_TIMING_DEFAULT_MS: i64 = 1000;
 /// How long to wait for an Ack after we PingReq - should be at least 2x the PING_TIMING_DEFAULT_MS
 const PINGREQ_TIMING_DEFAULT_MS: i64 = 2100;
-/// How many protocol periods before a suspect member is marked as confirmed.
+/// How many protocol periods before a suspect member is marked as confirmed, at the least.
 const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
+/// How many times longer than the least suspicion timeout we wait when nobody else agrees.
+const SUSPICION_TIMEOUT_DEFAULT_MAX_MULTIPLIER: i64 = 6;
+/// How many independent confirmations bring the suspicion timeout down to its least.
+const SUSPICION_CONFIRMATIONS_DEFAULT_EXPECTED: usize = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
+/// How long a member stays confirmed or departed before it, and its rumors, are reaped.
+const REAP_TIMEOUT_DEFAULT_MS: i64 = 3_600_000;
+/// How often we exchange our full state with a random member
+const PUSH_PULL_PERIOD_DEFAULT_MS: i64 = 30_000;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -30,6 +38,14 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    /// How many times `suspicion_timeout_protocol_periods` a lone suspicion lasts.
+    pub suspicion_timeout_max_multiplier: i64,
+    /// How many other members must suspect a member before we wait the least amount of time.
+    pub suspicion_confirmations_expected: usize,
+    /// How long a member stays confirmed or departed before it is reaped. Zero never reaps.
+    pub reap_timeout_ms: i64,
+    /// How often we exchange our full state with a random member.
+    pub push_pull_period_ms: i64,
 }
 
 impl Default for Timing {
@@ -39,6 +55,10 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            suspicion_timeout_max_multiplier: SUSPICION_TIMEOUT_DEFAULT_MAX_MULTIPLIER,
+            suspicion_confirmations_expected: SUSPICION_CONFIRMATIONS_DEFAULT_EXPECTED,
+            reap_timeout_ms: REAP_TIMEOUT_DEFAULT_MS,
+            push_pull_period_ms: PUSH_PULL_PERIOD_DEFAULT_MS,
         }
     }
 }
@@ -55,14 +75,36 @@ impl Timing {
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            suspicion_timeout_max_multiplier: SUSPICION_TIMEOUT_DEFAULT_MAX_MULTIPLIER,
+            suspicion_confirmations_expected: SUSPICION_CONFIRMATIONS_DEFAULT_EXPECTED,
+            reap_timeout_ms: REAP_TIMEOUT_DEFAULT_MS,
+            push_pull_period_ms: PUSH_PULL_PERIOD_DEFAULT_MS,
         }
     }
 
+    /// Set how long, in milliseconds, a member stays confirmed or departed before it is reaped.
+    /// Zero never reaps.
+    pub fn set_reap_timeout_ms(&mut self, reap_timeout_ms: i64) -> &mut Timing {
+        self.reap_timeout_ms = reap_timeout_ms;
+        self
+    }
+
+    /// Set how often, in milliseconds, we exchange our full state with a random member.
+    pub fn set_push_pull_period_ms(&mut self, push_pull_period_ms: i64) -> &mut Timing {
+        self.push_pull_period_ms = push_pull_period_ms;
+        self
+    }
+
     /// When should this gossip period expire
     pub fn gossip_timeout(&self) -> SteadyTime {
         SteadyTime::now() + TimeDuration::milliseconds(self.gossip_period_ms)
     }
 
+    /// When should we next exchange our full state with a random member
+    pub fn push_pull_timeout(&self) -> SteadyTime {
+        SteadyTime::now() + TimeDuration::milliseconds(self.push_pull_period_ms)
+    }
+
     /// How long is a protocol period, in millis.
     pub fn protocol_period_ms(&self) -> i64 {
         self.ping_ms + self.pingreq_ms
@@ -73,6 +115,13 @@ impl Timing {
         SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms)
     }
 
+    /// When should we tell a member that asked us to ping someone on their behalf that we heard
+    /// nothing back? A little sooner than a ping times out, so that the Nack reaches them well
+    /// before their PingReq times out.
+    pub fn nack_timeout(&self) -> SteadyTime {
+        SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms * 4 / 5)
+    }
+
     /// When should this pingreq timeout?
     pub fn pingreq_timeout(&self) -> SteadyTime {
         SteadyTime::now() + TimeDuration::milliseconds(self.pingreq_ms)
@@ -83,9 +132,88 @@ impl Timing {
         SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms + self.pingreq_ms)
     }
 
-    /// How long before this suspect entry times out
-    pub fn suspicion_timeout_duration(&self) -> TimeDuration {
-        TimeDuration::milliseconds(self.protocol_period_ms() *
-                                   self.suspicion_timeout_protocol_periods)
+    /// Returns a copy of this timing with the probe timeouts, and so the protocol period,
+    /// stretched by our local health multiplier. A member that is slow to process acks waits
+    /// longer for them, rather than blaming its peers.
+    pub fn scaled(&self, local_health_multiplier: usize) -> Timing {
+        let scale = local_health_multiplier as i64 + 1;
+        let mut timing = self.clone();
+        timing.ping_ms = self.ping_ms * scale;
+        timing.pingreq_ms = self.pingreq_ms * scale;
+        timing
+    }
+
+    /// How long before a suspect entry times out, given how many members are in the ring and how
+    /// many of them have independently told us they suspect it too.
+    ///
+    /// A lone suspicion waits `suspicion_timeout_max_multiplier` times the least timeout; each
+    /// confirmation brings it down logarithmically, until `suspicion_confirmations_expected`
+    /// confirmations bring it to the least. The least timeout grows with the log of the size of
+    /// the ring. Rings too small to produce the expected confirmations always use the least.
+    pub fn suspicion_timeout_duration(&self, members: usize, confirmations: usize) -> TimeDuration {
+        let min_ms = (self.protocol_period_ms() * self.suspicion_timeout_protocol_periods) as f64 *
+                     (members as f64).log10().max(1.0);
+        let expected = self.suspicion_confirmations_expected;
+        if expected == 0 || members < expected + 2 {
+            return TimeDuration::milliseconds(min_ms as i64);
+        }
+        let max_ms = min_ms * self.suspicion_timeout_max_multiplier as f64;
+        let progress = (confirmations as f64 + 1.0).ln() / (expected as f64 + 1.0).ln();
+        let timeout_ms = (max_ms - (max_ms - min_ms) * progress).max(min_ms);
+        TimeDuration::milliseconds(timeout_ms as i64)
+    }
+
+    /// How long a confirmed or departed member is kept before it is reaped, if it is reaped at
+    /// all. Tombstones for what was reaped last just as long, which gives the rest of the ring
+    /// time to reap it too.
+    pub fn reap_timeout_duration(&self) -> Option<TimeDuration> {
+        if self.reap_timeout_ms > 0 {
+            Some(TimeDuration::milliseconds(self.reap_timeout_ms))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use server::timing::Timing;
+
+    #[test]
+    fn scaled_stretches_the_protocol_period() {
+        let timing = Timing::default();
+        assert_eq!(timing.scaled(0).protocol_period_ms(), timing.protocol_period_ms());
+        assert_eq!(timing.scaled(2).protocol_period_ms(),
+                   timing.protocol_period_ms() * 3);
+        assert_eq!(timing.scaled(2).gossip_period_ms, timing.gossip_period_ms);
+    }
+
+    #[test]
+    fn suspicion_timeout_shrinks_with_confirmations() {
+        let timing = Timing::default();
+        let min = timing.protocol_period_ms() * timing.suspicion_timeout_protocol_periods;
+        let lone = timing.suspicion_timeout_duration(6, 0);
+        let one = timing.suspicion_timeout_duration(6, 1);
+        let all = timing.suspicion_timeout_duration(6, 3);
+        assert_eq!(lone.num_milliseconds(),
+                   min * timing.suspicion_timeout_max_multiplier);
+        assert!(one < lone);
+        assert!(all < one);
+        assert_eq!(all.num_milliseconds(), min);
+        assert_eq!(timing.suspicion_timeout_duration(6, 10), all);
+    }
+
+    #[test]
+    fn suspicion_timeout_is_least_in_small_rings() {
+        let timing = Timing::default();
+        let min = timing.protocol_period_ms() * timing.suspicion_timeout_protocol_periods;
+        assert_eq!(timing.suspicion_timeout_duration(2, 0).num_milliseconds(), min);
+    }
+
+    #[test]
+    fn suspicion_timeout_grows_with_the_ring() {
+        let timing = Timing::default();
+        assert!(timing.suspicion_timeout_duration(100, 3) >
+                timing.suspicion_timeout_duration(10, 3));
     }
 }
@@ -36,11 +36,13 @@ pub enum TraceKind {
     ProbeSuspect,
     ProbePingReq,
     RecvAck,
+    RecvNack,
     RecvPing,
     RecvPingReq,
     RecvRumor,
     SendAck,
     SendForwardAck,
+    SendNack,
     SendPing,
     SendPingReq,
     SendRumor,
@@ -58,11 +60,13 @@ impl fmt::Display for TraceKind {
             TraceKind::ProbeSuspect => write!(f, "ProbeSuspect"),
             TraceKind::ProbePingReq => write!(f, "ProbePingReq"),
             TraceKind::RecvAck => write!(f, "RecvAck"),
+            TraceKind::RecvNack => write!(f, "RecvNack"),
             TraceKind::RecvPing => write!(f, "RecvPing"),
             TraceKind::RecvPingReq => write!(f, "RecvPingReq"),
             TraceKind::RecvRumor => write!(f, "RecvRumor"),
             TraceKind::SendAck => write!(f, "SendAck"),
             TraceKind::SendForwardAck => write!(f, "SendForwardAck"),
+            TraceKind::SendNack => write!(f, "SendNack"),
             TraceKind::SendPing => write!(f, "SendPing"),
             TraceKind::SendPingReq => write!(f, "SendPingReq"),
             TraceKind::SendRumor => write!(f, "SendRumor"),
@@ -354,6 +358,8 @@ macro_rules! trace_it {
                                                          $payload.get_service_file().get_encrypted(),
                                                          $payload.get_service_file().get_filename()),
                     Rumor_Type::Election | Rumor_Type::ElectionUpdate => format!("{}-{}-{}-{}-{:?}-{:?}", $payload.get_election().get_member_id(), $payload.get_election().get_service_group(), $payload.get_election().get_term(), $payload.get_election().get_suitability(), $payload.get_election().get_status(), $payload.get_election().get_votes()),
+                    Rumor_Type::Departure => format!("{}", $payload.get_departure().get_member_id()),
+                    Rumor_Type::Tombstone => format!("{}-{}-{}", $payload.get_tombstone().get_key(), $payload.get_tombstone().get_id(), $payload.get_tombstone().get_version()),
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
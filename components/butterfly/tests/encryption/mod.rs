@@ -28,3 +28,30 @@ fn symmetric_encryption_of_wire_payloads() {
     net.wait_for_gossip_rounds(2);
     net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
 }
+
+#[test]
+fn rotating_the_ring_key_keeps_the_ring_together() {
+    let old_key = SymKey::generate_in_memory("wolverine")
+        .expect("Failed to generate an in memory symkey");
+    let new_key = SymKey::generate_in_memory("cyclops")
+        .expect("Failed to generate an in memory symkey");
+    let mut net = btest::SwimNet::new_ring_encryption(3, Some(old_key.clone()));
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+
+    for member in net.members.iter() {
+        member.add_ring_key(new_key.clone());
+    }
+    // Only the first member encrypts with the new key; everyone can still talk to everyone.
+    net[0].set_primary_ring_key(&new_key.name_with_rev()).unwrap();
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+
+    for member in net.members.iter() {
+        member.set_primary_ring_key(&new_key.name_with_rev()).unwrap();
+        member.remove_ring_key(&old_key.name_with_rev()).unwrap();
+    }
+    net.wait_for_rounds(2);
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+}
@@ -38,6 +38,16 @@ fn two_members_meshed_confirm_one_member() {
     assert_wait_for_health_of!(net, 1, 0, Health::Confirmed);
 }
 
+#[test]
+fn six_members_meshed_depart_one_member() {
+    let mut net = btest::SwimNet::new(6);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    trace_it!(TEST: &net[0], "Departed");
+    net[0].depart();
+    assert_wait_for_health_of!(net, 0, Health::Departed);
+}
+
 #[test]
 fn six_members_meshed_confirm_one_member() {
     let mut net = btest::SwimNet::new(6);
@@ -66,6 +76,35 @@ fn six_members_meshed_partition_half_of_nodes_from_each_other_both_sides_confirm
     assert_wait_for_health_of!(net, [0..3, 3..6], Health::Confirmed);
 }
 
+#[test]
+fn six_members_meshed_partition_one_member_only_it_loses_local_health() {
+    let mut net = btest::SwimNet::new(6);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    net.partition(0..1, 1..6);
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+    assert!(net.local_health_of(0) > 0);
+    assert_wait_for_health_of!(net, [1..6, 1..6], Health::Alive);
+    for member in 1..6 {
+        assert_eq!(net.local_health_of(member), 0);
+    }
+}
+
+#[test]
+fn six_members_meshed_partition_persistent_member_and_heal_local_health_recovers() {
+    let mut net = btest::SwimNet::new(6);
+    net[0].member.write().expect("Member lock is poisoned").set_persistent(true);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    net.partition(0..1, 1..6);
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+    let local_health = net.local_health_of(0);
+    assert!(local_health > 0);
+    net.unpartition(0..1, 1..6);
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    assert!(net.wait_for_local_health_below(0, local_health));
+}
+
 #[test]
 fn six_members_unmeshed_become_fully_meshed_via_gossip() {
     let mut net = btest::SwimNet::new(6);
@@ -43,6 +43,8 @@ pub const CACHE_SSL_PATH: &'static str = "hab/cache/ssl";
 pub const PKG_PATH: &'static str = "hab/pkgs";
 /// The root path containing all runtime service directories and files
 const SVC_PATH: &'static str = "hab/svc";
+/// The root path containing the persisted state of each Supervisor
+const SUP_PATH: &'static str = "hab/sup";
 
 lazy_static! {
     static ref EUID: u32 = users::get_effective_uid();
@@ -186,6 +188,11 @@ pub fn svc_var_path(service_name: &str) -> PathBuf {
     svc_path(service_name).join("var")
 }
 
+/// Returns the path to a given Supervisor's persisted state.
+pub fn sup_path(sup_name: &str) -> PathBuf {
+    Path::new("/").join(SUP_PATH).join(sup_name)
+}
+
 /// Returns the absolute path for a given command, if it exists, by searching the `PATH`
 /// environment variable.
 ///
@@ -91,9 +91,13 @@ impl Child {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
-        try!(send_signal(self.pid, libc::SIGTERM));
+        self.terminate(libc::SIGTERM as u32, Duration::seconds(8))
+    }
+
+    pub fn terminate(&mut self, signal: u32, timeout: Duration) -> Result<ShutdownMethod> {
+        try!(send_signal(self.pid, signal as libc::c_int));
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + timeout;
         loop {
             match self.status() {
                 Ok(status) => {
@@ -15,6 +15,8 @@
 use std::fmt;
 use std::process::Child;
 
+use time::Duration;
+
 use error::Result;
 
 #[allow(unused_variables)]
@@ -68,6 +70,12 @@ impl HabChild {
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
         self.inner.kill()
     }
+
+    /// Send `signal` to the process, and kill it if it is still running once `timeout` has
+    /// passed.
+    pub fn terminate(&mut self, signal: u32, timeout: Duration) -> Result<ShutdownMethod> {
+        self.inner.terminate(signal, timeout)
+    }
 }
 
 impl fmt::Debug for HabChild {
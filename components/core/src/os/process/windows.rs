@@ -137,6 +137,12 @@ impl Child {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
+        self.terminate(15, Duration::seconds(8))
+    }
+
+    /// There are no signals on Windows; the process is always sent a ctrl-C, and `signal` is
+    /// ignored.
+    pub fn terminate(&mut self, _signal: u32, timeout: Duration) -> Result<ShutdownMethod> {
         if self.last_status.is_some() {
             return Ok(ShutdownMethod::AlreadyExited);
         }
@@ -162,7 +168,7 @@ impl Child {
             }
         }
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + timeout;
 
         let result;
         loop {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::cmp::{Ordering, PartialOrd};
 use std::env;
 use std::fs::{DirEntry, File};
@@ -26,7 +26,7 @@ use rustc_serialize::{Encoder, Encodable};
 
 use error::{Error, Result};
 use fs::{self, PKG_PATH};
-use package::{Identifiable, MetaFile, PackageIdent, Target, PackageTarget};
+use package::{Bind, Identifiable, MetaFile, PackageIdent, Target, PackageTarget};
 
 #[derive(Clone, Debug)]
 pub struct PackageInstall {
@@ -125,6 +125,55 @@ impl PackageInstall {
         self.read_deps(MetaFile::TDeps)
     }
 
+    /// Returns the binds the package's service requires, or an empty vector if the package
+    /// doesn't contain a BINDS Metafile
+    ///
+    /// # Failures
+    ///
+    /// * The package contains a BINDS metafile but it could not be read or it was malformed
+    pub fn binds(&self) -> Result<Vec<Bind>> {
+        self.read_binds(MetaFile::Binds)
+    }
+
+    /// Returns the binds the package's service can run without, or an empty vector if the package
+    /// doesn't contain a BINDS_OPTIONAL Metafile
+    ///
+    /// # Failures
+    ///
+    /// * The package contains a BINDS_OPTIONAL metafile but it could not be read or it was
+    ///   malformed
+    pub fn binds_optional(&self) -> Result<Vec<Bind>> {
+        self.read_binds(MetaFile::BindsOptional)
+    }
+
+    /// Returns the configuration keys the package's service exports to the services bound to
+    /// it, as a map of export name to the key's path in its configuration, or an empty map if
+    /// the package doesn't contain an EXPORTS Metafile
+    ///
+    /// # Failures
+    ///
+    /// * The package contains an EXPORTS metafile but it could not be read or it was malformed
+    pub fn exports(&self) -> Result<BTreeMap<String, String>> {
+        match self.read_metafile(MetaFile::Exports) {
+            Ok(body) => {
+                let mut exports = BTreeMap::new();
+                for line in body.lines().filter(|l| !l.trim().is_empty()) {
+                    let mut parts = line.splitn(2, '=');
+                    match (parts.next(), parts.next()) {
+                        (Some(name), Some(path)) if !name.trim().is_empty() &&
+                                                    !path.trim().is_empty() => {
+                            exports.insert(String::from(name.trim()), String::from(path.trim()));
+                        }
+                        _ => return Err(Error::MetaFileMalformed(MetaFile::Exports)),
+                    }
+                }
+                Ok(exports)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::Exports)) => Ok(BTreeMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// A vector of ports we expose
     pub fn exposes(&self) -> Result<Vec<String>> {
         match self.read_metafile(MetaFile::Exposes) {
@@ -255,6 +304,16 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the resource limits the package is specified to run with, as `name=value` pairs,
+    /// or an empty vector if the package doesn't contain a SVC_LIMITS Metafile
+    pub fn svc_limits(&self) -> Result<Vec<String>> {
+        match self.read_metafile(MetaFile::SvcLimits) {
+            Ok(body) => Ok(body.split_whitespace().map(|x| String::from(x)).collect()),
+            Err(Error::MetaFileNotFound(MetaFile::SvcLimits)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn target(&self) -> Result<PackageTarget> {
         match self.read_metafile(MetaFile::Target) {
             Ok(body) => PackageTarget::from_str(&body),
@@ -262,6 +321,23 @@ impl PackageInstall {
         }
     }
 
+    fn read_binds(&self, file: MetaFile) -> Result<Vec<Bind>> {
+        match self.read_metafile(file.clone()) {
+            Ok(body) => {
+                let mut binds = Vec::new();
+                for line in body.lines().filter(|l| !l.trim().is_empty()) {
+                    match Bind::from_metafile_line(line) {
+                        Some(bind) => binds.push(bind),
+                        None => return Err(Error::MetaFileMalformed(file)),
+                    }
+                }
+                Ok(binds)
+            }
+            Err(Error::MetaFileNotFound(_)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Read the contents of a given metafile.
     ///
     /// # Failures
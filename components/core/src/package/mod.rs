@@ -28,10 +28,13 @@ use std::fmt;
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum MetaFile {
+    Binds,
+    BindsOptional,
     CFlags,
     Config,
     Deps,
     TDeps,
+    Exports,
     Exposes,
     Ident,
     LdRunPath,
@@ -40,16 +43,20 @@ pub enum MetaFile {
     Path,
     SvcUser,
     SvcGroup,
+    SvcLimits,
     Target,
 }
 
 impl fmt::Display for MetaFile {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let id = match *self {
+            MetaFile::Binds => "BINDS",
+            MetaFile::BindsOptional => "BINDS_OPTIONAL",
             MetaFile::CFlags => "CFLAGS",
             MetaFile::Config => "default.toml",
             MetaFile::Deps => "DEPS",
             MetaFile::TDeps => "TDEPS",
+            MetaFile::Exports => "EXPORTS",
             MetaFile::Exposes => "EXPOSES",
             MetaFile::Ident => "IDENT",
             MetaFile::LdRunPath => "LD_RUN_PATH",
@@ -58,8 +65,62 @@ impl fmt::Display for MetaFile {
             MetaFile::Path => "PATH",
             MetaFile::SvcUser => "SVC_USER",
             MetaFile::SvcGroup => "SVC_GROUP",
+            MetaFile::SvcLimits => "SVC_LIMITS",
             MetaFile::Target => "TARGET",
         };
         write!(f, "{}", id)
     }
 }
+
+/// A service group a package's service can be bound to, by name, and the configuration keys it
+/// expects the bound service group to export.
+#[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct Bind {
+    pub service: String,
+    pub exports: Vec<String>,
+}
+
+impl Bind {
+    /// Parse a bind from a line of a `BINDS` metafile, as `name=export export`.
+    pub fn from_metafile_line(line: &str) -> Option<Bind> {
+        let mut parts = line.splitn(2, '=');
+        let service = match parts.next() {
+            Some(service) if !service.trim().is_empty() => service.trim(),
+            _ => return None,
+        };
+        let exports = match parts.next() {
+            Some(exports) => exports.split_whitespace().map(|e| String::from(e)).collect(),
+            None => return None,
+        };
+        Some(Bind {
+            service: String::from(service),
+            exports: exports,
+        })
+    }
+}
+
+impl fmt::Display for Bind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.service, self.exports.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Bind;
+
+    #[test]
+    fn bind_from_metafile_line() {
+        let bind = Bind::from_metafile_line("database=port host").unwrap();
+        assert_eq!(bind.service, "database");
+        assert_eq!(bind.exports, vec!["port", "host"]);
+        assert_eq!(bind.to_string(), "database=port host");
+        assert_eq!(Bind::from_metafile_line("cache=").unwrap().exports.len(), 0);
+    }
+
+    #[test]
+    fn bind_from_malformed_metafile_line() {
+        assert!(Bind::from_metafile_line("database").is_none());
+        assert!(Bind::from_metafile_line("=port").is_none());
+    }
+}
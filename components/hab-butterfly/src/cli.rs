@@ -71,6 +71,9 @@ fn sub_config_apply() -> App<'static, 'static> {
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
         (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
+        (@arg SECRET: -s --secret +takes_value
+            "A comma-delimited list of keys of the configuration to encrypt as secrets for \
+            the service group, which requires --org and --user (ex: db.password)")
     )
 }
 
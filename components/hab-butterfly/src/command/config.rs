@@ -35,7 +35,8 @@ pub mod apply {
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
                  user_pair: Option<&BoxKeyPair>,
-                 service_pair: Option<&BoxKeyPair>)
+                 service_pair: Option<&BoxKeyPair>,
+                 secrets: &[String])
                  -> Result<()> {
         try!(ui.begin(format!("Applying configuration for {} incarnation {}", sg, number,)));
 
@@ -55,12 +56,15 @@ pub mod apply {
 
         // We want to expire the borrow of body before we check to see if we need
         // to encrypt the contents.
-        {
+        let mut table = {
             let toml_str = try!(str::from_utf8(&body)
                 .map_err(|e| Error::Utf8Error(format!("{}", e))));
             let mut parser = toml::Parser::new(toml_str);
             match parser.parse() {
-                Some(_) => try!(ui.status(Status::Verified, "this configuration is valid TOML")),
+                Some(table) => {
+                    try!(ui.status(Status::Verified, "this configuration is valid TOML"));
+                    table
+                }
                 None => {
                     for err in parser.errors.iter() {
                         try!(ui.fatal("Invalid TOML"));
@@ -71,12 +75,30 @@ pub mod apply {
                         try!(ui.para(&toml_str[err.lo..]));
                         return Err(Error::TomlError);
                     }
+                    return Err(Error::TomlError);
                 }
             }
-        }
+        };
 
         let mut encrypted = false;
-        if service_pair.is_some() && user_pair.is_some() {
+        if !secrets.is_empty() {
+            let (user_pair, service_pair) = match (user_pair, service_pair) {
+                (Some(user_pair), Some(service_pair)) => (user_pair, service_pair),
+                _ => {
+                    return Err(Error::ArgumentError("Secrets are encrypted to the service \
+                                                     group's key; specify both --org and --user"))
+                }
+            };
+            try!(ui.status(Status::Encrypting,
+                           format!("{} secret(s) as {} for {}",
+                                   secrets.len(),
+                                   user_pair.name_with_rev(),
+                                   service_pair.name_with_rev())));
+            for key in secrets {
+                try!(encrypt_secret(&mut table, key, user_pair, service_pair));
+            }
+            body = toml::encode_str(&toml::Value::Table(table)).into_bytes();
+        } else if service_pair.is_some() && user_pair.is_some() {
             try!(ui.status(Status::Encrypting,
                            format!("TOML as {} for {}",
                                    user_pair.unwrap().name_with_rev(),
@@ -101,4 +123,38 @@ pub mod apply {
         try!(ui.end("Applied configuration"));
         Ok(())
     }
+
+    /// Replace the value at the dotted path `key` with a table holding only its TOML, encrypted
+    /// to the service group's key. The supervisors of the service group decrypt it in memory.
+    fn encrypt_secret(table: &mut toml::Table,
+                      key: &str,
+                      user_pair: &BoxKeyPair,
+                      service_pair: &BoxKeyPair)
+                      -> Result<()> {
+        let parts: Vec<&str> = key.split('.').collect();
+        let value = match value_at(table, &parts) {
+            Some(value) => value,
+            None => {
+                return Err(Error::CryptoCLI(format!("Secret {} is not in the configuration",
+                                                    key)))
+            }
+        };
+        let payload = try!(user_pair.encrypt(value.to_string().as_bytes(), service_pair));
+        let payload = try!(String::from_utf8(payload)
+            .map_err(|e| Error::Utf8Error(format!("{}", e))));
+        let mut secret = toml::Table::new();
+        secret.insert(String::from("secret"), toml::Value::String(payload));
+        *value = toml::Value::Table(secret);
+        Ok(())
+    }
+
+    fn value_at<'a>(table: &'a mut toml::Table, parts: &[&str]) -> Option<&'a mut toml::Value> {
+        if parts.len() == 1 {
+            return table.get_mut(parts[0]);
+        }
+        match table.get_mut(parts[0]) {
+            Some(&mut toml::Value::Table(ref mut t)) => value_at(t, &parts[1..]),
+            _ => None,
+        }
+    }
 }
@@ -128,6 +128,11 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         user_pair = Some(try!(BoxKeyPair::get_latest_pair_for(&user_name, &cache)));
     }
 
+    let secrets: Vec<String> = match m.value_of("SECRET") {
+        Some(secrets) => secrets.split(",").map(|s| s.trim().to_string()).collect(),
+        None => Vec::new(),
+    };
+
     command::config::apply::start(ui,
                                   &sg,
                                   number,
@@ -135,7 +140,8 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                   &peers,
                                   ring_key.as_ref(),
                                   user_pair.as_ref(),
-                                  service_pair.as_ref())
+                                  service_pair.as_ref(),
+                                  &secrets)
 }
 
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
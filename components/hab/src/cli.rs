@@ -130,6 +130,12 @@ pub fn get() -> App<'static, 'static> {
             (about: "Commands relating to Habitat packages")
             (aliases: &["p", "pk", "package"])
             (@setting ArgRequiredElseHelp)
+            (@subcommand binds =>
+                (about: "Prints the binds a package's service requires, and those it can use")
+                (aliases: &["bind"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            )
             (@subcommand binlink =>
                 (about: "Creates a symlink for a package binary in a common 'PATH' location")
                 (aliases: &["bi", "bin", "binl", "binli", "binlin"])
@@ -361,6 +367,9 @@ fn sub_config_apply() -> App<'static, 'static> {
         (@arg FILE: {file_exists_or_stdin}
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg SECRET: -s --secret +takes_value
+            "A comma-delimited list of keys of the configuration to encrypt as secrets for \
+            the service group, which requires --org and --user (ex: db.password)")
     )
 }
 
@@ -14,6 +14,32 @@
 
 // Looking for `pkg::install`? That's in the `common` crate. You're welcome :)
 
+pub mod binds {
+    use std::path::Path;
+
+    use hcore::package::{Bind, PackageIdent, PackageInstall};
+
+    use error::Result;
+
+    pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+        let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+        print_binds("Required", &try!(pkg_install.binds()));
+        print_binds("Optional", &try!(pkg_install.binds_optional()));
+        Ok(())
+    }
+
+    fn print_binds(kind: &str, binds: &[Bind]) {
+        if binds.is_empty() {
+            println!("{} binds: none", kind);
+            return;
+        }
+        println!("{} binds:", kind);
+        for bind in binds {
+            println!("    {}: {}", bind.service, bind.exports.join(", "));
+        }
+    }
+}
+
 pub mod binlink {
     use std::fs;
     use std::path::Path;
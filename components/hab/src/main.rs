@@ -101,6 +101,7 @@ fn start(ui: &mut UI) -> Result<()> {
         }
         ("pkg", Some(matches)) => {
             match matches.subcommand() {
+                ("binds", Some(m)) => try!(sub_pkg_binds(m)),
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(ui, m)),
                 ("build", Some(m)) => try!(sub_pkg_build(ui, m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
@@ -345,6 +346,14 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_pkg_binds(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));  // Required via clap
+
+    command::pkg::binds::start(&ident, &fs_root_path)
+}
+
 fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Path::new(&fs_root);
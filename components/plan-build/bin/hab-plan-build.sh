@@ -177,6 +177,27 @@
 # pkg_expose=(80 443)
 # ```
 #
+# ### pkg_binds
+# An associative array of the service groups this service must be bound to,
+# by bind name, and the configuration keys it expects each to export.
+# ```
+# pkg_binds=([database]="port host")
+# ```
+#
+# ### pkg_binds_optional
+# Like `pkg_binds`, but for service groups this service can run without.
+# ```
+# pkg_binds_optional=([cache]="port")
+# ```
+#
+# ### pkg_exports
+# An associative array of the configuration keys this service exports to the
+# services bound to it, by export name, and the path of each key in its
+# configuration. Only these keys are gossiped to the rest of the ring.
+# ```
+# pkg_exports=([port]=server.port [host]=server.host)
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -362,6 +383,14 @@ pkg_expose=()
 pkg_svc_user=hab
 # The group to run the service as
 pkg_svc_group=$pkg_svc_user
+# An array of resource limits to run the service with, as `name=value` pairs
+pkg_svc_limits=()
+# The binds the service requires, and the exports it expects of each
+declare -A pkg_binds
+# The binds the service can run without, and the exports it expects of each
+declare -A pkg_binds_optional
+# The configuration keys the service exports to the services bound to it
+declare -A pkg_exports
 
 # Initially set $pkg_svc_* variables. This happens before the Plan is sourced,
 # meaning that `$pkg_name` is not yet set. However, `$pkg_svc_run` wants
@@ -1828,15 +1857,19 @@ do_default_install() {
 
 # **Internal** Write out the package data to files:
 #
+# * `$pkg_prefix/BINDS` - Any binds the service requires, and their exports
+# * `$pkg_prefix/BINDS_OPTIONAL` - Any binds the service can run without
 # * `$pkg_prefix/BUILD_DEPS` - Any dependencies we need build the package
 # * `$pkg_prefix/CFLAGS` - Any CFLAGS for things that link against us
 # * `$pkg_prefix/PKG_CONFIG_PATH` - Any PKG_CONFIG_PATH entries for things that depend on us
 # * `$pkg_prefix/DEPS` - Any dependencies we need to use the package at runtime
 # * `$pkg_prefix/EXPOSES` - Any ports we expose
+# * `$pkg_prefix/EXPORTS` - Any configuration keys we export to bound services
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/SVC_LIMITS` - Any resource limits the service runs with
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=""
@@ -1982,6 +2015,21 @@ _build_metadata() {
 
   echo "$pkg_svc_user" > $pkg_prefix/SVC_USER
   echo "$pkg_svc_group" > $pkg_prefix/SVC_GROUP
+  if [[ ${#pkg_svc_limits[@]} -gt 0 ]]; then
+    echo "${pkg_svc_limits[@]}" > $pkg_prefix/SVC_LIMITS
+  fi
+
+  local bind
+  for bind in "${!pkg_binds[@]}"; do
+    echo "${bind}=${pkg_binds[$bind]}" >> $pkg_prefix/BINDS
+  done
+  for bind in "${!pkg_binds_optional[@]}"; do
+    echo "${bind}=${pkg_binds_optional[$bind]}" >> $pkg_prefix/BINDS_OPTIONAL
+  done
+  local export
+  for export in "${!pkg_exports[@]}"; do
+    echo "${export}=${pkg_exports[$export]}" >> $pkg_prefix/EXPORTS
+  done
 
   # Generate the blake2b hashes of all the files in the package. This
   # is not in the resulting MANIFEST because MANIFEST is included!
@@ -1998,7 +2046,7 @@ _build_metadata() {
 }
 
 # Copy the `./config` directory, relative to the Plan, to `$pkg_prefix/config`.
-# Do the same with `default.toml`. Delegates most of the implementation to the
+# Do the same with `default.toml` and `config_schema.toml`. Delegates most of the implementation to the
 # `do_default_build_config()` function.
 do_build_config() {
   do_default_build_config
@@ -2036,6 +2084,9 @@ do_default_build_config() {
   if [[ -f "$PLAN_CONTEXT/default.toml" ]]; then
     cp "$PLAN_CONTEXT/default.toml" $pkg_prefix
   fi
+  if [[ -f "$PLAN_CONTEXT/config_schema.toml" ]]; then
+    cp "$PLAN_CONTEXT/config_schema.toml" $pkg_prefix
+  fi
   return 0
 }
 
@@ -21,13 +21,29 @@
 //! ```
 //!
 //! Will show the `default.toml`.
+//!
+//! ```bash
+//! $ hab-sup config render core/redis --census census.json --user-toml user.toml
+//! ```
+//!
+//! Will show the configuration files and hooks `core/redis` would render, given the census.
 
 use std::io::prelude::*;
-use std::fs::File;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use hcore::service::ServiceGroup;
+use rustc_serialize::json;
+use toml;
 
-use error::Result;
+use error::{Error, Result};
 use config::gconfig;
+use manager::census::CensusList;
+use manager::service::config::ServiceConfig;
 use package::Package;
+use package::hooks::{self, HOOK_TYPES};
+
+static LOGKEY: &'static str = "CF";
 
 /// Print the default.toml for a given package.
 ///
@@ -44,3 +60,80 @@ pub fn display() -> Result<()> {
     println!("{}", s);
     Ok(())
 }
+
+/// Render the configuration files and hooks of a package from a census snapshot, without
+/// starting anything. The files are printed, or written under the render path if there is one.
+///
+/// The configuration is merged as it would be for a running service, except that the user layer
+/// is the given `user.toml`, or nothing at all.
+///
+/// # Failures
+///
+/// * If the package cannot be found
+/// * If the census snapshot or `user.toml` cannot be read or parsed
+/// * If the snapshot has no census for the service group
+/// * If a template fails to render
+pub fn render() -> Result<()> {
+    let package = try!(Package::load(gconfig().package(), None));
+    let census_path = gconfig().census().as_ref().expect("The census is a required argument");
+    let census_list: CensusList = try!(json::decode(&try!(read_file(census_path))));
+    let sg = ServiceGroup::new(package.name.clone(),
+                               gconfig().group(),
+                               gconfig().organization().clone())
+        .as_string();
+    if census_list.get(&sg).is_none() {
+        return Err(sup_error!(Error::CensusNotFound(sg)));
+    }
+    let mut service_config =
+        try!(ServiceConfig::new(&sg, &package, &census_list, gconfig().bind()));
+    let user = match *gconfig().user_toml() {
+        Some(ref path) => {
+            let contents = try!(read_file(path));
+            let mut toml_parser = toml::Parser::new(&contents);
+            try!(toml_parser.parse().ok_or(sup_error!(Error::TomlParser(toml_parser.errors))))
+        }
+        None => toml::Table::new(),
+    };
+    service_config.user_cfg(user);
+
+    let mut rendered: Vec<(PathBuf, String)> = Vec::new();
+    for (config, data) in try!(service_config.render(&package)) {
+        rendered.push((Path::new("config").join(config), data));
+    }
+    for hook_type in HOOK_TYPES.iter() {
+        let template = package.hook_template_path(hook_type);
+        if !template.is_file() {
+            continue;
+        }
+        let data = try!(hooks::render(&template, &service_config));
+        if let Some(name) = template.file_name() {
+            rendered.push((Path::new("hooks").join(name), data));
+        }
+    }
+
+    for (path, data) in rendered {
+        match *gconfig().render_path() {
+            Some(ref render_path) => {
+                let path = Path::new(render_path).join(path);
+                if let Some(parent) = path.parent() {
+                    try!(fs::create_dir_all(parent));
+                }
+                let mut file = try!(File::create(&path));
+                try!(file.write_all(data.as_bytes()));
+                println!("Rendered {}", path.display());
+            }
+            None => {
+                println!("==> {} <==", path.display());
+                println!("{}", data);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_file(path: &str) -> Result<String> {
+    let mut file = try!(File::open(path));
+    let mut s = String::new();
+    try!(file.read_to_string(&mut s));
+    Ok(s)
+}
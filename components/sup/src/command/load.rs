@@ -0,0 +1,43 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loads a service into an already running Supervisor.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup load acme/redis -g production -t leader
+//! ```
+//!
+//! Will ask the Supervisor listening on `127.0.0.1:9631` to install (if needed) and run the
+//! `redis` service in the `production` group, using the `leader` topology. The service is
+//! remembered by the Supervisor, and loaded again whenever it restarts.
+
+use ansi_term::Colour::Yellow;
+use rustc_serialize::json;
+
+use error::Result;
+use manager::service::spec::ServiceSpec;
+use util::remote_sup;
+
+static LOGKEY: &'static str = "LD";
+
+pub fn service() -> Result<()> {
+    let spec = ServiceSpec::from_config();
+    let body = try!(json::encode(&spec));
+    try!(remote_sup::post("/services", &body));
+    outputln!("Requested load of {}",
+              Yellow.bold().paint(spec.service_group().to_string()));
+    Ok(())
+}
@@ -20,4 +20,8 @@
 
 pub mod start;
 pub mod configure;
+pub mod load;
 pub mod shell;
+pub mod status;
+pub mod ring_key;
+pub mod unload;
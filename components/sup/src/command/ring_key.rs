@@ -0,0 +1,72 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rotates the ring key of an already running Supervisor.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup ring-key add myring
+//! $ hab-sup ring-key use myring
+//! $ hab-sup ring-key remove myring-20160504220722
+//! ```
+//!
+//! Will accept gossip encrypted with the latest revision of `myring` from the key cache, start
+//! encrypting gossip with it, and then stop accepting gossip encrypted with an older revision.
+//! Run each step on every Supervisor in the ring before moving on to the next one.
+
+use ansi_term::Colour::Yellow;
+use rustc_serialize::json::{self, Json};
+
+use config::gconfig;
+use error::Result;
+use util::remote_sup;
+
+static LOGKEY: &'static str = "RK";
+
+pub fn list() -> Result<()> {
+    let body = try!(remote_sup::get("/butterfly/ring-keys"));
+    let keyring: Json = try!(json::decode(&body));
+    let primary = keyring.find("primary").and_then(|p| p.as_string());
+    for key in keyring.find("keys").and_then(|k| k.as_array()).unwrap_or(&Vec::new()) {
+        let name = key.as_string().unwrap_or("-");
+        if Some(name) == primary {
+            println!("{} (primary)", name);
+        } else {
+            println!("{}", name);
+        }
+    }
+    Ok(())
+}
+
+pub fn add() -> Result<()> {
+    let name = try!(remote_sup::post(&format!("/butterfly/ring-keys/{}", gconfig().key()), ""));
+    outputln!("Accepting gossip encrypted with {}", Yellow.bold().paint(name));
+    Ok(())
+}
+
+pub fn use_key() -> Result<()> {
+    let name = try!(remote_sup::post(&format!("/butterfly/ring-keys/{}/primary",
+                                              gconfig().key()),
+                                     ""));
+    outputln!("Encrypting gossip with {}", Yellow.bold().paint(name));
+    Ok(())
+}
+
+pub fn remove() -> Result<()> {
+    let name = try!(remote_sup::delete(&format!("/butterfly/ring-keys/{}", gconfig().key())));
+    outputln!("No longer accepting gossip encrypted with {}",
+              Yellow.bold().paint(name));
+    Ok(())
+}
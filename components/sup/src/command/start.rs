@@ -71,6 +71,7 @@ use error::{Error, Result};
 use config::{gconfig, UpdateStrategy};
 use package::Package;
 use manager::Manager;
+use manager::service::spec::ServiceSpec;
 
 static LOGKEY: &'static str = "CS";
 
@@ -92,15 +93,28 @@ pub fn package() -> Result<()> {
         return Err(sup_error!(Error::RootRequired));
     }
 
-    match Package::load(gconfig().package(), None) {
+    let package = try!(load_package(gconfig().package(),
+                                    gconfig().local_artifact(),
+                                    gconfig().update_strategy()));
+    start_package(package)
+}
+
+/// Loads an installed package, installing it from a local artifact or the Depot first if it's
+/// missing. When the update strategy calls for it, a newer release from the Depot is installed
+/// and loaded instead.
+pub fn load_package(ident: &PackageIdent,
+                    local_artifact: Option<&str>,
+                    update_strategy: UpdateStrategy)
+                    -> Result<Package> {
+    let mut ui = UI::default();
+    match Package::load(ident, None) {
         Ok(mut package) => {
-            let update_strategy = gconfig().update_strategy();
             match update_strategy {
                 UpdateStrategy::None => {}
                 _ => {
                     let url = gconfig().url();
                     outputln!("Checking Depot for newer versions...");
-                    // It is important to pass `gconfig().package()` to `show_package()` instead
+                    // It is important to pass the requested `ident` to `show_package()` instead
                     // of the package identifier of the loaded package. This will ensure that
                     // if the operator starts a package while specifying a version number, they
                     // will only automatically receive release updates for the started package.
@@ -109,7 +123,7 @@ pub fn package() -> Result<()> {
                     // automatically receive updates for any releases, regardless of version
                     // number, for the started  package.
                     let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
-                    let latest_pkg_data = try!(depot_client.show_package(gconfig().package()));
+                    let latest_pkg_data = try!(depot_client.show_package(ident));
                     let latest_ident: PackageIdent = latest_pkg_data.get_ident().clone().into();
                     if &latest_ident > package.ident() {
                         outputln!("Downloading latest version from Depot: {}", latest_ident);
@@ -127,13 +141,13 @@ pub fn package() -> Result<()> {
                     };
                 }
             }
-            start_package(package)
+            Ok(package)
         }
         Err(_) => {
             outputln!("{} is not installed",
-                      Yellow.bold().paint(gconfig().package().to_string()));
+                      Yellow.bold().paint(ident.to_string()));
             let url = gconfig().url();
-            let new_pkg_data = match gconfig().local_artifact() {
+            let new_pkg_data = match local_artifact {
                 Some(artifact) => {
                     try!(install::start(&mut ui,
                                         url,
@@ -146,11 +160,11 @@ pub fn package() -> Result<()> {
                 }
                 None => {
                     outputln!("Searching for {} in remote {}",
-                              Yellow.bold().paint(gconfig().package().to_string()),
+                              Yellow.bold().paint(ident.to_string()),
                               url);
                     try!(install::start(&mut ui,
                                         url,
-                                        &gconfig().package().to_string(),
+                                        &ident.to_string(),
                                         PRODUCT,
                                         VERSION,
                                         Path::new(FS_ROOT_PATH),
@@ -158,8 +172,7 @@ pub fn package() -> Result<()> {
                                         false))
                 }
             };
-            let package = try!(Package::load(&new_pkg_data, None));
-            start_package(package)
+            Package::load(&new_pkg_data, None)
         }
     }
 }
@@ -170,6 +183,9 @@ fn start_package(package: Package) -> Result<()> {
     env::set_var("PATH", &run_path);
 
     let mut manager = try!(Manager::new());
-    try!(manager.add_service(package, *gconfig().topology(), gconfig().update_strategy()));
+    try!(manager.add_service(package, ServiceSpec::from_config()));
+    // Services loaded into a previous run of this Supervisor come back too; the service given on
+    // the command line wins if it was among them.
+    manager.load_persisted_services();
     manager.run()
 }
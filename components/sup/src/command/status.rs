@@ -0,0 +1,88 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lists the services loaded in an already running Supervisor.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup status
+//! ```
+
+use rustc_serialize::json::{self, Json};
+
+use error::Result;
+use util::remote_sup;
+
+pub fn display() -> Result<()> {
+    let body = try!(remote_sup::get("/services"));
+    let services: Json = try!(json::decode(&body));
+    println!("{:<30} {:<40} {:<8} {:<8} {:<8} {}",
+             "SERVICE GROUP",
+             "PACKAGE",
+             "DESIRED",
+             "STATE",
+             "RESTARTS",
+             "PID");
+    for service in services.as_array().unwrap_or(&Vec::new()) {
+        let sg = service.find("service_group");
+        let org = sg.and_then(|sg| sg.find("organization")).and_then(|o| o.as_string());
+        let service_group = match org {
+            Some(org) => {
+                format!("{}.{}@{}",
+                        field(sg, "service"),
+                        field(sg, "group"),
+                        org)
+            }
+            None => format!("{}.{}", field(sg, "service"), field(sg, "group")),
+        };
+        let pkg = service.find("package");
+        let package = format!("{}/{}/{}/{}",
+                              field(pkg, "origin"),
+                              field(pkg, "name"),
+                              field(pkg, "version"),
+                              field(pkg, "release"));
+        let supervisor = service.find("supervisor");
+        let pid = match supervisor.and_then(|s| s.find("pid")).and_then(|p| p.as_u64()) {
+            Some(pid) => pid.to_string(),
+            None => "-".to_string(),
+        };
+        // A service held down by its restart policy says so, rather than just showing as down.
+        let restarts = service.find("restarts");
+        let state = match field(restarts, "state").as_ref() {
+            "Failed" => "failed".to_string(),
+            "Backoff" => "backoff".to_string(),
+            _ => field(supervisor, "state"),
+        };
+        let restart_count = match restarts.and_then(|r| r.find("count")).and_then(|c| c.as_u64()) {
+            Some(count) => count.to_string(),
+            None => "-".to_string(),
+        };
+        println!("{:<30} {:<40} {:<8} {:<8} {:<8} {}",
+                 service_group,
+                 package,
+                 field(Some(service), "desired_state"),
+                 state,
+                 restart_count,
+                 pid);
+    }
+    Ok(())
+}
+
+fn field(json: Option<&Json>, key: &str) -> String {
+    json.and_then(|j| j.find(key))
+        .and_then(|v| v.as_string())
+        .unwrap_or("-")
+        .to_string()
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unloads a service from an already running Supervisor.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup unload acme/redis -g production
+//! ```
+//!
+//! Will stop the `redis` service in the `production` group and forget about it, so it isn't
+//! loaded again when the Supervisor restarts.
+
+use ansi_term::Colour::Yellow;
+
+use error::Result;
+use manager::service::spec::ServiceSpec;
+use util::remote_sup;
+
+static LOGKEY: &'static str = "UL";
+
+pub fn service() -> Result<()> {
+    let service_group = ServiceSpec::from_config().service_group();
+    let path = match service_group.organization {
+        Some(ref org) => {
+            format!("/services/{}/{}/{}",
+                    service_group.service,
+                    service_group.group,
+                    org)
+        }
+        None => format!("/services/{}/{}", service_group.service, service_group.group),
+    };
+    try!(remote_sup::delete(&path));
+    outputln!("Requested unload of {}",
+              Yellow.bold().paint(service_group.to_string()));
+    Ok(())
+}
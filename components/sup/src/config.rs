@@ -32,6 +32,9 @@ use hcore::package::PackageIdent;
 
 use error::{Error, Result, SupError};
 use http_gateway;
+use limits::ResourceLimits;
+use logs::LogFormat;
+pub use manager::service::restart::RestartPolicy;
 pub use manager::service_updater::UpdateStrategy;
 
 static LOGKEY: &'static str = "CFG";
@@ -66,9 +69,17 @@ pub fn gconfig() -> &'static Config {
 /// An enum with the various CLI commands. Used to keep track of what command was called.
 pub enum Command {
     Config,
+    ConfigRender,
     Start,
     ShellBash,
     ShellSh,
+    Load,
+    Unload,
+    Status,
+    RingKeyList,
+    RingKeyAdd,
+    RingKeyUse,
+    RingKeyRemove,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -122,11 +133,17 @@ impl ToSocketAddrs for GossipListenAddr {
     }
 }
 
-#[derive(PartialEq, Eq, Debug, RustcEncodable, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, RustcEncodable, RustcDecodable, Clone, Copy)]
 pub enum Topology {
     Standalone,
     Leader,
     Initializer,
+    /// Members start one at a time, in suitability order, each waiting for the members ahead of
+    /// it to have started.
+    Ordered,
+    /// Like `Leader`, but the leader holds a lease which outlives a loss of quorum, and the group
+    /// keeps running while there is none.
+    Lease,
 }
 
 impl Default for Topology {
@@ -140,9 +157,17 @@ impl FromStr for Command {
     fn from_str(s: &str) -> Result<Command> {
         match s {
             "config" => Ok(Command::Config),
+            "config-render" => Ok(Command::ConfigRender),
             "bash" => Ok(Command::ShellBash),
             "sh" => Ok(Command::ShellSh),
             "start" => Ok(Command::Start),
+            "load" => Ok(Command::Load),
+            "unload" => Ok(Command::Unload),
+            "status" => Ok(Command::Status),
+            "ring-key-list" => Ok(Command::RingKeyList),
+            "ring-key-add" => Ok(Command::RingKeyAdd),
+            "ring-key-use" => Ok(Command::RingKeyUse),
+            "ring-key-remove" => Ok(Command::RingKeyRemove),
             _ => Err(sup_error!(Error::CommandNotImplemented)),
         }
     }
@@ -176,6 +201,9 @@ pub struct Config {
     servicekey: Option<String>,
     infile: Option<String>,
     outfile: Option<String>,
+    user_toml: Option<String>,
+    census: Option<String>,
+    render_path: Option<String>,
     gossip_peer: Vec<String>,
     gossip_permanent: bool,
     update_strategy: UpdateStrategy,
@@ -185,6 +213,20 @@ pub struct Config {
     organization: Option<String>,
     ring: Option<String>,
     config_from: Option<String>,
+    gateway_auth_token: Option<String>,
+    name: String,
+    remote_sup: Option<String>,
+    health_check_interval: u64,
+    reap_after: u64,
+    restart_policy: RestartPolicy,
+    max_restarts: u32,
+    restart_window: u64,
+    stop_signal: u32,
+    stop_timeout: u64,
+    limits: ResourceLimits,
+    log_format: LogFormat,
+    log_max_size: u64,
+    log_max_age: u64,
 }
 
 impl Config {
@@ -302,6 +344,39 @@ impl Config {
         &self.outfile
     }
 
+    /// Set the user.toml to render configuration with
+    pub fn set_user_toml(&mut self, user_toml: String) -> &mut Config {
+        self.user_toml = Some(user_toml);
+        self
+    }
+
+    /// Return the user.toml to render configuration with
+    pub fn user_toml(&self) -> &Option<String> {
+        &self.user_toml
+    }
+
+    /// Set the census snapshot to render configuration with
+    pub fn set_census(&mut self, census: String) -> &mut Config {
+        self.census = Some(census);
+        self
+    }
+
+    /// Return the census snapshot to render configuration with
+    pub fn census(&self) -> &Option<String> {
+        &self.census
+    }
+
+    /// Set the directory to write rendered configuration to
+    pub fn set_render_path(&mut self, render_path: String) -> &mut Config {
+        self.render_path = Some(render_path);
+        self
+    }
+
+    /// Return the directory to write rendered configuration to
+    pub fn render_path(&self) -> &Option<String> {
+        &self.render_path
+    }
+
     /// Set the key expire days
     pub fn set_expire_days(&mut self, expire_days: u16) -> &mut Config {
         self.expire_days = Some(expire_days);
@@ -483,6 +558,160 @@ impl Config {
     pub fn ring(&self) -> &Option<String> {
         &self.ring
     }
+
+    /// Set the bearer token required by the http gateway's control endpoints
+    pub fn set_gateway_auth_token(&mut self, token: String) -> &mut Config {
+        self.gateway_auth_token = Some(token);
+        self
+    }
+
+    /// Return the bearer token required by the http gateway's control endpoints
+    pub fn gateway_auth_token(&self) -> &Option<String> {
+        &self.gateway_auth_token
+    }
+
+    /// Set the name of this Supervisor
+    pub fn set_name(&mut self, name: String) -> &mut Config {
+        self.name = name;
+        self
+    }
+
+    /// Return the name of this Supervisor
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Set the http gateway address of the Supervisor a client command talks to
+    pub fn set_remote_sup(&mut self, remote_sup: String) -> &mut Config {
+        self.remote_sup = Some(remote_sup);
+        self
+    }
+
+    /// Return the http gateway address of the Supervisor a client command talks to
+    pub fn remote_sup(&self) -> &Option<String> {
+        &self.remote_sup
+    }
+
+    /// Set how often, in seconds, each service's health check is run
+    pub fn set_health_check_interval(&mut self, interval: u64) -> &mut Config {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Return how often, in seconds, each service's health check is run
+    pub fn health_check_interval(&self) -> u64 {
+        self.health_check_interval
+    }
+
+    /// Set how long, in seconds, a confirmed or departed member is kept before it is reaped
+    pub fn set_reap_after(&mut self, seconds: u64) -> &mut Config {
+        self.reap_after = seconds;
+        self
+    }
+
+    /// Return how long, in seconds, a confirmed or departed member is kept before it is reaped
+    pub fn reap_after(&self) -> u64 {
+        self.reap_after
+    }
+
+    /// Set what happens when a service's process exits on its own
+    pub fn set_restart_policy(&mut self, policy: RestartPolicy) -> &mut Config {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// Return what happens when a service's process exits on its own
+    pub fn restart_policy(&self) -> RestartPolicy {
+        self.restart_policy
+    }
+
+    /// Set how many restarts are allowed within the restart window before a service is failed
+    pub fn set_max_restarts(&mut self, max_restarts: u32) -> &mut Config {
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Return how many restarts are allowed within the restart window before a service is failed
+    pub fn max_restarts(&self) -> u32 {
+        self.max_restarts
+    }
+
+    /// Set the length of the restart window, in seconds
+    pub fn set_restart_window(&mut self, window: u64) -> &mut Config {
+        self.restart_window = window;
+        self
+    }
+
+    /// Return the length of the restart window, in seconds
+    pub fn restart_window(&self) -> u64 {
+        self.restart_window
+    }
+
+    /// Set the signal sent to a service's process to stop it
+    pub fn set_stop_signal(&mut self, signal: u32) -> &mut Config {
+        self.stop_signal = signal;
+        self
+    }
+
+    /// Return the signal sent to a service's process to stop it
+    pub fn stop_signal(&self) -> u32 {
+        self.stop_signal
+    }
+
+    /// Set how long a stopping process has to exit before it is killed, in seconds
+    pub fn set_stop_timeout(&mut self, timeout: u64) -> &mut Config {
+        self.stop_timeout = timeout;
+        self
+    }
+
+    /// Return how long a stopping process has to exit before it is killed, in seconds
+    pub fn stop_timeout(&self) -> u64 {
+        self.stop_timeout
+    }
+
+    /// Set the resource limits a service's process runs with, over those of its plan
+    pub fn set_limits(&mut self, limits: ResourceLimits) -> &mut Config {
+        self.limits = limits;
+        self
+    }
+
+    /// Return the resource limits a service's process runs with, over those of its plan
+    pub fn limits(&self) -> ResourceLimits {
+        self.limits.clone()
+    }
+
+    /// Set the format a service's log is written in
+    pub fn set_log_format(&mut self, format: LogFormat) -> &mut Config {
+        self.log_format = format;
+        self
+    }
+
+    /// Return the format a service's log is written in
+    pub fn log_format(&self) -> LogFormat {
+        self.log_format
+    }
+
+    /// Set how big a service's log gets before it is rotated, in bytes
+    pub fn set_log_max_size(&mut self, size: u64) -> &mut Config {
+        self.log_max_size = size;
+        self
+    }
+
+    /// Return how big a service's log gets before it is rotated, in bytes
+    pub fn log_max_size(&self) -> u64 {
+        self.log_max_size
+    }
+
+    /// Set how long a service's log is written to before it is rotated, in seconds
+    pub fn set_log_max_age(&mut self, age: u64) -> &mut Config {
+        self.log_max_age = age;
+        self
+    }
+
+    /// Return how long a service's log is written to before it is rotated, in seconds
+    pub fn log_max_age(&self) -> u64 {
+        self.log_max_age
+    }
 }
 
 #[cfg(test)]
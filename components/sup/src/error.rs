@@ -51,6 +51,7 @@ use std::sync::mpsc;
 use ansi_term::Colour::Red;
 use handlebars;
 use hcore::package::Identifiable;
+use hyper;
 use butterfly;
 use rustc_serialize::json;
 use toml;
@@ -98,7 +99,12 @@ impl SupError {
 #[derive(Debug)]
 pub enum Error {
     ButterflyError(butterfly::error::Error),
+    CensusNotFound(String),
     CommandNotImplemented,
+    /// A service's configuration does not match the schema its package declares. This error
+    /// contains every problem that was found.
+    ConfigInvalid(Vec<String>),
+    ConfigSchemaInvalid(String),
     DbInvalidPath,
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
@@ -113,17 +119,25 @@ pub enum Error {
     /// A hook failed to successfully execute. This error contains the type of hook which failed
     /// to run and the exit code.
     HookFailed(HookType, i32),
+    /// A hook was killed for running longer than the number of seconds it was allowed.
+    HookTimedOut(HookType, u64),
+    HyperError(hyper::Error),
     InvalidBinding(String),
     InvalidKeyParameter(String),
+    InvalidLimit(String),
     InvalidPidFile,
     InvalidPort(num::ParseIntError),
+    InvalidSecret(String),
     InvalidServiceGroupString(String),
+    InvalidSignal(String),
+    InvalidSuitability(String),
     Io(io::Error),
     IPFailed,
     JsonDecode(json::DecoderError),
     JsonEncode(json::EncoderError),
     KeyNotFound(String),
     MetaFileIO(io::Error),
+    MissingRequiredBinds(Vec<String>),
     NameLookup(io::Error),
     NetParseError(net::AddrParseError),
     NoRunFile,
@@ -132,7 +146,10 @@ pub enum Error {
     PackageNotFound(package::PackageIdent),
     Permissions(String),
     RemotePackageNotFound(package::PackageIdent),
+    RemoteSupFailed(String),
     RootRequired,
+    ServiceAlreadyLoaded(String),
+    ServiceNotLoaded(String),
     SignalFailed,
     SignalNotifierStarted,
     StrFromUtf8Error(str::Utf8Error),
@@ -141,6 +158,7 @@ pub enum Error {
     TomlMergeError(String),
     TomlParser(Vec<toml::ParserError>),
     TryRecvError(mpsc::TryRecvError),
+    UnknownBind(String),
     UnknownTopology(String),
     UnpackFailed,
 }
@@ -162,7 +180,12 @@ impl fmt::Display for SupError {
             Error::HabitatCore(ref err) => format!("{}", err),
             Error::HandlebarsTemplateFileError(ref err) => format!("{:?}", err),
             Error::HandlebarsRenderError(ref err) => format!("{}", err),
+            Error::CensusNotFound(ref sg) => format!("No census for {} in the snapshot", sg),
             Error::CommandNotImplemented => format!("Command is not yet implemented!"),
+            Error::ConfigInvalid(ref errs) => {
+                format!("Configuration does not match its schema: {}", errs.join("; "))
+            }
+            Error::ConfigSchemaInvalid(ref e) => format!("Invalid configuration schema: {}", e),
             Error::DbInvalidPath => format!("Invalid filepath to internal datastore"),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::EnvJoinPathsError(ref err) => format!("{}", err),
@@ -173,6 +196,10 @@ impl fmt::Display for SupError {
             Error::HookFailed(ref hook, ref code) => {
                 format!("{} hook failed to run with exit code {}", hook, code)
             }
+            Error::HookTimedOut(ref hook, ref secs) => {
+                format!("{} hook was killed after running for {} seconds", hook, secs)
+            }
+            Error::HyperError(ref err) => format!("{}", err),
             Error::InvalidBinding(ref binding) => {
                 format!("Invalid binding - must be ':' delimited: {}", binding)
             }
@@ -182,16 +209,27 @@ impl fmt::Display for SupError {
             Error::InvalidPort(ref e) => {
                 format!("Invalid port number in package expose metadata: {}", e)
             }
+            Error::InvalidSecret(ref e) => {
+                format!("Secret {} did not decrypt to a TOML value", e)
+            }
+            Error::InvalidLimit(ref e) => format!("Invalid resource limit: {}", e),
             Error::InvalidPidFile => format!("Invalid child process PID file"),
             Error::InvalidServiceGroupString(ref e) => {
                 format!("Invalid service group string: {}", e)
             }
+            Error::InvalidSignal(ref e) => format!("Invalid signal: {}", e),
+            Error::InvalidSuitability(ref e) => {
+                format!("Suitability hook must print a positive integer, not {:?}", e)
+            }
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
             Error::JsonEncode(ref e) => format!("JSON encoding error: {}", e),
             Error::KeyNotFound(ref e) => format!("Key not found in key cache: {}", e),
             Error::MetaFileIO(ref e) => format!("IO error while accessing MetaFile: {:?}", e),
+            Error::MissingRequiredBinds(ref e) => {
+                format!("Missing required bind(s), specify with --bind: {}", e.join(", "))
+            }
             Error::NameLookup(ref e) => format!("Error resolving a name or IP address: {}", e),
             Error::NetParseError(ref e) => format!("Can't parse ip:port: {}", e),
             Error::NoRunFile => {
@@ -217,9 +255,16 @@ impl fmt::Display for SupError {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::RemoteSupFailed(ref e) => format!("Request to the Supervisor failed: {}", e),
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
+            Error::ServiceAlreadyLoaded(ref sg) => {
+                format!("Service group {} is already loaded in this Supervisor", sg)
+            }
+            Error::ServiceNotLoaded(ref sg) => {
+                format!("Service group {} is not loaded in this Supervisor", sg)
+            }
             Error::SignalFailed => format!("Failed to send a signal to the child process"),
             Error::SignalNotifierStarted => {
                 format!("Only one instance of a Signal Notifier may be running")
@@ -232,6 +277,11 @@ impl fmt::Display for SupError {
                 format!("Failed to parse toml:\n{}", toml_parser_string(errs))
             }
             Error::TryRecvError(ref err) => format!("{}", err),
+            Error::UnknownBind(ref b) => {
+                format!("Unknown bind {}; the package doesn't declare it in pkg_binds or \
+                         pkg_binds_optional",
+                        b)
+            }
             Error::UnknownTopology(ref t) => format!("Unknown topology {}!", t),
             Error::UnpackFailed => format!("Failed to unpack a package"),
         };
@@ -258,26 +308,38 @@ impl error::Error for SupError {
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
 
+            Error::CensusNotFound(_) => "No census for the service group in the snapshot",
             Error::CommandNotImplemented => "Command is not yet implemented!",
+            Error::ConfigInvalid(_) => "Configuration does not match its schema",
+            Error::ConfigSchemaInvalid(_) => "Invalid configuration schema",
             Error::DbInvalidPath => "A bad filepath was provided for an internal datastore",
             Error::DepotClient(ref err) => err.description(),
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
             Error::HealthCheckBadExit(_) => "Health Check exited with an unknown status code",
             Error::HookFailed(_, _) => "Hook failed to run",
+            Error::HookTimedOut(_, _) => "Hook was killed for running too long",
+            Error::HyperError(ref err) => err.description(),
             Error::InvalidBinding(_) => "Invalid binding parameter",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPort(_) => "Invalid port number in package expose metadata",
+            Error::InvalidLimit(_) => {
+                "Resource limits must be one of cpu, memory, pids or nofile, with a positive value"
+            }
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidSecret(_) => "Secret did not decrypt to a TOML value",
             Error::InvalidServiceGroupString(_) => {
                 "Service group strings must be in service.group format (example: redis.default)"
             }
+            Error::InvalidSignal(_) => "Signal must be a known signal name or number",
+            Error::InvalidSuitability(_) => "Suitability hook must print a positive integer",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
             Error::JsonEncode(_) => "JSON encoding error",
             Error::KeyNotFound(_) => "Key not found in key cache",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
+            Error::MissingRequiredBinds(_) => "A service was loaded without its required binds",
             Error::NetParseError(_) => "Can't parse IP:port",
             Error::NameLookup(_) => "Error resolving a name or IP address",
             Error::NoRunFile => {
@@ -293,9 +355,12 @@ impl error::Error for SupError {
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::Permissions(_) => "File system permissions error",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
+            Error::RemoteSupFailed(_) => "The Supervisor rejected or failed the request",
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation"
             }
+            Error::ServiceAlreadyLoaded(_) => "Service group is already loaded",
+            Error::ServiceNotLoaded(_) => "Service group is not loaded",
             Error::SignalFailed => "Failed to send a signal to the child process",
             Error::SignalNotifierStarted => "Only one instance of a Signal Notifier may be running",
             Error::StrFromUtf8Error(_) => "Failed to convert a str from a &[u8] as UTF-8",
@@ -304,6 +369,7 @@ impl error::Error for SupError {
             Error::TomlMergeError(_) => "Failed to merge toml!",
             Error::TomlParser(_) => "Failed to parse toml!",
             Error::TryRecvError(_) => "A channel failed to receive a response",
+            Error::UnknownBind(_) => "Bind is not declared by the package",
             Error::UnknownTopology(_) => "Unknown topology",
             Error::UnpackFailed => "Failed to unpack a package",
         }
@@ -367,6 +433,12 @@ impl From<ffi::NulError> for SupError {
     }
 }
 
+impl From<hyper::Error> for SupError {
+    fn from(err: hyper::Error) -> SupError {
+        sup_error!(Error::HyperError(err))
+    }
+}
+
 impl From<io::Error> for SupError {
     fn from(err: io::Error) -> SupError {
         sup_error!(Error::Io(err))
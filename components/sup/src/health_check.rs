@@ -12,9 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::fmt::{self, Display, Formatter};
+use std::result;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable)]
+use butterfly::message::swim::Service_Health;
+use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
+use time;
+
+/// The number of results we keep in a service's health check history.
+pub const HISTORY_LEN: usize = 20;
+
+/// A service is flapping when its health has changed at least this many times within its
+/// history.
+pub const FLAPPING_THRESHOLD: usize = 5;
+
+/// The result of a health check. Encodes as its display name (`OK`, `CRITICAL`, ...) so that it
+/// can be compared against in templates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CheckResult {
     Ok,
     Warning,
@@ -33,3 +49,180 @@ impl Display for CheckResult {
         write!(f, "{}", msg)
     }
 }
+
+impl FromStr for CheckResult {
+    type Err = String;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_uppercase().as_ref() {
+            "OK" => Ok(CheckResult::Ok),
+            "WARNING" => Ok(CheckResult::Warning),
+            "CRITICAL" => Ok(CheckResult::Critical),
+            "UNKNOWN" => Ok(CheckResult::Unknown),
+            _ => Err(format!("Unknown health check result: {}", value)),
+        }
+    }
+}
+
+impl Encodable for CheckResult {
+    fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
+        s.emit_str(&self.to_string())
+    }
+}
+
+impl Decodable for CheckResult {
+    fn decode<D: Decoder>(d: &mut D) -> result::Result<Self, D::Error> {
+        let value = try!(d.read_str());
+        CheckResult::from_str(&value).map_err(|e| d.error(&e))
+    }
+}
+
+impl From<CheckResult> for Service_Health {
+    fn from(result: CheckResult) -> Service_Health {
+        match result {
+            CheckResult::Ok => Service_Health::OK,
+            CheckResult::Warning => Service_Health::WARNING,
+            CheckResult::Critical => Service_Health::CRITICAL,
+            CheckResult::Unknown => Service_Health::UNKNOWN,
+        }
+    }
+}
+
+impl From<Service_Health> for CheckResult {
+    fn from(health: Service_Health) -> CheckResult {
+        match health {
+            Service_Health::OK => CheckResult::Ok,
+            Service_Health::WARNING => CheckResult::Warning,
+            Service_Health::CRITICAL => CheckResult::Critical,
+            Service_Health::UNKNOWN => CheckResult::Unknown,
+        }
+    }
+}
+
+/// A single run of a health check, and when it happened (in seconds since the epoch).
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable)]
+pub struct CheckRecord {
+    pub result: CheckResult,
+    pub timestamp: i64,
+}
+
+/// The most recent health check results for a service, oldest first.
+#[derive(Debug, Clone, RustcEncodable)]
+pub struct HealthHistory {
+    results: VecDeque<CheckRecord>,
+    flapping: bool,
+}
+
+impl HealthHistory {
+    pub fn new() -> Self {
+        HealthHistory {
+            results: VecDeque::with_capacity(HISTORY_LEN),
+            flapping: false,
+        }
+    }
+
+    /// Record a new result, dropping the oldest one if the history is full. Returns true if the
+    /// result differs from the one before it.
+    pub fn push(&mut self, result: CheckResult) -> bool {
+        let changed = self.latest().map_or(true, |last| last.result != result);
+        if self.results.len() == HISTORY_LEN {
+            self.results.pop_front();
+        }
+        self.results.push_back(CheckRecord {
+            result: result,
+            timestamp: time::now_utc().to_timespec().sec,
+        });
+        self.flapping = self.transitions() >= FLAPPING_THRESHOLD;
+        changed
+    }
+
+    /// Returns the most recent result, if the check has run at all.
+    pub fn latest(&self) -> Option<&CheckRecord> {
+        self.results.back()
+    }
+
+    /// Returns true if the service's health keeps changing.
+    pub fn is_flapping(&self) -> bool {
+        self.flapping
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    fn transitions(&self) -> usize {
+        self.results
+            .iter()
+            .zip(self.results.iter().skip(1))
+            .filter(|&(a, b)| a.result != b.result)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use rustc_serialize::json;
+
+    use super::{CheckResult, HealthHistory, FLAPPING_THRESHOLD, HISTORY_LEN};
+
+    #[test]
+    fn check_result_encodes_as_its_name() {
+        assert_eq!(json::encode(&CheckResult::Critical).unwrap(), "\"CRITICAL\"");
+        let decoded: CheckResult = json::decode("\"WARNING\"").unwrap();
+        assert_eq!(decoded, CheckResult::Warning);
+        assert_eq!(CheckResult::from_str("ok").unwrap(), CheckResult::Ok);
+        assert!(CheckResult::from_str("sad").is_err());
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut history = HealthHistory::new();
+        for _ in 0..(HISTORY_LEN * 2) {
+            history.push(CheckResult::Ok);
+        }
+        assert_eq!(history.len(), HISTORY_LEN);
+    }
+
+    #[test]
+    fn push_reports_changes() {
+        let mut history = HealthHistory::new();
+        assert!(history.push(CheckResult::Ok));
+        assert!(!history.push(CheckResult::Ok));
+        assert!(history.push(CheckResult::Critical));
+        assert_eq!(history.latest().unwrap().result, CheckResult::Critical);
+    }
+
+    #[test]
+    fn alternating_results_are_flapping() {
+        let mut history = HealthHistory::new();
+        history.push(CheckResult::Ok);
+        for i in 0..FLAPPING_THRESHOLD {
+            assert!(!history.is_flapping());
+            if i % 2 == 0 {
+                history.push(CheckResult::Critical);
+            } else {
+                history.push(CheckResult::Ok);
+            }
+        }
+        assert!(history.is_flapping());
+    }
+
+    #[test]
+    fn flapping_ends_once_transitions_age_out() {
+        let mut history = HealthHistory::new();
+        for i in 0..(FLAPPING_THRESHOLD + 1) {
+            if i % 2 == 0 {
+                history.push(CheckResult::Ok);
+            } else {
+                history.push(CheckResult::Warning);
+            }
+        }
+        assert!(history.is_flapping());
+        for _ in 0..HISTORY_LEN {
+            history.push(CheckResult::Ok);
+        }
+        assert!(!history.is_flapping());
+    }
+}
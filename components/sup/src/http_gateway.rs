@@ -12,28 +12,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io;
+use std::cmp;
+use std::io::{self, Read, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
+use std::result;
 use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
+use butterfly::error::Error as ButterflyError;
+use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::service::ServiceGroup;
+use iron::headers::{Authorization, Bearer, CacheControl, CacheDirective};
+use iron::mime::Mime;
 use iron::prelude::*;
+use iron::response::{ResponseBody, WriteBody};
 use iron::status;
 use iron::typemap;
 use persistent;
 use router::Router;
 use rustc_serialize::json;
+use url::form_urlencoded;
 
 use config::gconfig;
 use error::{Result, Error, SupError};
 use health_check;
+use logs;
 use manager;
+use manager::{RestartOutcome, ServiceRequest};
+use manager::events::EventLog;
+use manager::service::spec::ServiceSpec;
+use manager::signals::Signal;
+use metrics as sup_metrics;
 
 static LOGKEY: &'static str = "HG";
 
+/// How often an event stream looks for new events, in milliseconds.
+const EVENT_POLL_INTERVAL_MS: u64 = 500;
+
+/// How often an idle event stream sends a comment, in milliseconds, so that we notice clients
+/// which went away.
+const EVENT_KEEPALIVE_MS: u64 = 15_000;
+
+/// How many event streams we serve at once. Each stream holds one of the gateway's worker threads
+/// for as long as the client stays connected, so we leave the rest for everything else.
+const MAX_EVENT_STREAMS: usize = 4;
+
+/// How many event streams are open.
+static EVENT_STREAMS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// How many lines of a service's log we answer with, unless asked for more or fewer.
+const DEFAULT_LOG_LINES: usize = 100;
+
+/// The most lines of a service's log we answer with, however many are asked for.
+const MAX_LOG_LINES: usize = 10_000;
+
+/// How long, in milliseconds, a restart request waits for the manager to say what came of it.
+const RESTART_REPLY_TIMEOUT_MS: u64 = 30_000;
+
 #[derive(PartialEq, Eq, Debug)]
 pub struct ListenAddr(SocketAddr);
 
@@ -97,12 +138,33 @@ impl Server {
     pub fn new(manager_state: manager::State) -> Self {
         let router = router!(
             butterfly: get "/butterfly" => butterfly,
+            ring_keys: get "/butterfly/ring-keys" => ring_keys,
+            ring_key_add: post "/butterfly/ring-keys/:key" => ring_key_add,
+            ring_key_primary: post "/butterfly/ring-keys/:key/primary" => ring_key_primary,
+            ring_key_remove: delete "/butterfly/ring-keys/:key" => ring_key_remove,
             census: get "/census" => census,
+            events: get "/events" => events,
+            metrics: get "/metrics" => metrics,
             services: get "/services" => services,
+            service_load: post "/services" => load,
+            service_unload: delete "/services/:svc/:group" => unload,
+            service_unload_org: delete "/services/:svc/:group/:org" => unload,
             service_config: get "/services/:svc/:group/config" => config,
             service_health: get "/services/:svc/:group/health" => health,
             service_config_org: get "/services/:svc/:group/:org/config" => config,
             service_health_org: get "/services/:svc/:group/:org/health" => health,
+            service_health_history: get "/services/:svc/:group/health/history" => health_history,
+            service_health_history_org: get "/services/:svc/:group/:org/health/history" => health_history,
+            service_logs: get "/services/:svc/:group/logs" => logs,
+            service_logs_org: get "/services/:svc/:group/:org/logs" => logs,
+            service_start: post "/services/:svc/:group/start" => start,
+            service_stop: post "/services/:svc/:group/stop" => stop,
+            service_restart: post "/services/:svc/:group/restart" => restart,
+            service_signal: post "/services/:svc/:group/signal/:signal" => signal,
+            service_start_org: post "/services/:svc/:group/:org/start" => start,
+            service_stop_org: post "/services/:svc/:group/:org/stop" => stop,
+            service_restart_org: post "/services/:svc/:group/:org/restart" => restart,
+            service_signal_org: post "/services/:svc/:group/:org/signal/:signal" => signal,
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerState>::both(manager_state));
@@ -126,13 +188,122 @@ fn butterfly(req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((status::Ok, json::encode(&state.butterfly).unwrap())))
 }
 
+/// Answers with the names of the ring keys we accept, and the one we encrypt with. Only the names
+/// are shown, but they say which keys an attacker would need, so they are only shown to
+/// authorized callers.
+fn ring_keys(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let keyring = state.butterfly.keyring.read().unwrap();
+    Ok(Response::with((status::Ok, json::encode(&*keyring).unwrap())))
+}
+
+/// Accept gossip encrypted with a ring key from the key cache, without encrypting with it yet.
+fn ring_key_add(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let key = match ring_key_from(req) {
+        Ok(key) => key,
+        Err(response) => return Ok(response),
+    };
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let name_with_rev = key.name_with_rev();
+    state.butterfly.add_ring_key(key);
+    Ok(Response::with((status::Ok, name_with_rev)))
+}
+
+/// Start encrypting gossip with a ring key from the key cache. Gossip encrypted with the ring keys
+/// we used before is still accepted, until they are removed.
+fn ring_key_primary(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let key = match ring_key_from(req) {
+        Ok(key) => key,
+        Err(response) => return Ok(response),
+    };
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let name_with_rev = key.name_with_rev();
+    state.butterfly.add_ring_key(key);
+    match state.butterfly.set_primary_ring_key(&name_with_rev) {
+        Ok(()) => Ok(Response::with((status::Ok, name_with_rev))),
+        Err(err) => Ok(Response::with((status::InternalServerError, err.to_string()))),
+    }
+}
+
+/// Stop accepting gossip encrypted with a ring key, given by name and revision.
+fn ring_key_remove(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let name_with_rev = req.extensions.get::<Router>().unwrap().find("key").unwrap().to_string();
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    match state.butterfly.remove_ring_key(&name_with_rev) {
+        Ok(()) => Ok(Response::with((status::Ok, name_with_rev))),
+        Err(err @ ButterflyError::UnknownRingKey(_)) => {
+            Ok(Response::with((status::NotFound, err.to_string())))
+        }
+        Err(err @ ButterflyError::PrimaryRingKey(_)) => {
+            Ok(Response::with((status::Conflict, err.to_string())))
+        }
+        Err(err) => Ok(Response::with((status::InternalServerError, err.to_string()))),
+    }
+}
+
 fn census(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     let data = state.census_list.read().unwrap();
     Ok(Response::with((status::Ok, json::encode(&*data).unwrap())))
 }
 
+/// Streams events as server-sent events. A client which reconnects with a `Last-Event-ID` header
+/// is sent whatever it missed, as long as we still have it. Like the other debug output, the
+/// stream is public.
+fn events(req: &mut Request) -> IronResult<Response> {
+    let slot = match EventStreamSlot::take() {
+        Some(slot) => slot,
+        None => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let last_id = match req.headers.get_raw("Last-Event-ID") {
+        Some(values) if !values.is_empty() => {
+            String::from_utf8_lossy(&values[0]).trim().parse::<u64>().ok()
+        }
+        _ => None,
+    };
+    let last_id = match last_id {
+        Some(id) => id,
+        None => state.events.read().unwrap().last_id(),
+    };
+    let stream: Box<WriteBody + Send> = Box::new(EventStream {
+        events: state.events.clone(),
+        last_id: last_id,
+        _slot: slot,
+    });
+    let content_type: Mime = "text/event-stream".parse().unwrap();
+    let mut response = Response::with((status::Ok, content_type, stream));
+    response.headers.set(CacheControl(vec![CacheDirective::NoCache]));
+    Ok(response)
+}
+
+/// Answers with our metrics in the Prometheus text format. Like the other debug output, they are
+/// public, so that they can be scraped without a token.
+fn metrics(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let content_type: Mime = sup_metrics::CONTENT_TYPE.parse().unwrap();
+    Ok(Response::with((status::Ok, content_type, sup_metrics::render(&state))))
+}
+
+/// Answers with a service's last configuration; with the `sources` query parameter, with the
+/// layer each of its `cfg` keys came from instead.
 fn config(req: &mut Request) -> IronResult<Response> {
+    let sources = match req.url.query() {
+        Some(query) => form_urlencoded::parse(query.as_bytes()).any(|(k, _)| k == "sources"),
+        None => false,
+    };
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     let service_group =
         ServiceGroup::new(req.extensions.get::<Router>().unwrap().find("svc").unwrap(),
@@ -140,6 +311,10 @@ fn config(req: &mut Request) -> IronResult<Response> {
                           req.extensions.get::<Router>().unwrap().find("org").map(|v| v.into()));
     let services = state.services.read().unwrap();
     match services.iter().find(|s| s.service_group == service_group) {
+        Some(service) if sources => {
+            let json = json::encode(&service.cfg_sources).unwrap();
+            Ok(Response::with((status::Ok, json)))
+        }
         Some(service) => {
             match service.package.last_config() {
                 Ok(config) => Ok(Response::with((status::Ok, config))),
@@ -162,6 +337,11 @@ fn health(req: &mut Request) -> IronResult<Response> {
     let services = state.services.read().unwrap();
     match services.iter().find(|s| s.service_group == service_group) {
         Some(service) => {
+            // Answer with the last scheduled check, and only run the hook ourselves if the
+            // service hasn't been checked yet.
+            if let Some(record) = service.health_history.latest() {
+                return Ok(record.result.into());
+            }
             match service.health_check() {
                 Ok(result) => Ok(result.into()),
                 Err(err) => {
@@ -174,12 +354,280 @@ fn health(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn health_history(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = service_group_from(req);
+    let services = state.services.read().unwrap();
+    match services.iter().find(|s| s.service_group == service_group) {
+        Some(service) => {
+            Ok(Response::with((status::Ok, json::encode(&service.health_history).unwrap())))
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
+/// Answers with the end of a service's log; the `lines` query parameter says how much of it, up
+/// to `MAX_LOG_LINES`. Logs can hold anything a service prints, so they are only shown to
+/// authorized callers.
+fn logs(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let lines = match req.url.query() {
+        Some(query) => {
+            match form_urlencoded::parse(query.as_bytes()).find(|&(ref k, _)| k == "lines") {
+                Some((_, lines)) => {
+                    match lines.parse::<usize>() {
+                        Ok(lines) => lines,
+                        Err(err) => {
+                            return Ok(Response::with((status::BadRequest, err.to_string())))
+                        }
+                    }
+                }
+                None => DEFAULT_LOG_LINES,
+            }
+        }
+        None => DEFAULT_LOG_LINES,
+    };
+    let lines = cmp::min(lines, MAX_LOG_LINES);
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = service_group_from(req);
+    // Reading the log can take a while; the manager shouldn't wait on us to change the services.
+    let log = state.services
+        .read()
+        .unwrap()
+        .iter()
+        .find(|s| s.service_group == service_group)
+        .map(|service| (service.log_dir(), service.log_format()));
+    let (log_dir, log_format) = match log {
+        Some(log) => log,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    match logs::tail(log_dir, lines) {
+        Ok(tail) => {
+            let content_type: Mime = log_format.content_type().parse().unwrap();
+            let mut body = tail.join("\n");
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            Ok(Response::with((status::Ok, content_type, body)))
+        }
+        Err(err) => {
+            error!("Couldn't read the service log, err={:?}", err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn services(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     let data = state.services.read().unwrap();
     Ok(Response::with((status::Ok, json::encode(&*data).unwrap())))
 }
 
+fn load(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let mut body = String::new();
+    if let Err(err) = req.body.read_to_string(&mut body) {
+        return Ok(Response::with((status::BadRequest, err.to_string())));
+    }
+    let spec: ServiceSpec = match json::decode(&body) {
+        Ok(spec) => spec,
+        Err(err) => return Ok(Response::with((status::BadRequest, err.to_string()))),
+    };
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = spec.service_group();
+    if state.services.read().unwrap().iter().any(|s| s.service_group == service_group) {
+        return Ok(Response::with(status::Conflict));
+    }
+    state.service_requests.lock().unwrap().push(ServiceRequest::Load(spec));
+    Ok(Response::with(status::Accepted))
+}
+
+fn unload(req: &mut Request) -> IronResult<Response> {
+    queue_service_request(req, ServiceRequest::Unload)
+}
+
+fn start(req: &mut Request) -> IronResult<Response> {
+    queue_service_request(req, ServiceRequest::Start)
+}
+
+fn stop(req: &mut Request) -> IronResult<Response> {
+    queue_service_request(req, ServiceRequest::Stop)
+}
+
+/// Restart a service, and wait for the manager to tell us whether it really restarted. A service
+/// waiting for its binds is unavailable; one held back by its topology conflicts with the restart.
+fn restart(req: &mut Request) -> IronResult<Response> {
+    let (tx, rx) = mpsc::channel();
+    let response = try!(queue_service_request(req, |sg| ServiceRequest::Restart(sg, tx)));
+    if response.status != Some(status::Accepted) {
+        return Ok(response);
+    }
+    match rx.recv_timeout(Duration::from_millis(RESTART_REPLY_TIMEOUT_MS)) {
+        Ok(RestartOutcome::Restarted) => Ok(Response::with(status::Ok)),
+        Ok(RestartOutcome::Deferred) => Ok(Response::with(status::Conflict)),
+        Ok(RestartOutcome::WaitingForBinds) => Ok(Response::with(status::ServiceUnavailable)),
+        Ok(RestartOutcome::NotLoaded) => Ok(Response::with(status::NotFound)),
+        Ok(RestartOutcome::Failed) => Ok(Response::with(status::InternalServerError)),
+        // Still queued, or still restarting; the restart goes ahead without us.
+        Err(RecvTimeoutError::Timeout) => Ok(response),
+        Err(RecvTimeoutError::Disconnected) => Ok(Response::with(status::ServiceUnavailable)),
+    }
+}
+
+/// Queue a request about a loaded service for the manager. Stopping and starting a service runs
+/// its hooks, which can take a while, so we leave that to the manager rather than holding the
+/// services lock here.
+fn queue_service_request<F>(req: &mut Request, request: F) -> IronResult<Response>
+    where F: FnOnce(ServiceGroup) -> ServiceRequest
+{
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = service_group_from(req);
+    if !state.services.read().unwrap().iter().any(|s| s.service_group == service_group) {
+        return Ok(Response::with(status::NotFound));
+    }
+    state.service_requests.lock().unwrap().push(request(service_group));
+    Ok(Response::with(status::Accepted))
+}
+
+fn signal(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let service_group = service_group_from(req);
+    let signal = match Signal::from_str(req.extensions
+        .get::<Router>()
+        .unwrap()
+        .find("signal")
+        .unwrap()) {
+        Ok(signal) => signal,
+        Err(err) => return Ok(Response::with((status::BadRequest, err.to_string()))),
+    };
+    let services = state.services.read().unwrap();
+    match services.iter().find(|s| s.service_group == service_group) {
+        Some(service) => {
+            match service.send_signal(signal as u32) {
+                Ok(()) => Ok(Response::with(status::Ok)),
+                Err(err) => {
+                    error!("Failed to signal service, err={:?}", err);
+                    Ok(Response::with(status::InternalServerError))
+                }
+            }
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
+struct EventStream {
+    events: Arc<RwLock<EventLog>>,
+    last_id: u64,
+    _slot: EventStreamSlot,
+}
+
+/// One of the `MAX_EVENT_STREAMS` slots for serving an event stream. The slot is given back when
+/// it is dropped, along with the stream holding it.
+struct EventStreamSlot;
+
+impl EventStreamSlot {
+    /// Take a slot, unless they are all in use.
+    fn take() -> Option<EventStreamSlot> {
+        let mut current = EVENT_STREAMS.load(Ordering::SeqCst);
+        loop {
+            if current >= MAX_EVENT_STREAMS {
+                return None;
+            }
+            let previous = EVENT_STREAMS.compare_and_swap(current, current + 1, Ordering::SeqCst);
+            if previous == current {
+                return Some(EventStreamSlot);
+            }
+            current = previous;
+        }
+    }
+}
+
+impl Drop for EventStreamSlot {
+    fn drop(&mut self) {
+        EVENT_STREAMS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        // Say something straight away, so the client knows the stream is open.
+        try!(res.write_all(b": connected\n\n"));
+        try!(res.flush());
+        let mut idle_ms = 0;
+        loop {
+            let events = self.events.read().unwrap().since(self.last_id);
+            if events.is_empty() {
+                thread::sleep(Duration::from_millis(EVENT_POLL_INTERVAL_MS));
+                idle_ms += EVENT_POLL_INTERVAL_MS;
+                if idle_ms >= EVENT_KEEPALIVE_MS {
+                    try!(res.write_all(b": keepalive\n\n"));
+                    try!(res.flush());
+                    idle_ms = 0;
+                }
+                continue;
+            }
+            for event in events.iter() {
+                let data = json::encode(event).unwrap();
+                try!(write!(res, "id: {}\nevent: {}\ndata: {}\n\n", event.id, event.kind, data));
+                self.last_id = event.id;
+            }
+            try!(res.flush());
+            idle_ms = 0;
+        }
+    }
+}
+
+/// Control endpoints require the request to carry the gateway's auth token as a bearer token.
+/// When no token has been configured the endpoints are disabled altogether.
+fn authorize(req: &Request) -> result::Result<(), status::Status> {
+    match *gconfig().gateway_auth_token() {
+        Some(ref token) => {
+            match req.headers.get::<Authorization<Bearer>>() {
+                Some(&Authorization(Bearer { token: ref given }))
+                    if constant_time_eq(given.as_bytes(), token.as_bytes()) => Ok(()),
+                _ => Err(status::Unauthorized),
+            }
+        }
+        None => Err(status::Forbidden),
+    }
+}
+
+/// Compare two byte strings in time that depends only on their lengths, so that the time it
+/// takes to turn down a bearer token says nothing about how much of it was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// The ring key named in the request, read from the key cache. It may be given by name and
+/// revision, or by name alone for its latest revision.
+fn ring_key_from(req: &Request) -> result::Result<SymKey, Response> {
+    let key = req.extensions.get::<Router>().unwrap().find("key").unwrap();
+    let cache_key_path = default_cache_key_path(None);
+    SymKey::get_pair_for(key, &cache_key_path)
+        .or_else(|_| SymKey::get_latest_pair_for(key, &cache_key_path))
+        .map_err(|err| Response::with((status::NotFound, err.to_string())))
+}
+
+fn service_group_from(req: &Request) -> ServiceGroup {
+    let router = req.extensions.get::<Router>().unwrap();
+    ServiceGroup::new(router.find("svc").unwrap(),
+                      router.find("group").unwrap(),
+                      router.find("org").map(|v| v.into()))
+}
+
 impl Into<Response> for health_check::CheckResult {
     fn into(self) -> Response {
         let status: status::Status = self.into();
@@ -197,3 +645,17 @@ impl Into<status::Status> for health_check::CheckResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::constant_time_eq;
+
+    #[test]
+    fn constant_time_eq_matches_only_identical_strings() {
+        assert!(constant_time_eq(b"s3cr3t", b"s3cr3t"));
+        assert!(constant_time_eq(b"", b""));
+        assert!(!constant_time_eq(b"s3cr3t", b"s3cr3T"));
+        assert!(!constant_time_eq(b"s3cr3t", b"s3cr3"));
+        assert!(!constant_time_eq(b"s3cr3", b"s3cr3t"));
+    }
+}
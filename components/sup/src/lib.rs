@@ -54,6 +54,7 @@ extern crate regex;
 extern crate libc;
 extern crate url;
 extern crate iron;
+extern crate hyper;
 #[macro_use]
 extern crate router;
 extern crate time;
@@ -268,7 +269,10 @@ pub mod config;
 pub mod error;
 pub mod health_check;
 pub mod http_gateway;
+pub mod limits;
+pub mod logs;
 pub mod manager;
+pub mod metrics;
 pub mod output;
 pub mod package;
 pub mod supervisor;
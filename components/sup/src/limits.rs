@@ -0,0 +1,425 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resource limits for supervised processes.
+//!
+//! A service's limits come from the `pkg_svc_limits` of its plan, overridden by any given on the
+//! command line. The cpu, memory and pids limits are enforced by running the process in a cgroup
+//! (v2) of its own, under `/sys/fs/cgroup/hab`; the open file limit is set with `setrlimit` in
+//! the child, just before it execs the run hook. When a process dies, the event counters of its
+//! cgroup tell us whether it was killed for running out of memory, or ran into one of its limits.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "RL";
+
+/// Where the cgroup v2 hierarchy is mounted.
+pub const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+
+/// The cgroup every service's cgroup is created in.
+const CGROUP_PARENT: &'static str = "hab";
+
+/// The period the cpu limit is enforced over, in microseconds.
+const CPU_PERIOD: u64 = 100_000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct ResourceLimits {
+    /// CPU time, in thousandths of a CPU.
+    pub cpu: Option<u64>,
+    /// Memory, in bytes.
+    pub memory: Option<u64>,
+    /// The number of processes and threads.
+    pub pids: Option<u64>,
+    /// The number of open files, per process.
+    pub nofile: Option<u64>,
+}
+
+impl ResourceLimits {
+    /// Parse limits given as `name=value` pairs, as they are in a plan's `pkg_svc_limits`.
+    pub fn parse(limits: &[String]) -> Result<ResourceLimits> {
+        let mut parsed = ResourceLimits::default();
+        for limit in limits.iter() {
+            let mut parts = limit.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some(name), Some(value)) => try!(parsed.set(name, value)),
+                _ => return Err(sup_error!(Error::InvalidLimit(limit.clone()))),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Set a limit by name; one of `cpu`, `memory`, `pids` or `nofile`.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "cpu" => self.cpu = Some(try!(parse_cpu(value))),
+            "memory" => self.memory = Some(try!(parse_memory(value))),
+            "pids" => self.pids = Some(try!(parse_count(name, value))),
+            "nofile" => self.nofile = Some(try!(parse_count(name, value))),
+            _ => return Err(sup_error!(Error::InvalidLimit(format!("{}={}", name, value)))),
+        }
+        Ok(())
+    }
+
+    /// Returns these limits, with any set in `overrides` taking their place.
+    pub fn merge(&self, overrides: &ResourceLimits) -> ResourceLimits {
+        ResourceLimits {
+            cpu: overrides.cpu.or(self.cpu),
+            memory: overrides.memory.or(self.memory),
+            pids: overrides.pids.or(self.pids),
+            nofile: overrides.nofile.or(self.nofile),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == ResourceLimits::default()
+    }
+
+    /// Returns true if any of the limits has to be enforced with a cgroup.
+    pub fn needs_cgroup(&self) -> bool {
+        self.cpu.is_some() || self.memory.is_some() || self.pids.is_some()
+    }
+}
+
+impl fmt::Display for ResourceLimits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut limits = Vec::new();
+        if let Some(cpu) = self.cpu {
+            limits.push(format!("cpu={}", cpu as f64 / 1000.0));
+        }
+        if let Some(memory) = self.memory {
+            limits.push(format!("memory={}", memory));
+        }
+        if let Some(pids) = self.pids {
+            limits.push(format!("pids={}", pids));
+        }
+        if let Some(nofile) = self.nofile {
+            limits.push(format!("nofile={}", nofile));
+        }
+        write!(f, "{}", limits.join(" "))
+    }
+}
+
+/// The ways a process can run into its limits that we can tell about after it is gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breach {
+    OomKill,
+    Memory,
+    Pids,
+}
+
+impl fmt::Display for Breach {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let breach = match *self {
+            Breach::OomKill => "was killed for running out of memory",
+            Breach::Memory => "reached its memory limit",
+            Breach::Pids => "reached its pids limit",
+        };
+        write!(f, "{}", breach)
+    }
+}
+
+/// The cgroup a service's process runs in.
+#[derive(Debug)]
+pub struct Cgroup {
+    path: PathBuf,
+    oom_kills: u64,
+    memory_max: u64,
+    pids_max: u64,
+}
+
+impl Cgroup {
+    /// Create the cgroup for a service, or reuse the one left by a previous run, and set its
+    /// limits. Returns `None` if none of the limits need a cgroup, or if this system doesn't have
+    /// cgroups v2.
+    pub fn create(name: &str, limits: &ResourceLimits) -> Result<Option<Cgroup>> {
+        if !limits.needs_cgroup() {
+            return Ok(None);
+        }
+        let root = Path::new(CGROUP_ROOT);
+        if !root.join("cgroup.controllers").is_file() {
+            outputln!("cgroups v2 are not available at {}; the cpu, memory and pids limits of \
+                       {} will not be enforced",
+                      CGROUP_ROOT,
+                      name);
+            return Ok(None);
+        }
+        let parent = root.join(CGROUP_PARENT);
+        try!(fs::create_dir_all(&parent));
+        let mut controllers = Vec::new();
+        if limits.cpu.is_some() {
+            controllers.push("cpu");
+        }
+        if limits.memory.is_some() {
+            controllers.push("memory");
+        }
+        if limits.pids.is_some() {
+            controllers.push("pids");
+        }
+        for controller in controllers.iter() {
+            try!(write_file(root.join("cgroup.subtree_control"), &format!("+{}", controller)));
+            try!(write_file(parent.join("cgroup.subtree_control"), &format!("+{}", controller)));
+        }
+        let path = parent.join(name);
+        try!(fs::create_dir_all(&path));
+        // A cgroup outlives the processes in it, so anything not set this time is reset.
+        if limits.cpu.is_some() || path.join("cpu.max").is_file() {
+            try!(write_file(path.join("cpu.max"), &cpu_max(limits.cpu)));
+        }
+        if limits.memory.is_some() || path.join("memory.max").is_file() {
+            try!(write_file(path.join("memory.max"), &max_value(limits.memory)));
+        }
+        if limits.pids.is_some() || path.join("pids.max").is_file() {
+            try!(write_file(path.join("pids.max"), &max_value(limits.pids)));
+        }
+        let mut cgroup = Cgroup {
+            path: path,
+            oom_kills: 0,
+            memory_max: 0,
+            pids_max: 0,
+        };
+        cgroup.breach();
+        Ok(Some(cgroup))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Open the file a process writes to, to move itself into the cgroup.
+    pub fn procs(&self) -> Result<File> {
+        let file = try!(OpenOptions::new().write(true).open(self.path.join("cgroup.procs")));
+        Ok(file)
+    }
+
+    /// Returns how the processes in the cgroup ran into their limits since we last looked, if
+    /// they did. Being OOM killed trumps the rest.
+    pub fn breach(&mut self) -> Option<Breach> {
+        let memory_events = read_file(self.path.join("memory.events"));
+        let oom_kills = counter(&memory_events, "oom_kill").unwrap_or(self.oom_kills);
+        let memory_max = counter(&memory_events, "max").unwrap_or(self.memory_max);
+        let pids_events = read_file(self.path.join("pids.events"));
+        let pids_max = counter(&pids_events, "max").unwrap_or(self.pids_max);
+        let breach = if oom_kills > self.oom_kills {
+            Some(Breach::OomKill)
+        } else if pids_max > self.pids_max {
+            Some(Breach::Pids)
+        } else if memory_max > self.memory_max {
+            Some(Breach::Memory)
+        } else {
+            None
+        };
+        self.oom_kills = oom_kills;
+        self.memory_max = memory_max;
+        self.pids_max = pids_max;
+        breach
+    }
+
+    /// Remove the cgroup. This fails, harmlessly, if there are still processes in it.
+    pub fn remove(&self) {
+        match fs::remove_dir(&self.path) {
+            Ok(_) => debug!("Removed cgroup {}", self.path.display()),
+            Err(e) => debug!("Error removing cgroup {}: {}, continuing", self.path.display(), e),
+        }
+    }
+}
+
+/// Have the child process set its open file limit and move itself into its cgroup before it
+/// execs. `procs` has to stay open until the process is spawned.
+#[cfg(target_os = "linux")]
+pub fn prepare_command(cmd: &mut Command, limits: &ResourceLimits, procs: Option<&File>) {
+    use std::io;
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::process::CommandExt;
+    use libc;
+
+    let nofile = limits.nofile;
+    let procs_fd = procs.map(|f| f.as_raw_fd());
+    if nofile.is_none() && procs_fd.is_none() {
+        return;
+    }
+    // This runs between fork and exec, so it sticks to system calls.
+    cmd.before_exec(move || {
+        if let Some(fd) = procs_fd {
+            let pid = b"0";
+            if unsafe { libc::write(fd, pid.as_ptr() as *const libc::c_void, pid.len()) } < 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        if let Some(nofile) = nofile {
+            let limit = libc::rlimit {
+                rlim_cur: nofile as libc::rlim_t,
+                rlim_max: nofile as libc::rlim_t,
+            };
+            if unsafe { libc::setrlimit(libc::RLIMIT_NOFILE, &limit) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    });
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn prepare_command(_cmd: &mut Command, limits: &ResourceLimits, _procs: Option<&File>) {
+    if !limits.is_empty() {
+        debug!("Resource limits are only enforced on Linux; ignoring {}", limits);
+    }
+}
+
+/// Parse a number of CPUs, which may be fractional, into thousandths of a CPU.
+fn parse_cpu(value: &str) -> Result<u64> {
+    match value.parse::<f64>() {
+        Ok(cpus) if cpus > 0.0 && (cpus * 1000.0).round() >= 1.0 => {
+            Ok((cpus * 1000.0).round() as u64)
+        }
+        _ => Err(sup_error!(Error::InvalidLimit(format!("cpu={}", value)))),
+    }
+}
+
+/// Parse a number of bytes, optionally followed by `K`, `M` or `G`. A size too big to count in
+/// bytes is refused, rather than wrapping around to a much smaller limit.
+fn parse_memory(value: &str) -> Result<u64> {
+    let (number, multiplier) = match value.chars().last() {
+        Some('K') | Some('k') => (&value[..value.len() - 1], 1024),
+        Some('M') | Some('m') => (&value[..value.len() - 1], 1024 * 1024),
+        Some('G') | Some('g') => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+    match number.parse::<u64>().ok().and_then(|n| n.checked_mul(multiplier)) {
+        Some(n) if n > 0 => Ok(n),
+        _ => Err(sup_error!(Error::InvalidLimit(format!("memory={}", value)))),
+    }
+}
+
+fn parse_count(name: &str, value: &str) -> Result<u64> {
+    match value.parse::<u64>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(sup_error!(Error::InvalidLimit(format!("{}={}", name, value)))),
+    }
+}
+
+/// The contents of `cpu.max` for a cpu limit: the time the cgroup may run for in each period,
+/// and the period.
+fn cpu_max(cpu: Option<u64>) -> String {
+    match cpu {
+        Some(millis) => format!("{} {}", millis * CPU_PERIOD / 1000, CPU_PERIOD),
+        None => format!("max {}", CPU_PERIOD),
+    }
+}
+
+fn max_value(limit: Option<u64>) -> String {
+    match limit {
+        Some(limit) => limit.to_string(),
+        None => String::from("max"),
+    }
+}
+
+/// Find a counter in the contents of a cgroup events file, which has one `name value` per line.
+fn counter(contents: &str, name: &str) -> Option<u64> {
+    contents.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) if key == name => value.parse().ok(),
+                _ => None,
+            }
+        })
+        .next()
+}
+
+fn read_file<P: AsRef<Path>>(path: P) -> String {
+    let mut contents = String::new();
+    if let Ok(mut file) = File::open(path.as_ref()) {
+        let _ = file.read_to_string(&mut contents);
+    }
+    contents
+}
+
+fn write_file<P: AsRef<Path>>(path: P, contents: &str) -> Result<()> {
+    let mut file = try!(OpenOptions::new().write(true).open(path.as_ref()));
+    try!(file.write_all(contents.as_bytes()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{counter, cpu_max, ResourceLimits};
+
+    fn limits(pairs: &[&str]) -> ResourceLimits {
+        let pairs: Vec<String> = pairs.iter().map(|p| p.to_string()).collect();
+        ResourceLimits::parse(&pairs).unwrap()
+    }
+
+    #[test]
+    fn parse_limits() {
+        let limits = limits(&["cpu=0.5", "memory=512M", "pids=64", "nofile=4096"]);
+        assert_eq!(limits.cpu, Some(500));
+        assert_eq!(limits.memory, Some(512 * 1024 * 1024));
+        assert_eq!(limits.pids, Some(64));
+        assert_eq!(limits.nofile, Some(4096));
+        assert_eq!(limits.to_string(),
+                   "cpu=0.5 memory=536870912 pids=64 nofile=4096");
+    }
+
+    #[test]
+    fn parse_invalid_limits() {
+        for limit in ["cpu=0", "cpu=lots", "memory=12X", "memory=-1", "pids=0", "swap=1", "cpu"]
+            .iter() {
+            assert!(ResourceLimits::parse(&[limit.to_string()]).is_err(),
+                    "{} should not parse",
+                    limit);
+        }
+    }
+
+    #[test]
+    fn memory_limits_too_big_to_count_are_rejected() {
+        assert!(ResourceLimits::parse(&["memory=17179869184G".to_string()]).is_err());
+        assert!(ResourceLimits::parse(&["memory=18446744073709551615K".to_string()]).is_err());
+        assert_eq!(limits(&["memory=17179869183G"]).memory,
+                   Some(17179869183 * 1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let plan = limits(&["cpu=2", "memory=1G"]);
+        let cli = limits(&["memory=256M", "nofile=1024"]);
+        let merged = plan.merge(&cli);
+        assert_eq!(merged.cpu, Some(2000));
+        assert_eq!(merged.memory, Some(256 * 1024 * 1024));
+        assert_eq!(merged.nofile, Some(1024));
+        assert!(merged.needs_cgroup());
+        assert!(!limits(&["nofile=1024"]).needs_cgroup());
+        assert!(ResourceLimits::default().is_empty());
+    }
+
+    #[test]
+    fn cpu_max_is_a_share_of_the_period() {
+        assert_eq!(cpu_max(Some(500)), "50000 100000");
+        assert_eq!(cpu_max(Some(2000)), "200000 100000");
+        assert_eq!(cpu_max(None), "max 100000");
+    }
+
+    #[test]
+    fn counter_reads_events() {
+        let events = "low 0\nhigh 0\nmax 12\noom 1\noom_kill 1\n";
+        assert_eq!(counter(events, "max"), Some(12));
+        assert_eq!(counter(events, "oom_kill"), Some(1));
+        assert_eq!(counter(events, "missing"), None);
+    }
+}
@@ -0,0 +1,381 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Captures the output of supervised processes.
+//!
+//! Every line a service's process writes, to stdout or stderr, is appended to `service.log` in
+//! the `logs` directory of the service's `var` path. Once that file grows past its maximum size,
+//! or has been written to for longer than its maximum age, it is rotated: `service.log` becomes
+//! `service.log.1`, `service.log.1` becomes `service.log.2`, and so on, keeping
+//! `LOG_FILES_KEPT` rotated files. Lines are written as the process wrote them, or as JSON
+//! objects which also say when the line was written, to which stream, and by which service group
+//! and member.
+
+use std::cmp;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::SeekFrom;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::result;
+
+use rustc_serialize::{Encodable, Encoder};
+use rustc_serialize::json;
+use time::{self, Duration, SteadyTime};
+
+use error::Result;
+
+/// The directory, in a service's `var` path, its logs are written to.
+pub const LOG_DIR: &'static str = "logs";
+
+/// The name of the log file being written to.
+pub const LOG_FILENAME: &'static str = "service.log";
+
+/// How big a log file gets before it is rotated, in bytes.
+pub const DEFAULT_LOG_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
+/// How long a log file is written to before it is rotated, in seconds.
+pub const DEFAULT_LOG_MAX_AGE: u64 = 86_400;
+
+/// How many rotated log files we keep.
+const LOG_FILES_KEPT: usize = 5;
+
+/// How much of a log file we read at a time, in bytes, working back from its end.
+const TAIL_CHUNK_BYTES: u64 = 8 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    pub fn from_str(format: &str) -> Self {
+        match format {
+            "text" => LogFormat::Text,
+            "json" => LogFormat::Json,
+            f => panic!("Invalid log format {}", f),
+        }
+    }
+
+    /// The content type of a log written in this format.
+    pub fn content_type(&self) -> &'static str {
+        match *self {
+            LogFormat::Text => "text/plain",
+            LogFormat::Json => "application/x-ndjson",
+        }
+    }
+}
+
+impl Default for LogFormat {
+    fn default() -> LogFormat {
+        LogFormat::Text
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = match *self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        };
+        write!(f, "{}", format)
+    }
+}
+
+/// How a service's output is captured.
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    pub max_size: u64,
+    pub max_age: u64,
+}
+
+impl Default for LogConfig {
+    fn default() -> LogConfig {
+        LogConfig {
+            format: LogFormat::default(),
+            max_size: DEFAULT_LOG_MAX_SIZE,
+            max_age: DEFAULT_LOG_MAX_AGE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl fmt::Display for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stream = match *self {
+            Stream::Stdout => "stdout",
+            Stream::Stderr => "stderr",
+        };
+        write!(f, "{}", stream)
+    }
+}
+
+impl Encodable for Stream {
+    fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
+        s.emit_str(&self.to_string())
+    }
+}
+
+#[derive(RustcEncodable)]
+struct LogLine<'a> {
+    timestamp: String,
+    stream: Stream,
+    service_group: &'a str,
+    member_id: &'a str,
+    line: &'a str,
+}
+
+/// The log of one service.
+#[derive(Debug)]
+pub struct ServiceLog {
+    dir: PathBuf,
+    config: LogConfig,
+    service_group: String,
+    member_id: String,
+    file: Option<File>,
+    size: u64,
+    opened: SteadyTime,
+}
+
+impl ServiceLog {
+    pub fn new<P: AsRef<Path>>(dir: P,
+                               config: LogConfig,
+                               service_group: String,
+                               member_id: String)
+                               -> ServiceLog {
+        ServiceLog {
+            dir: dir.as_ref().to_path_buf(),
+            config: config,
+            service_group: service_group,
+            member_id: member_id,
+            file: None,
+            size: 0,
+            opened: SteadyTime::now(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn format(&self) -> LogFormat {
+        self.config.format
+    }
+
+    /// Append a line the process wrote, rotating the log first if it is due.
+    pub fn write_line(&mut self, stream: Stream, line: &str) -> Result<()> {
+        let entry = self.format_line(stream, line, &time::now_utc().rfc3339().to_string());
+        if self.file.is_some() && self.needs_rotating(entry.len() as u64, SteadyTime::now()) {
+            try!(self.rotate());
+        }
+        if self.file.is_none() {
+            try!(self.open());
+        }
+        if let Some(ref mut file) = self.file {
+            try!(file.write_all(entry.as_bytes()));
+        }
+        self.size += entry.len() as u64;
+        Ok(())
+    }
+
+    fn format_line(&self, stream: Stream, line: &str, timestamp: &str) -> String {
+        match self.config.format {
+            LogFormat::Text => format!("{}\n", line),
+            LogFormat::Json => {
+                let entry = LogLine {
+                    timestamp: timestamp.to_string(),
+                    stream: stream,
+                    service_group: &self.service_group,
+                    member_id: &self.member_id,
+                    line: line,
+                };
+                format!("{}\n", json::encode(&entry).unwrap())
+            }
+        }
+    }
+
+    fn needs_rotating(&self, len: u64, now: SteadyTime) -> bool {
+        let max_age = Duration::seconds(self.config.max_age as i64);
+        (self.size > 0 && self.size + len > self.config.max_size) ||
+        (self.config.max_age > 0 && now - self.opened >= max_age)
+    }
+
+    fn open(&mut self) -> Result<()> {
+        try!(fs::create_dir_all(&self.dir));
+        let file = try!(OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(LOG_FILENAME)));
+        self.size = try!(file.metadata()).len();
+        self.opened = SteadyTime::now();
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        self.file = None;
+        let oldest = rotated_path(&self.dir, LOG_FILES_KEPT);
+        if oldest.exists() {
+            try!(fs::remove_file(&oldest));
+        }
+        for n in (1..LOG_FILES_KEPT).rev() {
+            let from = rotated_path(&self.dir, n);
+            if from.exists() {
+                try!(fs::rename(&from, rotated_path(&self.dir, n + 1)));
+            }
+        }
+        let current = self.dir.join(LOG_FILENAME);
+        if current.exists() {
+            try!(fs::rename(&current, rotated_path(&self.dir, 1)));
+        }
+        self.open()
+    }
+}
+
+/// Returns the last `lines` lines of a service's log, reaching back into the most recently
+/// rotated file if the current one doesn't have enough.
+pub fn tail<P: AsRef<Path>>(dir: P, lines: usize) -> Result<Vec<String>> {
+    let mut tail = try!(tail_file(&dir.as_ref().join(LOG_FILENAME), lines));
+    if tail.len() < lines {
+        let mut older = try!(tail_file(&rotated_path(dir.as_ref(), 1), lines - tail.len()));
+        older.append(&mut tail);
+        tail = older;
+    }
+    Ok(tail)
+}
+
+/// Returns the last `lines` lines of a file, or none if it can't be opened. The file is read
+/// backwards from its end, until we have read enough lines or reach its start, so a big log costs
+/// no more to tail than the lines asked for.
+fn tail_file(path: &Path, lines: usize) -> Result<Vec<String>> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let mut pos = try!(file.seek(SeekFrom::End(0)));
+    let mut chunks = Vec::new();
+    let mut newlines = 0;
+    // The first of the lines we read may be cut short, so we need one newline more than the
+    // lines asked for, unless we read the whole file.
+    while pos > 0 && newlines <= lines {
+        let size = cmp::min(pos, TAIL_CHUNK_BYTES);
+        pos -= size;
+        try!(file.seek(SeekFrom::Start(pos)));
+        let mut chunk = vec![0u8; size as usize];
+        try!(file.read_exact(&mut chunk));
+        newlines += chunk.iter().filter(|&&b| b == b'\n').count();
+        chunks.push(chunk);
+    }
+    let mut bytes = Vec::new();
+    for chunk in chunks.iter().rev() {
+        bytes.extend_from_slice(chunk);
+    }
+    let text = String::from_utf8_lossy(&bytes);
+    let all: Vec<&str> = text.lines().collect();
+    let skip = all.len().saturating_sub(lines);
+    Ok(all[skip..].iter().map(|line| line.to_string()).collect())
+}
+
+fn rotated_path(dir: &Path, n: usize) -> PathBuf {
+    dir.join(format!("{}.{}", LOG_FILENAME, n))
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_serialize::json::Json;
+    use tempdir::TempDir;
+    use time::{Duration, SteadyTime};
+
+    use super::{tail, LogConfig, LogFormat, ServiceLog, Stream, LOG_FILENAME};
+
+    fn log(dir: &TempDir, config: LogConfig) -> ServiceLog {
+        ServiceLog::new(dir.path().join("logs"),
+                        config,
+                        String::from("redis.default"),
+                        String::from("member"))
+    }
+
+    #[test]
+    fn lines_are_appended() {
+        let tmp = TempDir::new("logs").unwrap();
+        let mut log = log(&tmp, LogConfig::default());
+        log.write_line(Stream::Stdout, "one").unwrap();
+        log.write_line(Stream::Stderr, "two").unwrap();
+        assert_eq!(tail(log.dir(), 10).unwrap(), vec!["one", "two"]);
+        assert_eq!(tail(log.dir(), 1).unwrap(), vec!["two"]);
+    }
+
+    #[test]
+    fn json_lines_say_where_they_came_from() {
+        let tmp = TempDir::new("logs").unwrap();
+        let mut config = LogConfig::default();
+        config.format = LogFormat::Json;
+        let log = log(&tmp, config);
+        let entry = log.format_line(Stream::Stderr, "oh \"no\"", "2016-12-01T00:00:00Z");
+        let json = Json::from_str(entry.trim()).unwrap();
+        assert_eq!(json.find("stream").unwrap().as_string(), Some("stderr"));
+        assert_eq!(json.find("service_group").unwrap().as_string(),
+                   Some("redis.default"));
+        assert_eq!(json.find("member_id").unwrap().as_string(), Some("member"));
+        assert_eq!(json.find("line").unwrap().as_string(), Some("oh \"no\""));
+    }
+
+    #[test]
+    fn big_logs_are_rotated() {
+        let tmp = TempDir::new("logs").unwrap();
+        let mut config = LogConfig::default();
+        config.max_size = 10;
+        let mut log = log(&tmp, config);
+        for line in ["first", "second", "third", "fourth"].iter() {
+            log.write_line(Stream::Stdout, line).unwrap();
+        }
+        let dir = log.dir().to_path_buf();
+        assert!(dir.join(format!("{}.1", LOG_FILENAME)).exists());
+        assert!(dir.join(format!("{}.3", LOG_FILENAME)).exists());
+        assert_eq!(tail(&dir, 10).unwrap(), vec!["third", "fourth"]);
+    }
+
+    #[test]
+    fn tails_of_big_logs_are_read_from_the_end() {
+        let tmp = TempDir::new("logs").unwrap();
+        let mut log = log(&tmp, LogConfig::default());
+        for n in 0..5000 {
+            log.write_line(Stream::Stdout, &format!("line {}", n)).unwrap();
+        }
+        assert_eq!(tail(log.dir(), 2).unwrap(), vec!["line 4998", "line 4999"]);
+        assert_eq!(tail(log.dir(), 0).unwrap(), Vec::<String>::new());
+        let all = tail(log.dir(), 10_000).unwrap();
+        assert_eq!(all.len(), 5000);
+        assert_eq!(all[0], "line 0");
+    }
+
+    #[test]
+    fn old_logs_need_rotating() {
+        let tmp = TempDir::new("logs").unwrap();
+        let mut config = LogConfig::default();
+        config.max_age = 60;
+        let mut log = log(&tmp, config);
+        log.write_line(Stream::Stdout, "one").unwrap();
+        let now = SteadyTime::now();
+        assert!(!log.needs_rotating(4, now));
+        assert!(log.needs_rotating(4, now + Duration::seconds(61)));
+    }
+}
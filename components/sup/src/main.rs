@@ -38,7 +38,13 @@ use hcore::crypto::init as crypto_init;
 use hcore::package::{PackageArchive, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use sup::config::{gcache, gconfig, Command, Config, GossipListenAddr, UpdateStrategy, Topology};
+use sup::config::{gcache, gconfig, Command, Config, GossipListenAddr, RestartPolicy,
+                  UpdateStrategy, Topology};
+use sup::manager::service::restart::{DEFAULT_MAX_RESTARTS, DEFAULT_RESTART_WINDOW};
+use sup::limits::ResourceLimits;
+use sup::logs::{LogFormat, DEFAULT_LOG_MAX_AGE, DEFAULT_LOG_MAX_SIZE};
+use sup::manager::signals::Signal;
+use sup::supervisor::DEFAULT_STOP_TIMEOUT;
 use sup::error::{Error, Result, SupError};
 use sup::command::*;
 use sup::http_gateway;
@@ -51,9 +57,13 @@ const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"))
 
 /// CLI defaults
 static DEFAULT_GROUP: &'static str = "default";
+static DEFAULT_SUP_NAME: &'static str = "default";
+const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
+const DEFAULT_REAP_AFTER: u64 = 3600;
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static GATEWAY_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
 
 /// Creates a [Config](config/struct.Config.html) from global args
 /// and subcommand args.
@@ -67,6 +77,37 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(ref strategy) = sub_args.value_of("strategy") {
         config.set_update_strategy(UpdateStrategy::from_str(strategy));
     }
+    if let Some(ref policy) = sub_args.value_of("restart-policy") {
+        config.set_restart_policy(RestartPolicy::from_str(policy));
+    }
+    config.set_max_restarts(value_t!(sub_args, "max-restarts", u32)
+        .unwrap_or(DEFAULT_MAX_RESTARTS));
+    config.set_restart_window(value_t!(sub_args, "restart-window", u64)
+        .unwrap_or(DEFAULT_RESTART_WINDOW));
+    match sub_args.value_of("stop-signal") {
+        Some(signal) => config.set_stop_signal(try!(Signal::from_str(signal)) as u32),
+        None => config.set_stop_signal(Signal::SIGTERM as u32),
+    };
+    config.set_stop_timeout(value_t!(sub_args, "stop-timeout", u64)
+        .unwrap_or(DEFAULT_STOP_TIMEOUT));
+    let mut limits = ResourceLimits::default();
+    for &(arg, name) in [("cpu-limit", "cpu"),
+                         ("memory-limit", "memory"),
+                         ("pids-limit", "pids"),
+                         ("nofile-limit", "nofile")]
+        .iter() {
+        if let Some(value) = sub_args.value_of(arg) {
+            try!(limits.set(name, value));
+        }
+    }
+    config.set_limits(limits);
+    if let Some(ref format) = sub_args.value_of("log-format") {
+        config.set_log_format(LogFormat::from_str(format));
+    }
+    config.set_log_max_size(value_t!(sub_args, "log-max-size", u64)
+        .unwrap_or(DEFAULT_LOG_MAX_SIZE));
+    config.set_log_max_age(value_t!(sub_args, "log-max-age", u64)
+        .unwrap_or(DEFAULT_LOG_MAX_AGE));
     if let Some(ref archive) = sub_args.value_of("archive") {
         config.set_archive(archive.to_string());
     }
@@ -98,6 +139,15 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(outfile) = sub_args.value_of("outfile") {
         config.set_outfile(outfile.to_string());
     }
+    if let Some(user_toml) = sub_args.value_of("user-toml") {
+        config.set_user_toml(user_toml.to_string());
+    }
+    if let Some(census) = sub_args.value_of("census") {
+        config.set_census(census.to_string());
+    }
+    if let Some(render_path) = sub_args.value_of("output") {
+        config.set_render_path(render_path.to_string());
+    }
     if let Some(topology) = sub_args.value_of("topology") {
         match topology.as_ref() {
             "standalone" => {
@@ -109,6 +159,12 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
             "initializer" => {
                 config.set_topology(Topology::Initializer);
             }
+            "ordered" => {
+                config.set_topology(Topology::Ordered);
+            }
+            "lease" => {
+                config.set_topology(Topology::Lease);
+            }
             t => return Err(sup_error!(Error::UnknownTopology(String::from(t)))),
         }
     }
@@ -120,6 +176,10 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     let url = sub_args.value_of("url").unwrap_or(&env_or_default);
     config.set_url(url.to_string());
     config.set_group(sub_args.value_of("group").unwrap_or(DEFAULT_GROUP).to_string());
+    config.set_name(sub_args.value_of("name").unwrap_or(DEFAULT_SUP_NAME).to_string());
+    if let Some(remote_sup) = sub_args.value_of("remote-sup") {
+        config.set_remote_sup(remote_sup.to_string());
+    }
     let bindings = match sub_args.values_of("bind") {
         Some(bind) => bind.map(|s| s.to_string()).collect(),
         None => vec![],
@@ -160,6 +220,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
         config.set_file_path(fp.to_string());
     }
     config.set_version_number(value_t!(sub_args, "version-number", u64).unwrap_or(0));
+    config.set_health_check_interval(value_t!(sub_args, "health-check-interval", u64)
+        .unwrap_or(DEFAULT_HEALTH_CHECK_INTERVAL));
+    config.set_reap_after(value_t!(sub_args, "reap-after", u64).unwrap_or(DEFAULT_REAP_AFTER));
     let ring = match sub_args.value_of("ring") {
         Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
         None => {
@@ -184,6 +247,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(ring) = ring {
         config.set_ring(ring.name_with_rev());
     }
+    if let Ok(token) = henv::var(GATEWAY_AUTH_TOKEN_ENVVAR) {
+        config.set_gateway_auth_token(token);
+    }
     if sub_args.is_present("verbose") {
         sup::output::set_verbose(true);
     }
@@ -241,6 +307,126 @@ fn main() {
             .help("The update strategy; [default: none].")
     };
 
+    let arg_restart_policy = || {
+        Arg::with_name("restart-policy")
+            .long("restart-policy")
+            .takes_value(true)
+            .possible_values(&["always", "on-failure", "never"])
+            .help("What to do when the service's process exits; [default: always].")
+    };
+
+    let arg_max_restarts = || {
+        Arg::with_name("max-restarts")
+            .long("max-restarts")
+            .value_name("count")
+            .help("How many restarts are allowed within the restart window before the service \
+                   is marked failed [default: 5]")
+    };
+
+    let arg_restart_window = || {
+        Arg::with_name("restart-window")
+            .long("restart-window")
+            .value_name("seconds")
+            .help("The window in which restarts are counted [default: 300]")
+    };
+
+    let arg_stop_signal = || {
+        Arg::with_name("stop-signal")
+            .long("stop-signal")
+            .value_name("signal")
+            .help("The signal sent to the service's process to stop it [default: TERM]")
+    };
+
+    let arg_stop_timeout = || {
+        Arg::with_name("stop-timeout")
+            .long("stop-timeout")
+            .value_name("seconds")
+            .help("How long the process has to exit after the stop signal before it is killed \
+                   [default: 8]")
+    };
+
+    let arg_cpu_limit = || {
+        Arg::with_name("cpu-limit")
+            .long("cpu-limit")
+            .value_name("cpus")
+            .help("The CPU time the service may use, in CPUs, which may be fractional")
+    };
+
+    let arg_memory_limit = || {
+        Arg::with_name("memory-limit")
+            .long("memory-limit")
+            .value_name("bytes")
+            .help("The memory the service may use, in bytes, optionally followed by K, M or G")
+    };
+
+    let arg_pids_limit = || {
+        Arg::with_name("pids-limit")
+            .long("pids-limit")
+            .value_name("count")
+            .help("The number of processes and threads the service may have")
+    };
+
+    let arg_nofile_limit = || {
+        Arg::with_name("nofile-limit")
+            .long("nofile-limit")
+            .value_name("count")
+            .help("The number of files each of the service's processes may have open")
+    };
+
+    let arg_log_format = || {
+        Arg::with_name("log-format")
+            .long("log-format")
+            .takes_value(true)
+            .possible_values(&["text", "json"])
+            .help("The format the service's log is written in; [default: text].")
+    };
+
+    let arg_log_max_size = || {
+        Arg::with_name("log-max-size")
+            .long("log-max-size")
+            .value_name("bytes")
+            .help("How big the service's log gets before it is rotated [default: 10485760]")
+    };
+
+    let arg_log_max_age = || {
+        Arg::with_name("log-max-age")
+            .long("log-max-age")
+            .value_name("seconds")
+            .help("How long the service's log is written to before it is rotated \
+                   [default: 86400]")
+    };
+
+    let arg_topology = || {
+        Arg::with_name("topology")
+            .short("t")
+            .long("topology")
+            .value_name("topology")
+            .help("Service topology")
+    };
+
+    let arg_bind = || {
+        Arg::with_name("bind")
+            .long("bind")
+            .value_name("bind")
+            .multiple(true)
+            .help("One or more service groups to bind to a configuration")
+    };
+
+    let arg_remote_sup = || {
+        Arg::with_name("remote-sup")
+            .long("remote-sup")
+            .value_name("ip:port")
+            .help("The HTTP API address of the Supervisor to talk to [default: 127.0.0.1:9631]")
+    };
+
+    let arg_ring_key = || {
+        Arg::with_name("key")
+            .index(1)
+            .required(true)
+            .help("A ring key name, with an optional revision (ex: myring or \
+                   myring-20160504220722)")
+    };
+
     let sub_start = SubCommand::with_name("start")
         .about("Start a Habitat-supervised service from a package or artifact")
         .aliases(&["st", "sta", "star"])
@@ -253,21 +439,25 @@ fn main() {
         .arg(arg_group())
         .arg(arg_org())
         .arg(arg_strategy())
+        .arg(arg_restart_policy())
+        .arg(arg_max_restarts())
+        .arg(arg_restart_window())
+        .arg(arg_stop_signal())
+        .arg(arg_stop_timeout())
+        .arg(arg_cpu_limit())
+        .arg(arg_memory_limit())
+        .arg(arg_pids_limit())
+        .arg(arg_nofile_limit())
+        .arg(arg_log_format())
+        .arg(arg_log_max_size())
+        .arg(arg_log_max_age())
         .arg(Arg::with_name("config-from")
             .short("C")
             .long("config-from")
             .value_name("config-from")
             .help("Use package config from this path, rather than the package itself"))
-        .arg(Arg::with_name("topology")
-            .short("t")
-            .long("topology")
-            .value_name("topology")
-            .help("Service topology"))
-        .arg(Arg::with_name("bind")
-            .long("bind")
-            .value_name("bind")
-            .multiple(true)
-            .help("One or more service groups to bind to a configuration"))
+        .arg(arg_topology())
+        .arg(arg_bind())
         .arg(Arg::with_name("ring")
             .short("r")
             .long("ring")
@@ -294,18 +484,117 @@ fn main() {
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
-            .help("If this service is a permanent peer"));
+            .help("If this service is a permanent peer"))
+        .arg(Arg::with_name("name")
+            .long("name")
+            .value_name("name")
+            .help("The name of this Supervisor; its loaded services are persisted under \
+                   /hab/sup/<name> [default: default]"))
+        .arg(Arg::with_name("health-check-interval")
+            .long("health-check-interval")
+            .value_name("seconds")
+            .help("How often to run each service's health check [default: 30]"))
+        .arg(Arg::with_name("reap-after")
+            .long("reap-after")
+            .value_name("seconds")
+            .help("How long a confirmed or departed member is kept before it, and its rumors, \
+                   are removed; 0 never removes them [default: 3600]"));
+    let sub_load = SubCommand::with_name("load")
+        .about("Load a service into a running Supervisor")
+        .arg(Arg::with_name("pkg_ident_or_artifact")
+            .index(1)
+            .required(true)
+            .help("A Habitat package identifier (ex: acme/redis)"))
+        .arg(arg_group())
+        .arg(arg_org())
+        .arg(arg_strategy())
+        .arg(arg_restart_policy())
+        .arg(arg_max_restarts())
+        .arg(arg_restart_window())
+        .arg(arg_stop_signal())
+        .arg(arg_stop_timeout())
+        .arg(arg_cpu_limit())
+        .arg(arg_memory_limit())
+        .arg(arg_pids_limit())
+        .arg(arg_nofile_limit())
+        .arg(arg_log_format())
+        .arg(arg_log_max_size())
+        .arg(arg_log_max_age())
+        .arg(arg_topology())
+        .arg(arg_bind())
+        .arg(arg_remote_sup());
+    let sub_unload = SubCommand::with_name("unload")
+        .about("Stop a service and unload it from a running Supervisor")
+        .arg(Arg::with_name("pkg_ident_or_artifact")
+            .index(1)
+            .required(true)
+            .help("A Habitat package identifier (ex: acme/redis)"))
+        .arg(arg_group())
+        .arg(arg_org())
+        .arg(arg_remote_sup());
+    let sub_status = SubCommand::with_name("status")
+        .about("List the services loaded in a running Supervisor")
+        .arg(arg_remote_sup());
+    let sub_ring_key = SubCommand::with_name("ring-key")
+        .about("Manage the ring keys of a running Supervisor, to rotate them without downtime")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(SubCommand::with_name("list")
+            .about("List the ring keys a running Supervisor accepts, the one it encrypts with \
+                    first")
+            .arg(arg_remote_sup()))
+        .subcommand(SubCommand::with_name("add")
+            .about("Accept gossip encrypted with a ring key from the key cache")
+            .arg(arg_ring_key())
+            .arg(arg_remote_sup()))
+        .subcommand(SubCommand::with_name("use")
+            .about("Encrypt gossip with a ring key from the key cache")
+            .arg(arg_ring_key())
+            .arg(arg_remote_sup()))
+        .subcommand(SubCommand::with_name("remove")
+            .about("Stop accepting gossip encrypted with a ring key")
+            .arg(arg_ring_key())
+            .arg(arg_remote_sup()));
     let sub_bash = SubCommand::with_name("bash")
         .about("Start an interactive shell (bash)")
         .aliases(&["b", "ba", "bas"]);
     let sub_sh = SubCommand::with_name("sh").about("Start an interactive shell (sh)");
+    let sub_config_render = SubCommand::with_name("render")
+        .about("Render a package's configuration files and hooks from a census snapshot, \
+                without starting anything")
+        .arg(Arg::with_name("pkg_ident_or_artifact")
+            .index(1)
+            .required(true)
+            .help("A Habitat package identifier (ex: acme/redis)"))
+        .arg(Arg::with_name("census")
+            .long("census")
+            .value_name("census")
+            .required(true)
+            .help("A census snapshot, as served by the /census endpoint of the HTTP API"))
+        .arg(Arg::with_name("user-toml")
+            .long("user-toml")
+            .value_name("user-toml")
+            .help("Render with this user.toml"))
+        .arg(Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .value_name("output")
+            .help("Write the rendered files under this directory, rather than printing them"))
+        .arg(Arg::with_name("config-from")
+            .short("C")
+            .long("config-from")
+            .value_name("config-from")
+            .help("Use package config from this path, rather than the package itself"))
+        .arg(arg_group())
+        .arg(arg_bind());
     let sub_config = SubCommand::with_name("config")
         .about("Print the default.toml for a given package")
         .aliases(&["c", "co", "con", "conf", "confi"])
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(Arg::with_name("pkg_ident_or_artifact")
             .index(1)
             .required(true)
-            .help("Name of package"));
+            .help("Name of package"))
+        .subcommand(sub_config_render);
     let args = App::new(sup::PROGRAM_NAME.as_str())
         .version(VERSION)
         .setting(AppSettings::VersionlessSubcommands)
@@ -319,6 +608,10 @@ fn main() {
             .global(true)
             .help("Turn ANSI color off :("))
         .subcommand(sub_start)
+        .subcommand(sub_load)
+        .subcommand(sub_unload)
+        .subcommand(sub_status)
+        .subcommand(sub_ring_key)
         .subcommand(sub_bash)
         .subcommand(sub_sh)
         .subcommand(sub_config);
@@ -327,6 +620,16 @@ fn main() {
     debug!("clap matches {:?}", matches);
     let subcommand_name = matches.subcommand_name().unwrap();
     let subcommand_matches = matches.subcommand_matches(subcommand_name).unwrap();
+    // Nested subcommands, like `config render`, carry their own arguments, and are named
+    // `parent-child`.
+    let nested_name;
+    let (subcommand_name, subcommand_matches) = match subcommand_matches.subcommand() {
+        (child_name, Some(child_matches)) => {
+            nested_name = format!("{}-{}", subcommand_name, child_name);
+            (nested_name.as_str(), child_matches)
+        }
+        _ => (subcommand_name, subcommand_matches),
+    };
     debug!("subcommand name {:?}", &subcommand_name);
     debug!("Subcommand matches {:?}", &subcommand_matches);
 
@@ -339,7 +642,15 @@ fn main() {
         Command::ShellBash => shell_bash(),
         Command::ShellSh => shell_sh(),
         Command::Config => configure(),
+        Command::ConfigRender => configure_render(),
         Command::Start => start(),
+        Command::Load => load::service(),
+        Command::Unload => unload::service(),
+        Command::Status => status::display(),
+        Command::RingKeyList => ring_key::list(),
+        Command::RingKeyAdd => ring_key::add(),
+        Command::RingKeyUse => ring_key::use_key(),
+        Command::RingKeyRemove => ring_key::remove(),
     };
 
     match result {
@@ -374,6 +685,13 @@ fn configure() -> Result<()> {
     Ok(())
 }
 
+/// Render the configuration files and hooks for a service
+#[allow(dead_code)]
+fn configure_render() -> Result<()> {
+    try!(configure::render());
+    Ok(())
+}
+
 /// Start a service
 #[allow(dead_code)]
 fn start() -> Result<()> {
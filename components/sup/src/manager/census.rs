@@ -12,18 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::ops::{Deref, DerefMut};
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use time::{Duration, SteadyTime};
+use toml;
+
 use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 use butterfly::rumor::service::Service as ServiceRumor;
 use butterfly::rumor::election::{Election as ElectionRumor, Election_Status};
 use butterfly::member::{Member, Health};
 
+use health_check::CheckResult;
+
 static LOGKEY: &'static str = "CE";
 
+/// How long a leader in the lease topology keeps its lease once its election stops confirming it,
+/// in seconds.
+pub const LEADER_LEASE_TTL_SECS: i64 = 30;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct CensusUpdate {
     service_counter: usize,
@@ -45,6 +55,17 @@ impl CensusUpdate {
             membership_counter: membership_counter,
         }
     }
+
+    /// Returns true if the member list changed between the two updates.
+    pub fn membership_changed(&self, other: &CensusUpdate) -> bool {
+        self.membership_counter != other.membership_counter
+    }
+
+    /// Returns true if any election, or update election, changed between the two updates.
+    pub fn elections_changed(&self, other: &CensusUpdate) -> bool {
+        self.election_counter != other.election_counter ||
+        self.election_update_counter != other.election_update_counter
+    }
 }
 
 #[derive(Debug, Clone, RustcDecodable, RustcEncodable, PartialEq, Eq, Default)]
@@ -70,10 +91,13 @@ pub struct CensusEntry {
     pub update_election_is_no_quorum: Option<bool>,
     pub update_election_is_finished: Option<bool>,
     pub initialized: Option<bool>,
+    pub suitability: Option<u64>,
+    pub cfg: Option<String>,
     pub alive: Option<bool>,
     pub suspect: Option<bool>,
     pub confirmed: Option<bool>,
     pub persistent: Option<bool>,
+    pub health_check: Option<CheckResult>,
 }
 
 impl CensusEntry {
@@ -280,6 +304,36 @@ impl CensusEntry {
         self.initialized.unwrap_or(false)
     }
 
+    pub fn set_suitability(&mut self, value: u64) {
+        self.suitability = Some(value);
+    }
+
+    pub fn get_suitability(&self) -> u64 {
+        self.suitability.unwrap_or(0)
+    }
+
+    /// Set the configuration the member exports to the services bound to it, as TOML.
+    pub fn set_cfg(&mut self, value: String) {
+        self.cfg = Some(value);
+    }
+
+    /// Returns the configuration the member exports, or `None` if it exports nothing or what it
+    /// gossiped isn't a TOML table.
+    pub fn get_cfg(&self) -> Option<toml::Table> {
+        self.cfg.as_ref().and_then(|cfg| toml::Parser::new(cfg).parse())
+    }
+
+    /// Returns true if the member exports every one of the given keys.
+    pub fn exports_all(&self, keys: &[String]) -> bool {
+        if keys.is_empty() {
+            return true;
+        }
+        match self.get_cfg() {
+            Some(cfg) => keys.iter().all(|k| cfg.contains_key(k)),
+            None => false,
+        }
+    }
+
     pub fn set_alive(&mut self, value: bool) {
         self.alive = Some(value);
     }
@@ -312,6 +366,14 @@ impl CensusEntry {
         self.persistent.unwrap_or(false)
     }
 
+    pub fn set_health_check(&mut self, value: CheckResult) {
+        self.health_check = Some(value);
+    }
+
+    pub fn get_health_check(&self) -> CheckResult {
+        self.health_check.unwrap_or(CheckResult::Unknown)
+    }
+
     pub fn populate_from_service(&mut self, service_rumor: &ServiceRumor) {
         self.set_member_id(String::from(service_rumor.get_member_id()));
         let sg = match ServiceGroup::from_str(service_rumor.get_service_group()) {
@@ -334,6 +396,15 @@ impl CensusEntry {
         self.set_exposes(service_rumor.get_exposes().iter().map(|p| format!("{}", p)).collect());
         self.set_package_ident(PackageIdent::from_str(service_rumor.get_package_ident())
             .expect("Received invalid package ident in gossip data. This shouldn't be possible!"));
+        self.set_health_check(service_rumor.get_health().into());
+        self.set_initialized(service_rumor.get_initialized());
+        self.set_suitability(service_rumor.get_suitability());
+        if service_rumor.has_cfg() {
+            match String::from_utf8(service_rumor.get_cfg().to_vec()) {
+                Ok(cfg) => self.set_cfg(cfg),
+                Err(e) => debug!("Exported configuration from gossip isn't UTF-8: {}", e),
+            }
+        }
     }
 
     pub fn populate_from_member(&mut self, member: &Member) {
@@ -359,6 +430,11 @@ impl CensusEntry {
                 self.set_suspect(false);
                 self.set_confirmed(true);
             }
+            Health::Departed => {
+                self.set_alive(false);
+                self.set_suspect(false);
+                self.set_confirmed(false);
+            }
         }
     }
 
@@ -425,7 +501,7 @@ impl CensusEntry {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, RustcDecodable, RustcEncodable)]
 pub struct Census {
     // JW TODO: This needs to become an Ordered HashMap keyed on member_id. This will reduce our
     // allocations when ordering the population to determine who should update next in a rolling
@@ -433,6 +509,7 @@ pub struct Census {
     // members_ordered() functions.
     population: HashMap<String, CensusEntry>,
     member_id: String,
+    lease_holder: Option<String>,
 }
 
 impl Deref for Census {
@@ -454,6 +531,7 @@ impl Census {
         Census {
             population: HashMap::new(),
             member_id: member_id,
+            lease_holder: None,
         }
     }
 
@@ -473,6 +551,16 @@ impl Census {
         members
     }
 
+    /// Return all alive members whose health check isn't critical, ordered by member_id.
+    pub fn healthy_members(&self) -> Vec<&CensusEntry> {
+        let mut members: Vec<&CensusEntry> = self.population
+            .values()
+            .filter(|ce| ce.get_alive() && ce.get_health_check() != CheckResult::Critical)
+            .collect();
+        members.sort_by(|a, b| a.member_id.cmp(&b.member_id));
+        members
+    }
+
     /// Return all members.
     pub fn members(&self) -> Vec<&CensusEntry> {
         self.population.values().map(|ce| ce).collect()
@@ -485,10 +573,72 @@ impl Census {
         members
     }
 
+    /// Return all alive members, and us, in the order they start in the ordered topology: the
+    /// most suitable first, ties broken by member_id.
+    pub fn members_in_start_order(&self) -> Vec<&CensusEntry> {
+        let mut members: Vec<&CensusEntry> = self.population
+            .values()
+            .filter(|ce| ce.get_alive() || ce.member_id.as_ref() == Some(&self.member_id))
+            .collect();
+        members.sort_by(|a, b| match b.get_suitability().cmp(&a.get_suitability()) {
+            Ordering::Equal => a.member_id.cmp(&b.member_id),
+            ordering => ordering,
+        });
+        members
+    }
+
+    /// Return the first member ahead of us in start order which hasn't started its service yet,
+    /// or None if it is our turn.
+    pub fn waiting_on(&self) -> Option<&CensusEntry> {
+        for ce in self.members_in_start_order() {
+            if ce.member_id.as_ref() == Some(&self.member_id) {
+                return None;
+            }
+            if !ce.get_initialized() {
+                return Some(ce);
+            }
+        }
+        None
+    }
+
     pub fn get_leader(&self) -> Option<&CensusEntry> {
         self.population.values().find(|&ce| ce.get_leader())
     }
 
+    /// Return the leader of a finished election, ignoring any leader lease.
+    pub fn get_elected_leader(&self) -> Option<&CensusEntry> {
+        self.population.values().find(|&ce| ce.get_leader() && ce.get_election_is_finished())
+    }
+
+    /// Return the member holding the leader lease, if any.
+    pub fn get_lease_holder(&self) -> Option<&CensusEntry> {
+        match self.lease_holder {
+            Some(ref member_id) => self.population.get(member_id),
+            None => None,
+        }
+    }
+
+    /// Record who holds the leader lease. While no election says who the leader is, the lease
+    /// holder leads, and everyone else follows it.
+    pub fn set_lease_holder(&mut self, holder: Option<String>) {
+        if self.get_elected_leader().is_none() {
+            for ce in self.population.values_mut() {
+                match holder {
+                    Some(ref member_id) => {
+                        let is_holder = ce.member_id.as_ref() == Some(member_id);
+                        ce.set_leader(is_holder);
+                        ce.set_follower(!is_holder);
+                    }
+                    None => {
+                        ce.set_leader(false);
+                        ce.set_follower(false);
+                    }
+                }
+            }
+        }
+        self.lease_holder = holder;
+    }
+
     /// Return the leader of the currently running update election or None if there is no leader.
     pub fn get_update_leader(&self) -> Option<&CensusEntry> {
         self.population.values().find(|&ce| ce.get_update_leader())
@@ -550,7 +700,7 @@ impl Census {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, RustcDecodable, RustcEncodable)]
 pub struct CensusList {
     censuses: HashMap<String, Census>,
 }
@@ -583,6 +733,10 @@ impl CensusList {
         self.censuses.get(service_group)
     }
 
+    pub fn get_mut(&mut self, service_group: &str) -> Option<&mut Census> {
+        self.censuses.get_mut(service_group)
+    }
+
     pub fn populate_from_election(&mut self, election: &ElectionRumor) {
         if let Some(census_entries) = self.censuses.get_mut(election.get_service_group()) {
             for census_entry in census_entries.values_mut() {
@@ -607,15 +761,80 @@ impl CensusList {
         }
     }
 
+    /// Departed members left the ring on purpose, so rather than being marked as unhealthy they
+    /// are dropped from every census they were part of.
     pub fn populate_from_health(&mut self, member: &Member, health: Health) {
         for (_service_group, census) in self.censuses.iter_mut() {
-            if let Some(ce) = census.get_mut(member.get_id()) {
+            if health == Health::Departed {
+                census.remove(member.get_id());
+            } else if let Some(ce) = census.get_mut(member.get_id()) {
                 ce.populate_from_health(health);
             }
         }
     }
 }
 
+/// The leader leases of the service groups running in the lease topology. A lease is renewed
+/// whenever the census shows a finished election, and outlives the election running again or
+/// losing quorum until `LEADER_LEASE_TTL_SECS` have passed without a renewal.
+///
+/// Leases are local to each Supervisor; nothing is gossiped about them, and the ring never agrees
+/// on who holds one. When a partition elects a new leader, members that still hold the old
+/// leader's lease keep following it until their lease expires, so a service group can have two
+/// leaders for up to `LEADER_LEASE_TTL_SECS`. Leases carry no election term to fence the old
+/// leader with.
+#[derive(Debug, Default)]
+pub struct LeaderLeases {
+    leases: HashMap<String, (String, SteadyTime)>,
+}
+
+impl LeaderLeases {
+    pub fn new() -> LeaderLeases {
+        LeaderLeases::default()
+    }
+
+    /// Renew or expire the lease of a service group, and record its holder in the group's
+    /// census. Returns true if the holder changed.
+    pub fn check(&mut self,
+                 service_group: &str,
+                 census_list: &mut CensusList,
+                 now: SteadyTime)
+                 -> bool {
+        let census = match census_list.get_mut(service_group) {
+            Some(census) => census,
+            None => return false,
+        };
+        let previous = self.holder(service_group);
+        let elected = census.get_elected_leader().map(|ce| String::from(ce.get_member_id()));
+        match elected {
+            Some(member_id) => {
+                let expires = now + Duration::seconds(LEADER_LEASE_TTL_SECS);
+                self.leases.insert(String::from(service_group), (member_id, expires));
+            }
+            None => {
+                let expired = match self.leases.get(service_group) {
+                    Some(&(_, expires)) => expires <= now,
+                    None => false,
+                };
+                if expired {
+                    self.leases.remove(service_group);
+                }
+            }
+        }
+        let current = self.holder(service_group);
+        census.set_lease_holder(current.clone());
+        previous != current
+    }
+
+    pub fn remove(&mut self, service_group: &str) {
+        self.leases.remove(service_group);
+    }
+
+    fn holder(&self, service_group: &str) -> Option<String> {
+        self.leases.get(service_group).map(|&(ref member_id, _)| member_id.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod census_entry {
@@ -623,8 +842,10 @@ mod tests {
 
         use butterfly::rumor::service::Service;
         use butterfly::member::Member;
+        use butterfly::message::swim::Service_Health;
         use hcore::package::ident::PackageIdent;
 
+        use health_check::CheckResult;
         use manager::census::CensusEntry;
 
         #[test]
@@ -662,6 +883,23 @@ mod tests {
             assert_eq!(ce.get_exposes(),
                        &vec![String::from("6060"), String::from("8080")]);
             assert_eq!(ce.get_package_ident(), &ident);
+            assert_eq!(ce.get_health_check(), CheckResult::Unknown);
+        }
+
+        #[test]
+        fn populate_health_check_from_service_rumor() {
+            let mut ce = CensusEntry::default();
+            let ident = PackageIdent::from_str("core/overwatch/1.2.3/20161208121212").unwrap();
+            let mut service = Service::new("neurosis",
+                                           &ident,
+                                           "times",
+                                           None,
+                                           "foo.com",
+                                           "162.42.150.33",
+                                           vec![6060]);
+            service.set_health(Service_Health::CRITICAL);
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_health_check(), CheckResult::Critical);
         }
 
         #[test]
@@ -676,4 +914,121 @@ mod tests {
             assert_eq!(ce.get_persistent(), member.get_persistent());
         }
     }
+
+    mod census {
+        use butterfly::member::{Health, Member};
+        use rustc_serialize::json;
+        use time::{Duration, SteadyTime};
+
+        use manager::census::{CensusEntry, CensusList, LeaderLeases, LEADER_LEASE_TTL_SECS};
+
+        fn entry(member_id: &str, suitability: u64, initialized: bool) -> CensusEntry {
+            let mut ce = CensusEntry::default();
+            ce.set_member_id(String::from(member_id));
+            ce.set_service(String::from("redis"));
+            ce.set_group(String::from("default"));
+            ce.set_alive(true);
+            ce.set_suitability(suitability);
+            ce.set_initialized(initialized);
+            ce
+        }
+
+        #[test]
+        fn start_order_is_by_suitability_then_member_id() {
+            let mut cl = CensusList::new();
+            cl.insert(String::from("a"), entry("a", 0, false));
+            cl.insert(String::from("a"), entry("b", 10, false));
+            cl.insert(String::from("a"), entry("c", 0, false));
+            let census = cl.get("redis.default").unwrap();
+            let order: Vec<&str> =
+                census.members_in_start_order().iter().map(|ce| ce.get_member_id()).collect();
+            assert_eq!(order, vec!["b", "a", "c"]);
+        }
+
+        #[test]
+        fn departed_members_are_dropped_from_the_census() {
+            let departed = Member::new();
+            let confirmed = Member::new();
+            let mut cl = CensusList::new();
+            cl.insert(String::from("a"), entry("a", 0, true));
+            cl.insert(String::from("a"), entry(departed.get_id(), 0, true));
+            cl.insert(String::from("a"), entry(confirmed.get_id(), 0, true));
+            cl.populate_from_health(&departed, Health::Departed);
+            cl.populate_from_health(&confirmed, Health::Confirmed);
+            let census = cl.get("redis.default").unwrap();
+            assert!(census.get(departed.get_id()).is_none());
+            assert_eq!(census.get(confirmed.get_id()).unwrap().get_confirmed(), true);
+            assert_eq!(census.alive_members().len(), 1);
+        }
+
+        #[test]
+        fn census_list_decodes_from_the_json_it_is_served_as() {
+            let mut cl = CensusList::new();
+            cl.insert(String::from("a"), entry("a", 0, true));
+            cl.insert(String::from("a"), entry("b", 10, false));
+            let decoded: CensusList = json::decode(&json::encode(&cl).unwrap()).unwrap();
+            let census = decoded.get("redis.default").unwrap();
+            assert_eq!(census.me().unwrap().get_member_id(), "a");
+            assert_eq!(census.members_in_start_order(),
+                       cl.get("redis.default").unwrap().members_in_start_order());
+        }
+
+        #[test]
+        fn waiting_on_members_ahead_which_have_not_started() {
+            let mut cl = CensusList::new();
+            cl.insert(String::from("c"), entry("a", 0, true));
+            cl.insert(String::from("c"), entry("b", 0, false));
+            cl.insert(String::from("c"), entry("c", 0, false));
+            assert_eq!(cl.get("redis.default").unwrap().waiting_on().unwrap().get_member_id(),
+                       "b");
+            cl.insert(String::from("c"), entry("b", 0, true));
+            assert!(cl.get("redis.default").unwrap().waiting_on().is_none());
+        }
+
+        #[test]
+        fn first_member_in_start_order_never_waits() {
+            let mut cl = CensusList::new();
+            cl.insert(String::from("a"), entry("a", 0, false));
+            cl.insert(String::from("a"), entry("b", 0, false));
+            assert!(cl.get("redis.default").unwrap().waiting_on().is_none());
+        }
+
+        #[test]
+        fn lease_outlives_a_lost_quorum_until_it_expires() {
+            let mut cl = CensusList::new();
+            let mut leader = entry("a", 0, true);
+            leader.set_leader(true);
+            leader.set_election_is_finished(true);
+            let mut follower = entry("b", 0, true);
+            follower.set_follower(true);
+            follower.set_election_is_finished(true);
+            cl.insert(String::from("b"), leader);
+            cl.insert(String::from("b"), follower);
+            let mut leases = LeaderLeases::new();
+            let now = SteadyTime::now();
+            assert!(leases.check("redis.default", &mut cl, now));
+
+            // The election loses quorum; the census is rebuilt without a leader.
+            let mut cl = CensusList::new();
+            for member_id in ["a", "b"].iter() {
+                let mut ce = entry(member_id, 0, true);
+                ce.set_election_is_no_quorum(true);
+                cl.insert(String::from("b"), ce);
+            }
+            assert!(!leases.check("redis.default", &mut cl, now + Duration::seconds(1)));
+            {
+                let census = cl.get("redis.default").unwrap();
+                assert_eq!(census.get_leader().unwrap().get_member_id(), "a");
+                assert_eq!(census.get_lease_holder().unwrap().get_member_id(), "a");
+                assert!(census.me().unwrap().get_follower());
+            }
+
+            let expired = now + Duration::seconds(LEADER_LEASE_TTL_SECS);
+            assert!(leases.check("redis.default", &mut cl, expired));
+            let census = cl.get("redis.default").unwrap();
+            assert!(census.get_leader().is_none());
+            assert!(census.get_lease_holder().is_none());
+            assert!(!census.me().unwrap().get_follower());
+        }
+    }
 }
@@ -0,0 +1,252 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Events about changes to the census and the services of this Supervisor.
+//!
+//! The manager records an event whenever it notices something changed: a member's health, the
+//! outcome of an election, a gossiped configuration or file being applied or rejected, or a
+//! service being restarted. Membership and election events are only looked for when the `CensusUpdate`
+//! counters say butterfly has something new. The most recent events are kept in an `EventLog`,
+//! which the http gateway streams to its clients.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::result;
+
+use butterfly;
+use butterfly::member::Health;
+use butterfly::rumor::election::Election_Status;
+use rustc_serialize::{Encodable, Encoder};
+use time;
+
+use manager::census::CensusUpdate;
+
+/// How many events we keep around for clients that connect, or reconnect, late.
+pub const EVENT_LOG_LEN: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    MemberHealth,
+    ElectionFinished,
+    UpdateElectionFinished,
+    ServiceConfigApplied,
+    ServiceConfigRejected,
+    ServiceFileApplied,
+    ServiceRestarted,
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match *self {
+            EventKind::MemberHealth => "member-health",
+            EventKind::ElectionFinished => "election-finished",
+            EventKind::UpdateElectionFinished => "update-election-finished",
+            EventKind::ServiceConfigApplied => "service-config-applied",
+            EventKind::ServiceConfigRejected => "service-config-rejected",
+            EventKind::ServiceFileApplied => "service-file-applied",
+            EventKind::ServiceRestarted => "service-restarted",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+impl Encodable for EventKind {
+    fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
+        s.emit_str(&self.to_string())
+    }
+}
+
+/// Something that happened. What `detail` holds depends on the kind of event: the new health of
+/// a member, the member id of an election's leader, the incarnation of an applied configuration,
+/// why a configuration was rejected, the name of an applied file, or the pid of a restarted
+/// service.
+#[derive(Debug, Clone, RustcEncodable)]
+pub struct Event {
+    pub id: u64,
+    pub timestamp: i64,
+    pub kind: EventKind,
+    pub service_group: Option<String>,
+    pub member_id: Option<String>,
+    pub detail: Option<String>,
+}
+
+/// The most recent events, numbered in the order they happened.
+#[derive(Debug)]
+pub struct EventLog {
+    events: VecDeque<Event>,
+    next_id: u64,
+}
+
+impl EventLog {
+    pub fn new() -> EventLog {
+        EventLog {
+            events: VecDeque::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Record an event, returning its id.
+    pub fn push(&mut self,
+                kind: EventKind,
+                service_group: Option<String>,
+                member_id: Option<String>,
+                detail: Option<String>)
+                -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.events.push_back(Event {
+            id: id,
+            timestamp: time::now_utc().to_timespec().sec,
+            kind: kind,
+            service_group: service_group,
+            member_id: member_id,
+            detail: detail,
+        });
+        while self.events.len() > EVENT_LOG_LEN {
+            self.events.pop_front();
+        }
+        id
+    }
+
+    /// Returns every event we still have that happened after the event with the given id.
+    pub fn since(&self, last_id: u64) -> Vec<Event> {
+        self.events.iter().filter(|e| e.id > last_id).cloned().collect()
+    }
+
+    /// Returns the id of the most recent event, or 0 if nothing has happened yet.
+    pub fn last_id(&self) -> u64 {
+        self.next_id - 1
+    }
+}
+
+/// Remembers what butterfly told us last time, so we can tell what changed.
+#[derive(Debug, Default)]
+pub struct EventWatcher {
+    member_health: HashMap<String, Health>,
+    elections: HashMap<String, Election_Status>,
+    update_elections: HashMap<String, Election_Status>,
+}
+
+impl EventWatcher {
+    pub fn new() -> EventWatcher {
+        EventWatcher::default()
+    }
+
+    /// Record an event for every member whose health changed, and every election which finished,
+    /// since the last census update.
+    pub fn check(&mut self,
+                 server: &butterfly::Server,
+                 last_update: &CensusUpdate,
+                 update: &CensusUpdate,
+                 log: &mut EventLog) {
+        if update.membership_changed(last_update) {
+            let member_health = &mut self.member_health;
+            server.member_list.with_members(|member| {
+                let health = match server.member_list.health_of(member) {
+                    Some(health) => health,
+                    None => return,
+                };
+                if member_health.get(member.get_id()) != Some(&health) {
+                    member_health.insert(String::from(member.get_id()), health);
+                    log.push(EventKind::MemberHealth,
+                             None,
+                             Some(String::from(member.get_id())),
+                             Some(health.to_string()));
+                }
+            });
+        }
+        if update.elections_changed(last_update) {
+            let elections = &mut self.elections;
+            server.election_store.with_keys(|(service_group, rumors)| {
+                if let Some(election) = rumors.get("election") {
+                    if finished(elections, service_group, election.get_status()) {
+                        log.push(EventKind::ElectionFinished,
+                                 Some(service_group.clone()),
+                                 None,
+                                 Some(String::from(election.get_member_id())));
+                    }
+                }
+            });
+            let update_elections = &mut self.update_elections;
+            server.update_store.with_keys(|(service_group, rumors)| {
+                if let Some(election) = rumors.get("election") {
+                    if finished(update_elections, service_group, election.get_status()) {
+                        log.push(EventKind::UpdateElectionFinished,
+                                 Some(service_group.clone()),
+                                 None,
+                                 Some(String::from(election.get_member_id())));
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Records the status of an election, returning true if it has just finished.
+fn finished(seen: &mut HashMap<String, Election_Status>,
+            service_group: &str,
+            status: Election_Status)
+            -> bool {
+    let previous = seen.insert(String::from(service_group), status);
+    status == Election_Status::Finished && previous != Some(Election_Status::Finished)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use butterfly::rumor::election::Election_Status;
+
+    use super::{finished, EventKind, EventLog, EVENT_LOG_LEN};
+
+    #[test]
+    fn since_returns_newer_events() {
+        let mut log = EventLog::new();
+        assert_eq!(log.last_id(), 0);
+        let first = log.push(EventKind::ServiceRestarted,
+                             Some(String::from("redis.default")),
+                             None,
+                             Some(String::from("1234")));
+        let second = log.push(EventKind::ServiceConfigApplied,
+                              Some(String::from("redis.default")),
+                              None,
+                              Some(String::from("2")));
+        assert_eq!(log.last_id(), second);
+        let events = log.since(first);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::ServiceConfigApplied);
+        assert!(log.since(second).is_empty());
+    }
+
+    #[test]
+    fn log_keeps_only_the_most_recent_events() {
+        let mut log = EventLog::new();
+        for _ in 0..(EVENT_LOG_LEN + 10) {
+            log.push(EventKind::MemberHealth, None, None, None);
+        }
+        let events = log.since(0);
+        assert_eq!(events.len(), EVENT_LOG_LEN);
+        assert_eq!(events[0].id, 11);
+    }
+
+    #[test]
+    fn election_finishes_once() {
+        let mut seen = HashMap::new();
+        assert!(!finished(&mut seen, "redis.default", Election_Status::Running));
+        assert!(finished(&mut seen, "redis.default", Election_Status::Finished));
+        assert!(!finished(&mut seen, "redis.default", Election_Status::Finished));
+        assert!(!finished(&mut seen, "redis.default", Election_Status::Running));
+        assert!(finished(&mut seen, "redis.default", Election_Status::Finished));
+    }
+}
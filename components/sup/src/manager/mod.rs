@@ -13,28 +13,39 @@
 // limitations under the License.
 
 pub mod census;
+pub mod events;
 pub mod service;
 pub mod signals;
 pub mod service_updater;
 
 use std::net::{SocketAddr, ToSocketAddrs};
+use std::path::PathBuf;
 use std::thread;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
 use std::time::Duration;
 
+use ansi_term::Colour::{Red, Yellow};
 use butterfly;
 use butterfly::member::Member;
 use butterfly::trace::Trace;
 use butterfly::rumor::service::Service as ServiceRumor;
+use butterfly::server::snapshot;
 use butterfly::server::timing::Timing;
 use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::fs::sup_path;
+use hcore::service::ServiceGroup;
 use time::{SteadyTime, Duration as TimeDuration};
 
 use self::service_updater::ServiceUpdater;
+use command::start::load_package;
 use error::{Error, Result};
-use config::{gconfig, UpdateStrategy, Topology};
-use manager::service::Service;
-use manager::census::{CensusUpdate, CensusList, CensusEntry};
+use health_check::CheckResult;
+use config::{gconfig, Topology};
+use manager::service::{DesiredState, Service};
+use manager::service::spec::{self, ServiceSpec};
+use manager::census::{CensusUpdate, CensusList, CensusEntry, LeaderLeases};
+use manager::events::{EventKind, EventLog, EventWatcher};
 use manager::signals::SignalEvent;
 use package::Package;
 use http_gateway;
@@ -42,11 +53,50 @@ use util;
 
 static LOGKEY: &'static str = "MR";
 
+/// The file, in the Supervisor's state directory, holding the last snapshot of butterfly.
+static BUTTERFLY_SNAPSHOT_FILENAME: &'static str = "butterfly.dat";
+
+/// How often, in milliseconds, we write a snapshot of butterfly to disk.
+const BUTTERFLY_SNAPSHOT_INTERVAL_MS: i64 = 30_000;
+
+/// How long, in milliseconds, we wait for our departure to be gossiped before shutting down.
+const DEPARTURE_GOSSIP_TIMEOUT_MS: i64 = 5_000;
+
+/// A request to change the set of services running in this Supervisor, or the state of one of
+/// them. Requests are queued by the http gateway and handled by the manager on its next pass, so
+/// the gateway never holds the services lock while hooks and processes run.
+pub enum ServiceRequest {
+    Load(ServiceSpec),
+    Unload(ServiceGroup),
+    Start(ServiceGroup),
+    Stop(ServiceGroup),
+    /// Restart a service, telling the gateway what came of it.
+    Restart(ServiceGroup, Sender<RestartOutcome>),
+}
+
+/// What came of a restart requested through the http gateway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartOutcome {
+    /// The service is running under a new pid.
+    Restarted,
+    /// The service was not restarted, because its topology held it back; an election is running
+    /// or it is not its turn to start.
+    Deferred,
+    /// The service was not restarted, because it is waiting for its binds.
+    WaitingForBinds,
+    /// The service is no longer loaded.
+    NotLoaded,
+    /// Restarting the service failed.
+    Failed,
+}
+
 #[derive(Clone)]
 pub struct State {
     pub butterfly: butterfly::Server,
     pub census_list: Arc<RwLock<CensusList>>,
     pub services: Arc<RwLock<Vec<Service>>>,
+    pub service_requests: Arc<Mutex<Vec<ServiceRequest>>>,
+    pub events: Arc<RwLock<EventLog>>,
 }
 
 impl State {
@@ -55,6 +105,8 @@ impl State {
             butterfly: butterfly,
             census_list: Arc::new(RwLock::new(CensusList::new())),
             services: Arc::new(RwLock::new(Vec::new())),
+            service_requests: Arc::new(Mutex::new(Vec::new())),
+            events: Arc::new(RwLock::new(EventLog::new())),
         }
     }
 }
@@ -62,11 +114,30 @@ impl State {
 pub struct Manager {
     state: State,
     updater: ServiceUpdater,
+    specs: Vec<ServiceSpec>,
+    watcher: EventWatcher,
+    leases: LeaderLeases,
+    /// Services requested through the http gateway whose packages are still being installed.
+    installing: Vec<(ServiceSpec, Receiver<Option<Package>>)>,
 }
 
 impl Manager {
     pub fn new() -> Result<Manager> {
-        let mut member = Member::new();
+        // Come back as the member we were before a restart, if we can. The incarnation is bumped
+        // so our peers take our word over anything they were gossiping about us while we were
+        // gone.
+        let mut member = match snapshot::read_member(snapshot_path()) {
+            Ok(Some(mut member)) => {
+                let incarnation = member.get_incarnation() + 1;
+                member.set_incarnation(incarnation);
+                member
+            }
+            Ok(None) => Member::new(),
+            Err(e) => {
+                outputln!("Failed to read butterfly snapshot: {}", e);
+                Member::new()
+            }
+        };
         member.set_persistent(gconfig().gossip_permanent());
         member.set_swim_port(gconfig().gossip_listen().port() as i32);
         member.set_gossip_port(gconfig().gossip_listen().port() as i32);
@@ -78,6 +149,7 @@ impl Manager {
             }
             &None => None,
         };
+        let ring_name = ring_key.as_ref().map(|key| key.name.clone());
 
         let server = try!(butterfly::Server::new(gconfig().gossip_listen(),
                                                  gconfig().gossip_listen(),
@@ -86,6 +158,23 @@ impl Manager {
                                                  ring_key,
                                                  None));
         outputln!("Butterfly Member ID {}", server.member_id());
+        // Every other revision of the ring key we have is accepted too, so that we keep talking to
+        // the rest of the ring while a new revision is rolled out.
+        if let Some(ref ring) = ring_name {
+            match SymKey::get_pairs_for(ring, &default_cache_key_path(None)) {
+                Ok(keys) => {
+                    for key in keys.into_iter() {
+                        server.add_ring_key(key);
+                    }
+                }
+                Err(e) => outputln!("Failed to load other revisions of ring {}: {}", ring, e),
+            }
+        }
+        match snapshot::rehydrate(&server, snapshot_path()) {
+            Ok(0) => {}
+            Ok(count) => outputln!("Restored {} members and rumors from snapshot", count),
+            Err(e) => outputln!("Failed to restore butterfly snapshot: {}", e),
+        }
         for peer_addr in gconfig().gossip_peer() {
             let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
                 Ok(addrs) => addrs.collect(),
@@ -104,19 +193,131 @@ impl Manager {
         Ok(Manager {
             updater: ServiceUpdater::new(server.clone()),
             state: State::new(server),
+            specs: Vec::new(),
+            watcher: EventWatcher::new(),
+            leases: LeaderLeases::new(),
+            installing: Vec::new(),
         })
     }
 
-    pub fn add_service(&mut self,
-                       package: Package,
-                       topology: Topology,
-                       update_strategy: UpdateStrategy)
-                       -> Result<()> {
-        let service = try!(Service::new(package.clone(),
-                                        gconfig().group(),
-                                        gconfig().organization().clone(),
-                                        topology,
-                                        update_strategy));
+    /// Load every service persisted by a previous run of this Supervisor which isn't already
+    /// loaded. Specs which fail to load are kept, so they are retried on the next start.
+    pub fn load_persisted_services(&mut self) {
+        let persisted = match spec::read_specs(spec::specs_path()) {
+            Ok(persisted) => persisted,
+            Err(e) => {
+                outputln!("Failed to read persisted services: {}", e);
+                return;
+            }
+        };
+        for spec in persisted.into_iter() {
+            if self.is_loaded(&spec.service_group()) {
+                continue;
+            }
+            outputln!("Loading persisted service {}", spec.service_group());
+            if let Err(e) = self.load_service(spec.clone()) {
+                outputln!("Failed to load {}: {}", spec.service_group(), e);
+                self.specs.push(spec);
+            }
+        }
+        self.persist_specs();
+    }
+
+    /// Install (if necessary) and load the package for a spec, then add it as a service. This
+    /// blocks until the package is installed, which can mean a download from the Depot, so it is
+    /// only used before the manager loop starts; requests from the http gateway are installed in
+    /// the background instead.
+    pub fn load_service(&mut self, spec: ServiceSpec) -> Result<()> {
+        if self.is_loaded(&spec.service_group()) {
+            return Err(sup_error!(Error::ServiceAlreadyLoaded(spec.service_group().to_string())));
+        }
+        let package = try!(load_package(&spec.ident, None, spec.update_strategy));
+        self.add_service(package, spec)
+    }
+
+    /// Stop a service and remove it from this Supervisor, withdrawing its service rumor so the
+    /// rest of the ring stops counting us in its service group. A service that fails to stop
+    /// stays loaded.
+    pub fn unload_service(&mut self, service_group: &ServiceGroup) -> Result<()> {
+        {
+            let mut services = self.state.services.write().expect("Services lock is poisoned!");
+            let index = match services.iter().position(|s| &s.service_group == service_group) {
+                Some(index) => index,
+                None => {
+                    return Err(sup_error!(Error::ServiceNotLoaded(service_group.to_string())));
+                }
+            };
+            outputln!("Unloading {}", services[index]);
+            try!(services[index].down());
+            services.remove(index);
+        }
+        self.state.butterfly.withdraw_service(&service_group.as_string());
+        self.updater.remove(service_group);
+        self.leases.remove(&service_group.as_string());
+        self.specs.retain(|s| &s.service_group() != service_group);
+        self.persist_specs();
+        Ok(())
+    }
+
+    /// Mark a loaded service as wanted up, starting it right away if it is down.
+    fn start_service(&self, service_group: &ServiceGroup) -> Result<()> {
+        let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
+        let mut services = self.state.services.write().expect("Services lock is poisoned!");
+        match services.iter_mut().find(|s| &s.service_group == service_group) {
+            Some(service) => service.up(&census_list),
+            None => Err(sup_error!(Error::ServiceNotLoaded(service_group.to_string()))),
+        }
+    }
+
+    /// Stop a loaded service, and keep it down until it is started again.
+    fn stop_service(&self, service_group: &ServiceGroup) -> Result<()> {
+        let mut services = self.state.services.write().expect("Services lock is poisoned!");
+        match services.iter_mut().find(|s| &s.service_group == service_group) {
+            Some(service) => service.stop(),
+            None => Err(sup_error!(Error::ServiceNotLoaded(service_group.to_string()))),
+        }
+    }
+
+    /// Restart a loaded service, with a fresh restart budget, and mark it as wanted up. The
+    /// service may decline to restart, if it is waiting for its binds or for an election; that
+    /// shows up as its pid not changing.
+    fn restart_service(&self, service_group: &ServiceGroup) -> RestartOutcome {
+        let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
+        let mut services = self.state.services.write().expect("Services lock is poisoned!");
+        let service = match services.iter_mut().find(|s| &s.service_group == service_group) {
+            Some(service) => service,
+            None => return RestartOutcome::NotLoaded,
+        };
+        service.desired_state = DesiredState::Up;
+        service.restarts.reset();
+        let pid = service.pid();
+        if let Err(e) = service.restart(&census_list) {
+            outputln!("Failed to restart {}: {}", service_group, e);
+            return RestartOutcome::Failed;
+        }
+        if service.pid().is_none() || service.pid() == pid {
+            if service.unsatisfied_binds.is_empty() {
+                return RestartOutcome::Deferred;
+            } else {
+                return RestartOutcome::WaitingForBinds;
+            }
+        }
+        self.state
+            .events
+            .write()
+            .expect("Event log lock is poisoned!")
+            .push(EventKind::ServiceRestarted,
+                  Some(service.service_group_str()),
+                  None,
+                  service.pid().map(|p| p.to_string()));
+        RestartOutcome::Restarted
+    }
+
+    pub fn add_service(&mut self, package: Package, spec: ServiceSpec) -> Result<()> {
+        if self.is_loaded(&spec.service_group()) {
+            return Err(sup_error!(Error::ServiceAlreadyLoaded(spec.service_group().to_string())));
+        }
+        let service = try!(Service::new(package.clone(), &spec, self.state.butterfly.member_id()));
         let hostname = try!(util::sys::hostname());
         let ip = try!(util::sys::ip());
         // TODO: We should do this much earlier, to confirm that the ports we expose are not
@@ -126,26 +327,145 @@ impl Manager {
             let port_num = try!(port.parse::<u32>().map_err(|e| sup_error!(Error::InvalidPort(e))));
             exposes.push(port_num);
         }
-        let service_rumor = ServiceRumor::new(self.state.butterfly.member_id(),
-                                              package.ident(),
-                                              service.service_group.group.clone(),
-                                              service.service_group.organization.clone(),
-                                              hostname,
-                                              ip.to_string(),
-                                              exposes);
-        self.state.butterfly.insert_service(service_rumor);
-
-        if topology == Topology::Leader || topology == Topology::Initializer {
-            // Note - eventually, we need to deal with suitability here. The original implementation
-            // didn't have this working either.
-            self.state.butterfly.start_election(service.service_group.clone(), 0, 0);
+        let mut service_rumor = ServiceRumor::new(self.state.butterfly.member_id(),
+                                                  package.ident(),
+                                                  service.service_group.group.clone(),
+                                                  service.service_group.organization.clone(),
+                                                  hostname,
+                                                  ip.to_string(),
+                                                  exposes);
+        // A rumor restored from a snapshot (or still being gossiped by our peers) would win over
+        // a fresh one at incarnation zero, so we have to come in above it; so would the tombstone
+        // left when we last unloaded the service.
+        if let Some(withdrawn) = self.state
+            .butterfly
+            .withdrawn_service_incarnation(&service.service_group.as_string()) {
+            service_rumor.set_incarnation(withdrawn + 1);
         }
+        self.state
+            .butterfly
+            .service_store
+            .with_rumor(&service.service_group.as_string(),
+                        self.state.butterfly.member_id(),
+                        |existing| if let Some(existing) = existing {
+                            service_rumor.set_incarnation(existing.get_incarnation() + 1);
+                        });
+        self.state.butterfly.insert_service(service_rumor);
 
         self.updater.add(&service);
         self.state.services.write().expect("Services lock is poisoned!").push(service);
+        self.specs.retain(|s| s.service_group() != spec.service_group());
+        self.specs.push(spec);
+        self.persist_specs();
         Ok(())
     }
 
+    fn is_loaded(&self, service_group: &ServiceGroup) -> bool {
+        self.state
+            .services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .any(|s| &s.service_group == service_group)
+    }
+
+    /// Tell the ring we are leaving on purpose, and give the departure a full gossip round to get
+    /// out before we shut down. Peers drop us from their census and elections when it arrives,
+    /// instead of waiting for us to be suspected and confirmed dead.
+    fn depart(&self) {
+        outputln!("Announcing our departure to the ring");
+        self.state.butterfly.depart();
+        let rounds = self.state.butterfly.gossip_rounds();
+        let timeout = SteadyTime::now() +
+                      TimeDuration::milliseconds(DEPARTURE_GOSSIP_TIMEOUT_MS);
+        while self.state.butterfly.gossip_rounds() < rounds + 2 && SteadyTime::now() < timeout {
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    /// Write a snapshot of butterfly to disk, so a restart doesn't lose what we know.
+    fn write_butterfly_snapshot(&self) {
+        if let Err(e) = snapshot::write(&self.state.butterfly, snapshot_path()) {
+            outputln!("Failed to write butterfly snapshot: {}", e);
+        }
+    }
+
+    fn persist_specs(&self) {
+        if let Err(e) = spec::write_specs(spec::specs_path(), &self.specs) {
+            outputln!("Failed to persist the loaded services: {}", e);
+        }
+    }
+
+    /// Handle the requests queued up by the http gateway.
+    fn check_for_service_requests(&mut self) {
+        let requests: Vec<ServiceRequest> = {
+            let mut requests = self.state
+                .service_requests
+                .lock()
+                .expect("Service requests lock is poisoned!");
+            requests.drain(..).collect()
+        };
+        for request in requests.into_iter() {
+            match request {
+                ServiceRequest::Load(spec) => {
+                    let sg = spec.service_group();
+                    if self.is_loaded(&sg) ||
+                       self.installing.iter().any(|&(ref s, _)| s.service_group() == sg) {
+                        outputln!("Failed to load {}: {}",
+                                  sg,
+                                  sup_error!(Error::ServiceAlreadyLoaded(sg.to_string())));
+                        continue;
+                    }
+                    outputln!("Loading {}", sg);
+                    let rx = install_in_background(&spec);
+                    self.installing.push((spec, rx));
+                }
+                ServiceRequest::Unload(sg) => {
+                    if let Err(e) = self.unload_service(&sg) {
+                        outputln!("Failed to unload {}: {}", sg, e);
+                    }
+                }
+                ServiceRequest::Start(sg) => {
+                    if let Err(e) = self.start_service(&sg) {
+                        outputln!("Failed to start {}: {}", sg, e);
+                    }
+                }
+                ServiceRequest::Stop(sg) => {
+                    if let Err(e) = self.stop_service(&sg) {
+                        outputln!("Failed to stop {}: {}", sg, e);
+                    }
+                }
+                ServiceRequest::Restart(sg, reply) => {
+                    // The gateway may have given up waiting for us; the restart stands either way.
+                    let _ = reply.send(self.restart_service(&sg));
+                }
+            }
+        }
+    }
+
+    /// Add the services whose packages have finished installing in the background. Failed
+    /// installs were already reported by the thread that ran them.
+    fn check_for_installed_packages(&mut self) {
+        let mut ready = Vec::new();
+        let mut i = 0;
+        while i < self.installing.len() {
+            let received = self.installing[i].1.try_recv();
+            match received {
+                Err(TryRecvError::Empty) => i += 1,
+                Ok(package) => ready.push((self.installing.remove(i).0, package)),
+                Err(TryRecvError::Disconnected) => ready.push((self.installing.remove(i).0, None)),
+            }
+        }
+        for (spec, package) in ready.into_iter() {
+            if let Some(package) = package {
+                let sg = spec.service_group();
+                if let Err(e) = self.add_service(package, spec) {
+                    outputln!("Failed to load {}: {}", sg, e);
+                }
+            }
+        }
+    }
+
     pub fn build_census(&mut self, last_update: &CensusUpdate) -> Result<(bool, CensusUpdate)> {
         let update = CensusUpdate::new(self.state.butterfly.service_store.get_update_counter(),
                                        self.state.butterfly.election_store.get_update_counter(),
@@ -153,6 +473,10 @@ impl Manager {
                                        self.state.butterfly.member_list.get_update_counter());
 
         if &update != last_update {
+            self.watcher.check(&self.state.butterfly,
+                               last_update,
+                               &update,
+                               &mut self.state.events.write().expect("Event log lock is poisoned!"));
             let mut cl = CensusList::new();
             debug!("Updating census from butterfly data");
             self.state.butterfly.service_store.with_keys(|(_service_group, rumors)| {
@@ -220,41 +544,200 @@ impl Manager {
 
     /// Walk each service and check if it has an updated package installed via the Update Strategy.
     pub fn check_for_updated_packages(&mut self) {
-        let member_id = {
-            self.state.butterfly.member_id().to_string()
-        };
         let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
         for service in self.state.services.write().expect("Services lock is poisoned!").iter_mut() {
             if self.updater.check_for_updated_package(service, &census_list) {
-                let mut rumor = {
-                    let list = self.state
-                        .butterfly
-                        .service_store
-                        .list
-                        .read()
-                        .expect("Rumor store lock poisoned");
-                    list.get(&service.service_group.as_string())
-                        .and_then(|r| r.get(&member_id))
-                        .unwrap()
-                        .clone()
-                };
+                let package_ident = service.package.to_string();
+                self.update_own_service_rumor(&service.service_group, |rumor| {
+                    rumor.set_package_ident(package_ident);
+                    true
+                });
+            }
+        }
+    }
+
+    /// Renew or expire the leader lease of every service running in the lease topology, returning
+    /// true if any lease changed hands.
+    fn check_leases(&mut self) -> bool {
+        let now = SteadyTime::now();
+        let mut census_list = self.state
+            .census_list
+            .write()
+            .expect("Census list lock is poisoned!");
+        let mut changed = false;
+        for service in self.state
+            .services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .filter(|s| s.topology == Topology::Lease) {
+            let sg = service.service_group.as_string();
+            if self.leases.check(&sg, &mut census_list, now) {
+                match census_list.get(&sg).and_then(|c| c.get_lease_holder()) {
+                    Some(holder) => {
+                        outputln!(preamble sg,
+                                  "{} holds the leader lease",
+                                  holder.get_member_id())
+                    }
+                    None => {
+                        outputln!(preamble sg, "{}", Yellow.bold().paint("Leader lease expired"))
+                    }
+                }
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Gossip that our member of a service group has started its service, so members behind us
+    /// in the ordered topology can start theirs.
+    fn gossip_initialized(&self, service_group: &ServiceGroup) {
+        self.update_own_service_rumor(service_group, |rumor| if rumor.get_initialized() {
+            false
+        } else {
+            rumor.set_initialized(true);
+            true
+        });
+    }
+
+    /// Gossip the configuration a service exports, if it changed since we last gossiped it.
+    fn gossip_exports(&self, service: &Service) {
+        let exported = match service.exported_cfg {
+            Some(ref exported) => exported,
+            None => return,
+        };
+        self.update_own_service_rumor(&service.service_group,
+                                      |rumor| if rumor.get_cfg() == exported.as_bytes() {
+                                          false
+                                      } else {
+                                          rumor.set_cfg(exported.as_bytes().to_vec());
+                                          true
+                                      });
+    }
+
+    /// Change our service rumor for a service group, gossiping it under a new incarnation if `f`
+    /// returns true. Does nothing if we have no rumor for the service group.
+    fn update_own_service_rumor<F>(&self, service_group: &ServiceGroup, f: F)
+        where F: FnOnce(&mut ServiceRumor) -> bool
+    {
+        let mut rumor = None;
+        self.state
+            .butterfly
+            .service_store
+            .with_rumor(&service_group.as_string(),
+                        self.state.butterfly.member_id(),
+                        |existing| rumor = existing.cloned());
+        if let Some(mut rumor) = rumor {
+            if f(&mut rumor) {
                 let incarnation = rumor.get_incarnation() + 1;
-                rumor.set_package_ident(service.package.to_string());
                 rumor.set_incarnation(incarnation);
                 self.state.butterfly.insert_service(rumor);
             }
         }
     }
 
+    /// Run the suitability hook of every initialized service, declaring any change; with
+    /// `undeclared_only`, only of the services that have yet to declare their suitability. Hooks
+    /// can take a while, so they run under the read lock on the services, and the gossip goes out
+    /// once we have let go of it.
+    pub fn check_service_suitability(&self, undeclared_only: bool) {
+        let results: Vec<(ServiceGroup, Option<u64>)> = {
+            let services = self.state.services.read().expect("Services lock is poisoned!");
+            services.iter()
+                .filter(|service| service.initialized)
+                .filter(|service| !undeclared_only || service.suitability.is_none())
+                .map(|service| (service.service_group.clone(), service.check_suitability()))
+                .collect()
+        };
+        let changes: Vec<(ServiceGroup, Topology, u64)> = {
+            let mut services = self.state.services.write().expect("Services lock is poisoned!");
+            results.into_iter()
+                .filter_map(|(sg, result)| {
+                    services.iter_mut()
+                        .find(|service| service.service_group == sg)
+                        .and_then(|service| {
+                            service.record_suitability(result)
+                                .map(|suitability| (sg, service.topology, suitability))
+                        })
+                })
+                .collect()
+        };
+        for (sg, topology, suitability) in changes {
+            self.declare_suitability(&sg, topology, suitability);
+        }
+    }
+
+    /// Gossip a service's new suitability and declare it in the service group's election. A
+    /// finished election is left alone; an election which is still running is joined again with
+    /// the new suitability, and a service group which needs an election and has none gets one.
+    fn declare_suitability(&self,
+                           service_group: &ServiceGroup,
+                           topology: Topology,
+                           suitability: u64) {
+        let sg = service_group.as_string();
+        self.update_own_service_rumor(service_group, |rumor| {
+            rumor.set_suitability(suitability);
+            true
+        });
+        match topology {
+            Topology::Leader | Topology::Initializer | Topology::Lease => {}
+            Topology::Standalone | Topology::Ordered => return,
+        }
+        let mut election = None;
+        self.state.butterfly.election_store.with_rumor(&sg, "election", |e| {
+            election = e.map(|e| (e.get_term(), e.is_finished()));
+        });
+        let term = match election {
+            Some((_, true)) => return,
+            Some((term, false)) => term,
+            None => 0,
+        };
+        self.state.butterfly.start_election(service_group.clone(), suitability, term);
+    }
+
+    /// Run the health check of every initialized service, gossiping any change in a service's
+    /// health to the rest of the ring. As with suitability, the checks run under the read lock
+    /// on the services, and the gossip goes out once we have let go of it.
+    pub fn check_service_health(&mut self) {
+        let results: Vec<(ServiceGroup, CheckResult)> = {
+            let services = self.state.services.read().expect("Services lock is poisoned!");
+            services.iter()
+                .filter(|service| service.initialized)
+                .map(|service| (service.service_group.clone(), service.check_health()))
+                .collect()
+        };
+        let changes: Vec<(ServiceGroup, CheckResult)> = {
+            let mut services = self.state.services.write().expect("Services lock is poisoned!");
+            results.into_iter()
+                .filter_map(|(sg, result)| {
+                    services.iter_mut()
+                        .find(|service| service.service_group == sg)
+                        .and_then(|service| service.record_health(result))
+                        .map(|result| (sg, result))
+                })
+                .collect()
+        };
+        for (sg, result) in changes {
+            self.update_own_service_rumor(&sg, |rumor| {
+                rumor.set_health(result.into());
+                true
+            });
+        }
+    }
+
     //  * Start butterfly
     //  Loop {
     //    * Check for incoming signals; forward them; shut down if necessary
+    //    * Snapshot butterfly to disk, if it's time
+    //    * Run the health checks, if it's time, and gossip any changes
+    //    * Load or unload services on request
     //    * Check if each service needs its package updated
     //      * Update the package
     //    * Check if the Census needs building from Butterfly, or the package changed
     //      * Loop the services, and reconfigure the service from the Census
     //    * Reap any dead children
     //    * Start or restart the services
+    //    * Declare the suitability of newly initialized services
     //  }
     //
     pub fn run(&mut self) -> Result<()> {
@@ -263,7 +746,9 @@ impl Manager {
 
         outputln!("Starting butterfly on {}",
                   gconfig().gossip_listen().to_string());
-        try!(self.state.butterfly.start(Timing::default()));
+        let mut timing = Timing::default();
+        timing.set_reap_timeout_ms(gconfig().reap_after() as i64 * 1000);
+        try!(self.state.butterfly.start(timing));
         debug!("butterfly server started");
         outputln!("Starting http-gateway on {}", gconfig().http_listen_addr());
         try!(http_gateway::Server::new(self.state.clone()).start());
@@ -271,6 +756,10 @@ impl Manager {
 
         // Watch for updates
         let mut last_census_update = CensusUpdate::new(0, 0, 0, 0);
+        let mut next_snapshot = SteadyTime::now() +
+                                TimeDuration::milliseconds(BUTTERFLY_SNAPSHOT_INTERVAL_MS);
+        let health_check_interval = TimeDuration::seconds(gconfig().health_check_interval() as i64);
+        let mut next_health_check = SteadyTime::now() + health_check_interval;
 
         'services: loop {
             let next_check = SteadyTime::now() + TimeDuration::milliseconds(1000);
@@ -284,10 +773,29 @@ impl Manager {
             // This function returns true if we are supposed to shut the system down, false if we
             // can keep going.
             if self.check_for_incoming_signals() {
+                self.depart();
+                self.write_butterfly_snapshot();
                 outputln!("Habitat thanks you - shutting down!");
                 return Ok(());
             }
 
+            if SteadyTime::now() >= next_snapshot {
+                self.write_butterfly_snapshot();
+                next_snapshot = SteadyTime::now() +
+                                TimeDuration::milliseconds(BUTTERFLY_SNAPSHOT_INTERVAL_MS);
+            }
+
+            if SteadyTime::now() >= next_health_check {
+                self.check_service_health();
+                self.check_service_suitability(false);
+                next_health_check = SteadyTime::now() + health_check_interval;
+            }
+
+            // Load or unload any services we've been asked to via the http gateway, and add the
+            // ones whose packages have finished installing.
+            self.check_for_service_requests();
+            self.check_for_installed_packages();
+
             // Check for updated packages; this updates the Service to point to the new service
             // struct, and then marks it for restarting.
             self.check_for_updated_packages();
@@ -303,6 +811,10 @@ impl Manager {
                 last_census_update = ncu;
             }
 
+            // Apply the leader leases to the census; a lease changing hands changes the census as
+            // much as new gossip does.
+            let census_updated = self.check_leases() || census_updated;
+
             for service in self.state
                 .services
                 .write()
@@ -316,7 +828,19 @@ impl Manager {
                     .service_files_for(&service.service_group_str(),
                                        &service.current_service_files)
                     .into_iter() {
-                    let result = service.write_butterfly_service_file(filename, incarnation, body);
+                    let result = service.write_butterfly_service_file(filename.clone(),
+                                                                      incarnation,
+                                                                      body);
+                    if result {
+                        self.state
+                            .events
+                            .write()
+                            .expect("Event log lock is poisoned!")
+                            .push(EventKind::ServiceFileApplied,
+                                  Some(service.service_group_str()),
+                                  None,
+                                  Some(filename));
+                    }
                     if service_files_updated == false && result == true {
                         service_files_updated = true;
                     }
@@ -331,7 +855,38 @@ impl Manager {
                     .butterfly
                     .service_config_for(&service.service_group_str(),
                                         service.service_config_incarnation) {
-                    service_config_updated = service.write_butterfly_service_config(config);
+                    // A configuration which doesn't match the package's schema is never
+                    // written, so the service keeps running with the configuration it has.
+                    match service.validate_butterfly_service_config(&config) {
+                        Ok(()) => {
+                            service_config_updated =
+                                service.write_butterfly_service_config(config);
+                            if service_config_updated {
+                                self.state
+                                    .events
+                                    .write()
+                                    .expect("Event log lock is poisoned!")
+                                    .push(EventKind::ServiceConfigApplied,
+                                          Some(service.service_group_str()),
+                                          None,
+                                          Some(incarnation.to_string()));
+                            }
+                        }
+                        Err(e) => {
+                            outputln!(preamble service.service_group_str(),
+                                      "Rejected service configuration {} from butterfly: {}",
+                                      incarnation,
+                                      Red.bold().paint(format!("{}", e)));
+                            self.state
+                                .events
+                                .write()
+                                .expect("Event log lock is poisoned!")
+                                .push(EventKind::ServiceConfigRejected,
+                                      Some(service.service_group_str()),
+                                      None,
+                                      Some(e.to_string()));
+                        }
+                    }
                     service.service_config_incarnation = Some(incarnation);
                 }
 
@@ -346,21 +901,43 @@ impl Manager {
                 // If this service has not been initialized, do so now.
                 service.initialize();
 
+                // Let the services bound to us know what we export.
+                self.gossip_exports(service);
+
                 // Reap dead children
                 let _ = service.check_process();
 
                 // Start or restart the service
-                if service.initialized && (service.needs_restart || service.is_down()) {
+                if service.should_restart() {
+                    let pid = service.pid();
                     match service.restart(&self.state
                         .census_list
                         .read()
                         .expect("Census list lock is poisoned!")) {
-                        Ok(()) => {}
+                        Ok(()) => {
+                            if service.pid().is_some() {
+                                self.gossip_initialized(&service.service_group);
+                            }
+                            if service.pid().is_some() && service.pid() != pid {
+                                self.state
+                                    .events
+                                    .write()
+                                    .expect("Event log lock is poisoned!")
+                                    .push(EventKind::ServiceRestarted,
+                                          Some(service.service_group_str()),
+                                          None,
+                                          service.pid().map(|p| p.to_string()));
+                            }
+                        }
                         Err(e) => outputln!("Cannot restart service: {}", e),
                     }
                 }
             }
 
+            // Declare how suitable we are to lead the service groups of the services that were
+            // just initialized, which starts their elections if they need them.
+            self.check_service_suitability(true);
+
             let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
             if time_to_wait > 0 {
                 thread::sleep(Duration::from_millis(time_to_wait as u64));
@@ -368,3 +945,35 @@ impl Manager {
         }
     }
 }
+
+/// Install (if necessary) and load the package for a spec on its own thread, so that a download
+/// from the Depot doesn't hold up the manager loop. The package is sent back once it is loaded;
+/// `None` is sent if it can't be.
+fn install_in_background(spec: &ServiceSpec) -> Receiver<Option<Package>> {
+    let (tx, rx) = channel();
+    let sg = spec.service_group();
+    let ident = spec.ident.clone();
+    let update_strategy = spec.update_strategy;
+    let spawned = thread::Builder::new()
+        .name(format!("installer-{}", sg))
+        .spawn(move || {
+            let package = match load_package(&ident, None, update_strategy) {
+                Ok(package) => Some(package),
+                Err(e) => {
+                    outputln!("Failed to load {}: {}", sg, e);
+                    None
+                }
+            };
+            // The manager only goes away when we are shutting down.
+            let _ = tx.send(package);
+        });
+    if let Err(e) = spawned {
+        outputln!("Failed to start installing {}: {}", spec.service_group(), e);
+    }
+    rx
+}
+
+/// Returns the path to the butterfly snapshot for this Supervisor.
+fn snapshot_path() -> PathBuf {
+    sup_path(gconfig().name()).join(BUTTERFLY_SNAPSHOT_FILENAME)
+}
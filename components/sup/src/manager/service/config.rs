@@ -15,6 +15,7 @@
 /// Collect all the configuration data that is exposed to users, and render it.
 
 use std::ascii::AsciiExt;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::prelude::*;
@@ -24,11 +25,13 @@ use rustc_serialize::Encodable;
 use toml;
 use handlebars::Handlebars;
 
-use manager::census::{Census, CensusList};
+use manager::census::{Census, CensusEntry, CensusList};
+use manager::service::schema::{self, ConfigSchema, CONFIG_SCHEMA_FILENAME};
+use manager::service::secrets;
 use config::gconfig;
 use error::{Error, Result};
-use hcore::package::PackageInstall;
-use hcore::crypto;
+use hcore::package::{Bind as PackageBind, PackageInstall};
+use hcore::crypto::{self, default_cache_key_path};
 use package::Package;
 use util;
 use util::convert;
@@ -111,6 +114,35 @@ impl ServiceConfig {
         Ok(toml::Value::Table(top))
     }
 
+    /// Returns the keys of the merged `cfg` data the package exports, by export name. Keys which
+    /// aren't set are left out.
+    pub fn exported_cfg(&self, exports: &BTreeMap<String, String>) -> Result<toml::Table> {
+        let cfg = match try!(self.cfg.to_toml()) {
+            toml::Value::Table(cfg) => cfg,
+            _ => toml::Table::new(),
+        };
+        let mut exported = toml::Table::new();
+        for (name, path) in exports.iter() {
+            if self.cfg.is_secret(path) {
+                outputln!("Not exporting {}; {} is a secret", name, path);
+                continue;
+            }
+            match schema::lookup(&cfg, path) {
+                Some(value) => {
+                    exported.insert(name.clone(), value.clone());
+                }
+                None => debug!("Exported key {} ({}) is not set", name, path),
+            }
+        }
+        Ok(exported)
+    }
+
+    /// Returns the layer each key of the merged `cfg` data came from, by dotted path: one of
+    /// `default`, `environment`, `user` or `gossip`.
+    pub fn cfg_sources(&self) -> BTreeMap<String, String> {
+        self.cfg.sources()
+    }
+
     /// Replace the `pkg` data.
     pub fn pkg(&mut self, pkg_install: &PackageInstall) {
         self.pkg = Pkg::new(pkg_install);
@@ -142,19 +174,19 @@ impl ServiceConfig {
         }
     }
 
-    /// Write the configuration to `config.toml`, and render the templated configuration files.
-    pub fn write(&mut self, pkg: &Package) -> Result<bool> {
-        let pi = &pkg.pkg_install;
-        let final_toml = try!(self.to_toml());
-        {
-            let mut last_toml = try!(File::create(pi.svc_path().join("config.toml")));
-            try!(write!(&mut last_toml, "{}", toml::encode_str(&final_toml)));
-        }
+    /// Replace the `user` layer of the `cfg` data, as if `user.toml` held `user`.
+    pub fn user_cfg(&mut self, user: toml::Table) {
+        self.cfg.user = Some(toml::Value::Table(user));
+        self.needs_write = true
+    }
+
+    /// Render the templated configuration files, returning the contents of each by file name.
+    /// Nothing is written to disk.
+    pub fn render(&self, pkg: &Package) -> Result<Vec<(String, String)>> {
         let mut handlebars = Handlebars::new();
 
         debug!("Registering handlebars helpers");
-        handlebars.register_helper("json", Box::new(handlebars_helpers::json_helper));
-        handlebars.register_helper("toml", Box::new(handlebars_helpers::toml_helper));
+        handlebars_helpers::register(&mut handlebars);
 
         debug!("Registering configuration templates");
         // By default, handlebars escapes HTML. We don't want that.
@@ -175,11 +207,30 @@ impl ServiceConfig {
             }
         }
 
-        let final_data = convert::toml_to_json(final_toml);
-        let mut should_restart = false;
+        let final_data = convert::toml_to_json(try!(self.to_toml()));
+        let mut rendered = Vec::new();
         for config in config_files {
             debug!("Rendering template {}", &config);
             let template_data = try!(handlebars.render(&config, &final_data));
+            rendered.push((config, template_data));
+        }
+        Ok(rendered)
+    }
+
+    /// Write the configuration to `config.toml`, and render the templated configuration files.
+    pub fn write(&mut self, pkg: &Package) -> Result<bool> {
+        let pi = &pkg.pkg_install;
+        {
+            // Secrets only ever live in memory; the snapshot keeps them encrypted.
+            let mut snapshot = try!(self.to_toml());
+            if let toml::Value::Table(ref mut table) = snapshot {
+                secrets::redact(table, "cfg", &self.cfg.secrets);
+            }
+            let mut last_toml = try!(File::create(pi.svc_path().join("config.toml")));
+            try!(write!(&mut last_toml, "{}", toml::encode_str(&snapshot)));
+        }
+        let mut should_restart = false;
+        for (config, template_data) in try!(self.render(pkg)) {
             let template_hash = try!(crypto::hash::hash_string(&template_data));
             let filename = pi.svc_config_path().join(&config).to_string_lossy().into_owned();
             let file_hash = match crypto::hash::hash_file(&filename) {
@@ -216,6 +267,25 @@ impl ServiceConfig {
     }
 }
 
+/// Check that the configuration we would end up with, if `gossip` replaced the configuration
+/// gossiped so far, matches the package's schema. Packages without a schema accept anything.
+pub fn validate_gossip_config(pkg: &Package, gossip: &str) -> Result<()> {
+    let mut toml_parser = toml::Parser::new(gossip);
+    let mut gossip_toml = try!(toml_parser.parse()
+        .ok_or(sup_error!(Error::TomlParser(toml_parser.errors))));
+    try!(secrets::decrypt(&mut gossip_toml, &default_cache_key_path(None)));
+    let schema = match try!(ConfigSchema::load(pkg.config_from().join(CONFIG_SCHEMA_FILENAME))) {
+        Some(schema) => schema,
+        None => return Ok(()),
+    };
+    let mut cfg = try!(Cfg::new(pkg));
+    cfg.gossip = Some(toml::Value::Table(gossip_toml));
+    match try!(cfg.to_toml()) {
+        toml::Value::Table(ref table) => schema.validate(table),
+        _ => unreachable!(),
+    }
+}
+
 #[derive(Debug, RustcEncodable)]
 struct Bind {
     toml: toml::Table,
@@ -288,39 +358,115 @@ impl Svc {
     }
 }
 
+/// Check the `--bind` mappings a service was loaded with against the binds its package declares.
+/// Every required bind must be mapped, and if the package declares any binds, every mapping must
+/// be to one of them.
+pub fn check_bind_contract(bindings: &[String],
+                           required: &[String],
+                           optional: &[String])
+                           -> Result<()> {
+    let names: Vec<&str> = bindings.iter().filter_map(|b| b.splitn(2, ':').next()).collect();
+    let missing: Vec<String> = required.iter()
+        .filter(|r| !names.contains(&r.as_str()))
+        .cloned()
+        .collect();
+    if !missing.is_empty() {
+        return Err(sup_error!(Error::MissingRequiredBinds(missing)));
+    }
+    if required.is_empty() && optional.is_empty() {
+        return Ok(());
+    }
+    for name in names {
+        if !required.iter().any(|r| r == name) && !optional.iter().any(|o| o == name) {
+            return Err(sup_error!(Error::UnknownBind(String::from(name))));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the required binds, as `name (service.group)`, whose service group has no alive
+/// member exporting every key the bind expects yet.
+pub fn unsatisfied_binds(bindings: &[String],
+                         required: &[PackageBind],
+                         cl: &CensusList)
+                         -> Vec<String> {
+    let mut unsatisfied = Vec::new();
+    for bind in required {
+        let prefix = format!("{}:", bind.service);
+        match bindings.iter().find(|b| b.starts_with(&prefix)) {
+            Some(binding) => {
+                let service_group = &binding[prefix.len()..];
+                let satisfied = cl.get(service_group).map_or(false, |c| {
+                    c.alive_members().iter().any(|ce| ce.exports_all(&bind.exports))
+                });
+                if !satisfied {
+                    unsatisfied.push(format!("{} ({})", bind.service, service_group));
+                }
+            }
+            None => unsatisfied.push(bind.service.clone()),
+        }
+    }
+    unsatisfied
+}
+
 fn service_entry(census: &Census) -> toml::Table {
     let service = toml::Value::String(String::from(census.get_service()));
     let group = toml::Value::String(String::from(census.get_group()));
     let ident = toml::Value::String(census.get_service_group());
-    let leader = census.get_leader().map(|ce| toml::encode(ce));
+    let leader = census.get_leader().map(|ce| census_entry_toml(ce));
     let mut members: Vec<toml::Value> = Vec::new();
     let mut member_id = toml::Table::new();
     for (sg, ce) in census.iter() {
-        members.push(toml::encode(ce));
-        member_id.insert(format!("{}", sg), toml::encode(ce));
+        members.push(census_entry_toml(ce));
+        member_id.insert(format!("{}", sg), census_entry_toml(ce));
     }
+    let healthy_members: Vec<toml::Value> =
+        census.healthy_members().into_iter().map(census_entry_toml).collect();
+    let ordered_members: Vec<toml::Value> =
+        census.members_in_start_order().into_iter().map(census_entry_toml).collect();
     let mut result = toml::Table::new();
     result.insert("service".to_string(), service);
     result.insert("group".to_string(), group);
     result.insert("ident".to_string(), ident);
     if let Some(me) = census.me() {
-        let toml_me = toml::encode(me);
+        let toml_me = census_entry_toml(me);
         result.insert("me".to_string(), toml_me);
     }
     if let Some(l) = leader {
         result.insert("leader".to_string(), l);
     }
+    if let Some(holder) = census.get_lease_holder() {
+        result.insert("lease_holder".to_string(), census_entry_toml(holder));
+    }
     result.insert("members".to_string(), toml::Value::Array(members));
+    result.insert("healthy_members".to_string(),
+                  toml::Value::Array(healthy_members));
+    result.insert("ordered_members".to_string(),
+                  toml::Value::Array(ordered_members));
     result.insert("member_id".to_string(), toml::Value::Table(member_id));
     result
 }
 
+/// Encode a census entry for a template, with the configuration the member exports as a table
+/// rather than the TOML it was gossiped as.
+fn census_entry_toml(ce: &CensusEntry) -> toml::Value {
+    let mut value = toml::encode(ce);
+    if let toml::Value::Table(ref mut table) = value {
+        match ce.get_cfg() {
+            Some(cfg) => table.insert(String::from("cfg"), toml::Value::Table(cfg)),
+            None => table.remove("cfg"),
+        };
+    }
+    value
+}
+
 #[derive(Debug, RustcEncodable)]
 struct Cfg {
     default: Option<toml::Value>,
     user: Option<toml::Value>,
     gossip: Option<toml::Value>,
     environment: Option<toml::Value>,
+    secrets: BTreeMap<String, toml::Value>,
 }
 
 // Recursively merges the `other` TOML table into `me`
@@ -355,6 +501,34 @@ fn toml_merge_recurse(me: &mut toml::Table, other: &toml::Table, depth: u16) ->
     Ok(())
 }
 
+// Records `source` as the source of every key `layer` sets, the way `toml_merge` would merge it.
+fn record_sources(prefix: &str,
+                  layer: &toml::Table,
+                  source: &str,
+                  sources: &mut BTreeMap<String, String>) {
+    for (key, value) in layer.iter() {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        // A table replaces a value, and a value replaces a whole table.
+        sources.remove(&path);
+        match *value {
+            toml::Value::Table(ref table) => record_sources(&path, table, source, sources),
+            _ => {
+                let nested = format!("{}.", path);
+                let replaced: Vec<String> =
+                    sources.keys().filter(|k| k.starts_with(&nested)).cloned().collect();
+                for key in replaced {
+                    sources.remove(&key);
+                }
+                sources.insert(path, String::from(source));
+            }
+        }
+    }
+}
+
 fn is_toml_value_a_table(key: &str, table: &toml::Table) -> bool {
     match table.get(key) {
         None => return false,
@@ -374,6 +548,7 @@ impl Cfg {
             user: None,
             gossip: None,
             environment: None,
+            secrets: BTreeMap::new(),
         };
         try!(cfg.load_default(pkg));
         try!(cfg.load_user(pkg));
@@ -382,23 +557,47 @@ impl Cfg {
         Ok(cfg)
     }
 
+    /// Returns true if the key at `path`, or a table holding it, was gossiped as a secret.
+    fn is_secret(&self, path: &str) -> bool {
+        self.secrets.keys().any(|secret| {
+            path == secret || path.starts_with(&format!("{}.", secret)) ||
+            secret.starts_with(&format!("{}.", path))
+        })
+    }
+
+    /// The layers of configuration, by name, in the order they are merged; later layers win.
+    fn layers(&self) -> Vec<(&'static str, &toml::Table)> {
+        let mut layers = Vec::new();
+        for &(name, layer) in [("default", &self.default),
+                               ("environment", &self.environment),
+                               ("user", &self.user),
+                               ("gossip", &self.gossip)]
+            .iter() {
+            if let Some(toml::Value::Table(ref table)) = *layer {
+                layers.push((name, table));
+            }
+        }
+        layers
+    }
+
     fn to_toml(&self) -> Result<toml::Value> {
         let mut output_toml = toml::Table::new();
-        if let Some(toml::Value::Table(ref default_cfg)) = self.default {
-            try!(toml_merge(&mut output_toml, default_cfg));
-        }
-        if let Some(toml::Value::Table(ref env_cfg)) = self.environment {
-            try!(toml_merge(&mut output_toml, env_cfg));
-        }
-        if let Some(toml::Value::Table(ref user_cfg)) = self.user {
-            try!(toml_merge(&mut output_toml, user_cfg));
-        }
-        if let Some(toml::Value::Table(ref gossip_cfg)) = self.gossip {
-            try!(toml_merge(&mut output_toml, gossip_cfg));
+        for (_, layer) in self.layers() {
+            try!(toml_merge(&mut output_toml, layer));
         }
         Ok(toml::Value::Table(output_toml))
     }
 
+    /// Returns the name of the layer each key of the merged configuration came from, by dotted
+    /// path.
+    fn sources(&self) -> BTreeMap<String, String> {
+        let mut sources = BTreeMap::new();
+        for (name, layer) in self.layers() {
+            record_sources("", layer, name, &mut sources);
+        }
+        sources
+    }
+
     fn load_default(&mut self, pkg: &Package) -> Result<()> {
         // Default
         let mut file = match File::open(pkg.config_from().join("default.toml")) {
@@ -463,8 +662,9 @@ impl Cfg {
         match file.read_to_string(&mut config) {
             Ok(_) => {
                 let mut toml_parser = toml::Parser::new(&config);
-                let toml = try!(toml_parser.parse()
+                let mut toml = try!(toml_parser.parse()
                     .ok_or(sup_error!(Error::TomlParser(toml_parser.errors))));
+                self.secrets = try!(secrets::decrypt(&mut toml, &default_cache_key_path(None)));
                 self.gossip = Some(toml::Value::Table(toml));
             }
             Err(e) => {
@@ -663,18 +863,24 @@ impl Hab {
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
+    use std::fs::File;
+    use std::io::Write;
     use std::path::PathBuf;
     use std::str::FromStr;
 
     use regex::Regex;
+    use tempdir::TempDir;
     use toml;
 
     use error::Error;
+    use health_check::CheckResult;
     use manager::census::{CensusEntry, CensusList};
     use config::{gcache, Config};
-    use hcore::package::{PackageIdent, PackageInstall};
+    use hcore::package::{Bind, PackageIdent, PackageInstall};
     use package::Package;
-    use super::ServiceConfig;
+    use package::hooks;
+    use super::{check_bind_contract, unsatisfied_binds, Cfg, ServiceConfig};
     use VERSION;
     use super::toml_merge;
 
@@ -706,6 +912,16 @@ mod test {
         cl
     }
 
+    fn gen_census_entry(member_id: &str, health: CheckResult) -> CensusEntry {
+        let mut ce = CensusEntry::default();
+        ce.set_member_id(String::from(member_id));
+        ce.set_service(String::from("redis"));
+        ce.set_group(String::from("default"));
+        ce.set_alive(true);
+        ce.set_health_check(health);
+        ce
+    }
+
     fn toml_from_string(content: &str) -> toml::Table {
         toml::Parser::new(content)
             .parse()
@@ -746,6 +962,99 @@ mod test {
         assert!(re.is_match(&ip));
     }
 
+    #[test]
+    fn to_toml_svc_health() {
+        gcache(Config::new());
+        let pkg = gen_pkg();
+        let mut cl = CensusList::new();
+        cl.insert(String::from("a"), gen_census_entry("a", CheckResult::Ok));
+        cl.insert(String::from("b"), gen_census_entry("b", CheckResult::Critical));
+        cl.insert(String::from("c"), gen_census_entry("c", CheckResult::Warning));
+        let sc = ServiceConfig::new("redis.default", &pkg, &cl, Vec::new()).unwrap();
+        let toml = sc.to_toml().unwrap();
+        let health = toml.lookup("svc.member_id.b.health_check").unwrap().as_str().unwrap();
+        assert_eq!(health, "CRITICAL");
+        let healthy: Vec<&str> = toml.lookup("svc.healthy_members")
+            .unwrap()
+            .as_slice()
+            .unwrap()
+            .iter()
+            .map(|m| m.lookup("member_id").unwrap().as_str().unwrap())
+            .collect();
+        assert_eq!(healthy, vec!["a", "c"]);
+    }
+
+    #[test]
+    fn to_toml_svc_ordered_members() {
+        gcache(Config::new());
+        let pkg = gen_pkg();
+        let mut cl = CensusList::new();
+        let mut b = gen_census_entry("b", CheckResult::Ok);
+        b.set_suitability(5);
+        cl.insert(String::from("a"), gen_census_entry("a", CheckResult::Ok));
+        cl.insert(String::from("a"), b);
+        cl.insert(String::from("a"), gen_census_entry("c", CheckResult::Ok));
+        let sc = ServiceConfig::new("redis.default", &pkg, &cl, Vec::new()).unwrap();
+        let toml = sc.to_toml().unwrap();
+        let ordered: Vec<&str> = toml.lookup("svc.ordered_members")
+            .unwrap()
+            .as_slice()
+            .unwrap()
+            .iter()
+            .map(|m| m.lookup("member_id").unwrap().as_str().unwrap())
+            .collect();
+        assert_eq!(ordered, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn user_cfg_replaces_the_user_layer() {
+        gcache(Config::new());
+        let pkg = gen_pkg();
+        let cl = gen_census_list();
+        let mut sc = ServiceConfig::new("redis.default", &pkg, &cl, Vec::new()).unwrap();
+        sc.user_cfg(toml_from_string("port = 6380\n[tuning]\nmaxmemory = \"1gb\"\n"));
+        let toml = sc.to_toml().unwrap();
+        assert_eq!(toml.lookup("cfg.port").unwrap().as_integer(), Some(6380));
+        assert_eq!(toml.lookup("cfg.tuning.maxmemory").unwrap().as_str(), Some("1gb"));
+    }
+
+    #[test]
+    fn cfg_sources_follow_the_merge_order() {
+        let layer = |s: &str| Some(toml::Value::Table(toml_from_string(s)));
+        let cfg = Cfg {
+            default: layer("port = 6379\nbind = \"0.0.0.0\"\n[tuning]\na = 1\nb = 2\n\
+                            [limits]\nmemory = 1\n"),
+            environment: layer("[tuning]\nb = 3\n"),
+            user: layer("port = 6380\nlimits = \"none\"\n"),
+            gossip: layer("[tuning]\na = 4\n"),
+            secrets: BTreeMap::new(),
+        };
+        let sources = cfg.sources();
+        let expected: Vec<(&str, &str)> = vec![("bind", "default"),
+                                               ("limits", "user"),
+                                               ("port", "user"),
+                                               ("tuning.a", "gossip"),
+                                               ("tuning.b", "environment")];
+        assert_eq!(sources.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect::<Vec<_>>(),
+                   expected);
+    }
+
+    #[test]
+    fn hooks_render_without_being_written() {
+        gcache(Config::new());
+        let pkg = gen_pkg();
+        let cl = gen_census_list();
+        let mut sc = ServiceConfig::new("redis.default", &pkg, &cl, Vec::new()).unwrap();
+        sc.user_cfg(toml_from_string("port = 6380"));
+        let dir = TempDir::new("hooks").unwrap();
+        let template = dir.path().join("run");
+        let mut file = File::create(&template).unwrap();
+        file.write_all(b"exec redis-server --port {{cfg.port}} # {{svc.me.member_id}}")
+            .unwrap();
+        assert_eq!(hooks::render(&template, &sc).unwrap(),
+                   "exec redis-server --port 6380 # 0000000000000000000");
+    }
+
     #[test]
     fn merge_with_empty_me_table() {
         let mut me = toml_from_string("");
@@ -942,4 +1251,87 @@ mod test {
             assert_eq!(version, VERSION);
         }
     }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| String::from(*n)).collect()
+    }
+
+    #[test]
+    fn bind_contract_requires_required_binds() {
+        let required = names(&["database"]);
+        let optional = names(&["cache"]);
+        assert!(check_bind_contract(&names(&["database:postgres.default"]), &required, &optional)
+            .is_ok());
+        match check_bind_contract(&names(&["cache:redis.default"]), &required, &optional) {
+            Err(e) => {
+                match e.err {
+                    Error::MissingRequiredBinds(missing) => assert_eq!(missing, vec!["database"]),
+                    e => panic!("Unexpected error {:?}", e),
+                }
+            }
+            Ok(_) => panic!("A missing required bind should be refused"),
+        }
+    }
+
+    #[test]
+    fn bind_contract_refuses_undeclared_binds() {
+        let bindings = names(&["database:postgres.default", "queue:rabbitmq.default"]);
+        match check_bind_contract(&bindings, &names(&["database"]), &[]) {
+            Err(e) => {
+                match e.err {
+                    Error::UnknownBind(name) => assert_eq!(name, "queue"),
+                    e => panic!("Unexpected error {:?}", e),
+                }
+            }
+            Ok(_) => panic!("An undeclared bind should be refused"),
+        }
+        // Packages which don't declare binds can be bound to anything
+        assert!(check_bind_contract(&bindings, &[], &[]).is_ok());
+    }
+
+    fn bind(service: &str, exports: &[&str]) -> Bind {
+        Bind {
+            service: String::from(service),
+            exports: names(exports),
+        }
+    }
+
+    #[test]
+    fn unsatisfied_binds_need_alive_members() {
+        let bindings = names(&["database:redis.default", "cache:memcached.default"]);
+        let mut cl = CensusList::new();
+        cl.insert(String::from("0000000000000000000"),
+                  gen_census_entry("0000000000000000000", CheckResult::Ok));
+        assert!(unsatisfied_binds(&bindings, &[bind("database", &[])], &cl).is_empty());
+        assert_eq!(unsatisfied_binds(&bindings,
+                                     &[bind("database", &[]), bind("cache", &[])],
+                                     &cl),
+                   vec!["cache (memcached.default)"]);
+    }
+
+    #[test]
+    fn unsatisfied_binds_need_expected_exports() {
+        let bindings = names(&["database:redis.default"]);
+        let required = [bind("database", &["port"])];
+        let mut cl = CensusList::new();
+        let mut ce = gen_census_entry("0000000000000000000", CheckResult::Ok);
+        cl.insert(String::from("0000000000000000000"), ce.clone());
+        assert_eq!(unsatisfied_binds(&bindings, &required, &cl),
+                   vec!["database (redis.default)"]);
+        ce.set_cfg(String::from("port = 6379\n"));
+        cl.insert(String::from("0000000000000000000"), ce);
+        assert!(unsatisfied_binds(&bindings, &required, &cl).is_empty());
+    }
+
+    #[test]
+    fn exported_cfg_is_a_table_in_templates() {
+        let mut cl = CensusList::new();
+        let mut ce = gen_census_entry("0000000000000000000", CheckResult::Ok);
+        ce.set_cfg(String::from("port = 6379\n"));
+        cl.insert(String::from("0000000000000000000"), ce);
+        let census = cl.get("redis.default").unwrap();
+        let entry = super::service_entry(census);
+        let members = entry.get("members").unwrap().as_slice().unwrap();
+        assert_eq!(members[0].lookup("cfg.port").unwrap().as_integer(), Some(6379));
+    }
 }
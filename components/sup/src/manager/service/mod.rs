@@ -13,25 +13,36 @@
 // limitations under the License.
 
 pub mod config;
+pub mod restart;
+pub mod schema;
+pub mod secrets;
+pub mod spec;
 
 use std;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
+use std::path::PathBuf;
 
 use ansi_term::Colour::{Yellow, Red, Green};
+use hcore::package::Bind;
 use hcore::service::ServiceGroup;
 use hcore::crypto::hash;
 use hcore::fs;
 use hcore::util::perm::{set_owner, set_permissions};
+use toml;
 
-use config::{gconfig, UpdateStrategy, Topology};
+use config::{UpdateStrategy, Topology};
 use error::Result;
-use health_check;
+use health_check::{self, CheckResult, HealthHistory};
+use limits::ResourceLimits;
+use logs::LogFormat;
 use manager::signals;
 use manager::census::CensusList;
 use manager::service::config::ServiceConfig;
+use manager::service::restart::{RestartPolicy, RestartState, RestartTracker};
+use manager::service::spec::ServiceSpec;
 use package::Package;
 use supervisor::{Supervisor, RuntimeConfig};
 use util;
@@ -44,14 +55,36 @@ enum LastRestartDisplay {
     ElectionInProgress,
     ElectionNoQuorum,
     ElectionFinished,
+    NoLeader,
+    WaitingForBinds,
+    WaitingForTurn,
+}
+
+/// The state an operator has asked a service to be in. The manager will only start or restart a
+/// service whose desired state is `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable)]
+pub enum DesiredState {
+    Up,
+    Down,
 }
 
 #[derive(Debug, RustcEncodable)]
 pub struct Service {
+    pub binds: Vec<String>,
+    pub required_binds: Vec<Bind>,
+    pub unsatisfied_binds: Vec<String>,
+    pub exports: BTreeMap<String, String>,
+    pub exported_cfg: Option<String>,
+    pub cfg_sources: BTreeMap<String, String>,
+    pub desired_state: DesiredState,
+    pub health_history: HealthHistory,
     pub needs_restart: bool,
     pub package: Package,
+    pub restart_policy: RestartPolicy,
+    pub restarts: RestartTracker,
     pub service_config_incarnation: Option<u64>,
     pub service_group: ServiceGroup,
+    pub suitability: Option<u64>,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
     pub current_service_files: HashMap<String, u64>,
@@ -61,31 +94,60 @@ pub struct Service {
 }
 
 impl Service {
-    pub fn new<S: Into<String>>(package: Package,
-                                group: S,
-                                organization: Option<String>,
-                                topology: Topology,
-                                update_strategy: UpdateStrategy)
-                                -> Result<Service> {
-        let service_group = ServiceGroup::new(package.name.clone(), group, organization);
+    pub fn new(package: Package, spec: &ServiceSpec, member_id: &str) -> Result<Service> {
+        let service_group = ServiceGroup::new(package.name.clone(),
+                                              spec.group.clone(),
+                                              spec.organization.clone());
         let (svc_user, svc_group) = try!(util::users::get_user_and_group(&package.pkg_install));
         let sg = format!("{}.{}", service_group.service, service_group.group);
         outputln!(preamble sg, "Process will run as user={}, group={}",
                   &svc_user,
                   &svc_group);
-        let runtime_config = RuntimeConfig::new(svc_user, svc_group);
-        let supervisor = Supervisor::new(package.ident().clone(), &service_group, runtime_config);
+        let required_binds = try!(package.pkg_install.binds());
+        let required: Vec<String> = required_binds.iter().map(|b| b.service.clone()).collect();
+        let optional: Vec<String> = try!(package.pkg_install.binds_optional())
+            .into_iter()
+            .map(|b| b.service)
+            .collect();
+        try!(config::check_bind_contract(&spec.binds, &required, &optional));
+        let exports = try!(package.pkg_install.exports());
+        let plan_limits = try!(ResourceLimits::parse(&try!(package.pkg_install.svc_limits())));
+        let limits = plan_limits.merge(&spec.limits);
+        if !limits.is_empty() {
+            outputln!(preamble sg, "Process will run with limits {}", limits);
+        }
+        let runtime_config = RuntimeConfig::new(svc_user,
+                                                svc_group,
+                                                spec.stop_signal,
+                                                spec.stop_timeout,
+                                                limits,
+                                                spec.log.clone());
+        let supervisor = Supervisor::new(package.ident().clone(),
+                                         &service_group,
+                                         member_id,
+                                         runtime_config);
         Ok(Service {
+            binds: spec.binds.clone(),
+            required_binds: required_binds,
+            unsatisfied_binds: Vec::new(),
+            exports: exports,
+            exported_cfg: None,
+            cfg_sources: BTreeMap::new(),
+            desired_state: DesiredState::Up,
+            health_history: HealthHistory::new(),
             service_group: service_group,
             supervisor: supervisor,
             package: package,
-            topology: topology,
+            restart_policy: spec.restart_policy,
+            restarts: RestartTracker::new(spec.max_restarts, spec.restart_window),
+            topology: spec.topology,
             needs_restart: false,
-            update_strategy: update_strategy,
+            update_strategy: spec.update_strategy,
             current_service_files: HashMap::new(),
             last_restart_display: LastRestartDisplay::None,
             initialized: false,
             service_config_incarnation: None,
+            suitability: None,
         })
     }
 
@@ -94,10 +156,24 @@ impl Service {
     }
 
     pub fn start(&mut self) -> Result<()> {
-        self.supervisor.start()
+        try!(self.supervisor.start());
+        self.post_run();
+        Ok(())
     }
 
     pub fn restart(&mut self, census_list: &CensusList) -> Result<()> {
+        self.unsatisfied_binds =
+            config::unsatisfied_binds(&self.binds, &self.required_binds, census_list);
+        if !self.unsatisfied_binds.is_empty() {
+            if self.last_restart_display != LastRestartDisplay::WaitingForBinds {
+                outputln!(preamble self.service_group_str(),
+                          "Not starting service; {}",
+                          Yellow.bold().paint(format!("waiting for binds {}",
+                                                      self.unsatisfied_binds.join(", "))));
+                self.last_restart_display = LastRestartDisplay::WaitingForBinds;
+            }
+            return Ok(());
+        }
         match self.topology {
             Topology::Leader | Topology::Initializer => {
                 if let Some(census) = census_list.get(&format!("{}.{}",
@@ -131,20 +207,134 @@ impl Service {
                             self.last_restart_display = LastRestartDisplay::ElectionFinished;
                         }
                         self.needs_restart = false;
-                        try!(self.supervisor.restart());
+                        try!(self.restart_process());
+                        self.restarts.started();
+                    }
+                }
+            }
+            Topology::Lease => {
+                if let Some(census) = census_list.get(&self.service_group.as_string()) {
+                    let me = census.me().unwrap();
+                    // A lease held through an election keeps the group running; only a group
+                    // which has never had a leader waits for its first election to finish.
+                    if me.get_election_is_running() && census.get_leader().is_none() {
+                        if self.last_restart_display != LastRestartDisplay::ElectionInProgress {
+                            outputln!(preamble self.service_group_str(),
+                                      "Not restarting service; {}",
+                                      Yellow.bold().paint("election in progress."));
+                            self.last_restart_display = LastRestartDisplay::ElectionInProgress;
+                        }
+                        return Ok(());
+                    }
+                    match census.get_leader() {
+                        Some(leader) => {
+                            if self.last_restart_display != LastRestartDisplay::ElectionFinished {
+                                outputln!(preamble self.service_group_str(),
+                                          "Restarting service; {} is the leader",
+                                          Green.bold().paint(leader.get_member_id()));
+                                self.last_restart_display = LastRestartDisplay::ElectionFinished;
+                            }
+                        }
+                        None => {
+                            if self.last_restart_display != LastRestartDisplay::NoLeader {
+                                outputln!(preamble self.service_group_str(),
+                                          "Restarting service; {}",
+                                          Yellow.bold().paint("no member holds the leader \
+                                                               lease"));
+                                self.last_restart_display = LastRestartDisplay::NoLeader;
+                            }
+                        }
+                    }
+                    self.needs_restart = false;
+                    try!(self.restart_process());
+                    self.restarts.started();
+                }
+            }
+            Topology::Ordered => {
+                if let Some(census) = census_list.get(&self.service_group.as_string()) {
+                    let me = census.me().unwrap();
+                    // Once we have started, the order no longer matters; we come back up as soon
+                    // as we can.
+                    if !me.get_initialized() {
+                        if let Some(ahead) = census.waiting_on() {
+                            if self.last_restart_display != LastRestartDisplay::WaitingForTurn {
+                                outputln!(preamble self.service_group_str(),
+                                          "Not starting service; {}",
+                                          Yellow.bold().paint(format!("waiting for {} to start",
+                                                                      ahead.get_member_id())));
+                                self.last_restart_display = LastRestartDisplay::WaitingForTurn;
+                            }
+                            return Ok(());
+                        }
                     }
+                    self.needs_restart = false;
+                    try!(self.restart_process());
+                    self.restarts.started();
                 }
             }
             Topology::Standalone => {
                 self.needs_restart = false;
-                try!(self.supervisor.restart());
+                try!(self.restart_process());
+                self.restarts.started();
             }
         }
         Ok(())
     }
 
+    /// Returns true if the manager should start or restart the service: it is wanted up, and
+    /// either its configuration changed or its process is down and its restart policy allows
+    /// bringing it back.
+    pub fn should_restart(&self) -> bool {
+        if !self.initialized || self.desired_state != DesiredState::Up {
+            return false;
+        }
+        if self.is_down() {
+            self.restarts.may_start()
+        } else {
+            self.needs_restart
+        }
+    }
+
+    /// Restart the process, running the stop hooks around stopping it if it is running, and the
+    /// post-run hook once it is back up.
+    fn restart_process(&mut self) -> Result<()> {
+        if !self.is_down() {
+            self.pre_stop();
+            try!(self.supervisor.stop());
+            self.post_stop();
+        }
+        try!(self.supervisor.restart());
+        self.post_run();
+        Ok(())
+    }
+
     pub fn down(&mut self) -> Result<()> {
-        self.supervisor.down()
+        if self.is_down() {
+            return self.supervisor.down();
+        }
+        self.pre_stop();
+        try!(self.supervisor.down());
+        self.post_stop();
+        Ok(())
+    }
+
+    /// Stop the service and keep it stopped until it is explicitly started again.
+    pub fn stop(&mut self) -> Result<()> {
+        outputln!(preamble self.service_group_str(), "Stopping service on request");
+        self.desired_state = DesiredState::Down;
+        self.down()
+    }
+
+    /// Mark the service as wanted again. If it has been initialized and isn't running, it is
+    /// started right away; otherwise the manager picks it up on its next pass.
+    pub fn up(&mut self, census_list: &CensusList) -> Result<()> {
+        self.desired_state = DesiredState::Up;
+        self.restarts.reset();
+        if self.initialized && self.is_down() {
+            self.restart(census_list)
+        } else {
+            Ok(())
+        }
     }
 
     pub fn send_signal(&self, signal: u32) -> Result<()> {
@@ -161,8 +351,49 @@ impl Service {
         self.supervisor.child.is_none()
     }
 
+    /// Returns the pid of the service's process, if it is running.
+    pub fn pid(&self) -> Option<u32> {
+        self.supervisor.child.as_ref().map(|child| child.id())
+    }
+
+    /// Returns the directory the service's log is written to.
+    pub fn log_dir(&self) -> PathBuf {
+        self.supervisor.log_dir()
+    }
+
+    /// Returns the format the service's log is written in.
+    pub fn log_format(&self) -> LogFormat {
+        self.supervisor.runtime_config.log.format
+    }
+
+    /// Reap the process if it has exited, and apply the restart policy if it exited on its own.
     pub fn check_process(&mut self) -> Result<()> {
-        self.supervisor.check_process()
+        if !try!(self.supervisor.check_process()) {
+            return Ok(());
+        }
+        let success = self.supervisor.last_exit_code == Some(0);
+        match self.restarts.process_exited(self.restart_policy, success) {
+            RestartState::Backoff => {
+                let wait = self.restarts.next_attempt_in().map_or(0, |d| d.num_milliseconds());
+                outputln!(preamble self.service_group_str(),
+                          "Restarting in {}ms ({} restarts so far)",
+                          wait,
+                          self.restarts.count());
+            }
+            RestartState::Failed => {
+                outputln!(preamble self.service_group_str(),
+                          "{}; it exited too many times, and will not be restarted until it \
+                           is started again by hand",
+                          Red.bold().paint("Service failed"));
+            }
+            RestartState::Stopped => {
+                outputln!(preamble self.service_group_str(),
+                          "Not restarting; restart policy is {}",
+                          self.restart_policy);
+            }
+            RestartState::Running => {}
+        }
+        Ok(())
     }
 
     pub fn write_butterfly_service_file(&mut self,
@@ -244,6 +475,12 @@ impl Service {
     }
 
 
+    /// Check a configuration gossiped to the service group against the package's schema, before
+    /// we apply it.
+    pub fn validate_butterfly_service_config(&self, config: &str) -> Result<()> {
+        config::validate_gossip_config(&self.package, config)
+    }
+
     pub fn write_butterfly_service_config(&mut self, config: String) -> bool {
         let on_disk_path = fs::svc_path(&self.service_group.service).join("gossip.toml");
         let current_checksum = match hash::hash_file(&on_disk_path) {
@@ -314,6 +551,66 @@ impl Service {
         self.package.health_check(&self.supervisor, &self.service_group)
     }
 
+    /// Run the health check. A check which fails to run leaves the service's health unknown.
+    pub fn check_health(&self) -> CheckResult {
+        match self.health_check() {
+            Ok(result) => result,
+            Err(e) => {
+                outputln!(preamble self.service_group_str(), "Health check failed: {}", e);
+                CheckResult::Unknown
+            }
+        }
+    }
+
+    /// Record the result of a health check in the service's history. Returns the result if it
+    /// differs from the previous one, so the caller can let the rest of the ring know.
+    pub fn record_health(&mut self, result: CheckResult) -> Option<CheckResult> {
+        let was_flapping = self.health_history.is_flapping();
+        let changed = self.health_history.push(result);
+        if changed {
+            outputln!(preamble self.service_group_str(), "Health is now {}", result);
+        }
+        if self.health_history.is_flapping() && !was_flapping {
+            outputln!(preamble self.service_group_str(),
+                      "Health is {}",
+                      Yellow.bold().paint("flapping"));
+        } else if was_flapping && !self.health_history.is_flapping() {
+            outputln!(preamble self.service_group_str(),
+                      "Health is {}",
+                      Green.bold().paint("no longer flapping"));
+        }
+        if changed { Some(result) } else { None }
+    }
+
+    /// Run the suitability hook. Packages without the hook are all equally suitable, at 0.
+    /// Returns `None` if the hook failed.
+    pub fn check_suitability(&self) -> Option<u64> {
+        match self.package.suitability(&self.service_group) {
+            Ok(suitability) => Some(suitability.unwrap_or(0)),
+            Err(e) => {
+                outputln!(preamble self.service_group_str(), "Suitability hook failed: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Record the outcome of the suitability hook, returning the suitability if it differs from
+    /// the one we last declared. A failed hook keeps the suitability we declared, or declares 0
+    /// if we have yet to declare one.
+    pub fn record_suitability(&mut self, suitability: Option<u64>) -> Option<u64> {
+        let suitability = match suitability {
+            Some(suitability) => suitability,
+            None if self.suitability.is_some() => return None,
+            None => 0,
+        };
+        if self.suitability == Some(suitability) {
+            return None;
+        }
+        outputln!(preamble self.service_group_str(), "Suitability is now {}", suitability);
+        self.suitability = Some(suitability);
+        Some(suitability)
+    }
+
     pub fn file_updated(&self) {
         if self.initialized {
             match self.package.file_updated(&self.service_group) {
@@ -339,10 +636,28 @@ impl Service {
         }
     }
 
+    fn post_run(&self) {
+        if let Err(e) = self.package.post_run(&self.service_group) {
+            outputln!(preamble self.service_group_str(), "Post-run hook failed: {}", e);
+        }
+    }
+
+    fn pre_stop(&self) {
+        if let Err(e) = self.package.pre_stop(&self.service_group) {
+            outputln!(preamble self.service_group_str(), "Pre-stop hook failed: {}", e);
+        }
+    }
+
+    fn post_stop(&self) {
+        if let Err(e) = self.package.post_stop(&self.service_group) {
+            outputln!(preamble self.service_group_str(), "Post-stop hook failed: {}", e);
+        }
+    }
+
     pub fn reconfigure(&mut self, census_list: &CensusList) {
         let sg = format!("{}", self.service_group);
         let mut service_config =
-            match ServiceConfig::new(&sg, &self.package, census_list, gconfig().bind()) {
+            match ServiceConfig::new(&sg, &self.package, census_list, self.binds.clone()) {
                 Ok(sc) => sc,
                 Err(e) => {
                     outputln!(preamble self.service_group_str(),
@@ -351,6 +666,19 @@ impl Service {
                     return;
                 }
             };
+        self.cfg_sources = service_config.cfg_sources();
+        if !self.exports.is_empty() {
+            match service_config.exported_cfg(&self.exports) {
+                Ok(exported) => {
+                    self.exported_cfg = Some(toml::encode_str(&toml::Value::Table(exported)));
+                }
+                Err(e) => {
+                    outputln!(preamble self.service_group_str(),
+                              "Failed to export configuration: {}",
+                              e);
+                }
+            }
+        }
         match self.package.create_svc_path() {
             Ok(_) => {}
             Err(e) => outputln!("Failed to create the svc path: {}", e),
@@ -0,0 +1,297 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decides whether, and when, a service whose process exited on its own is started again.
+//!
+//! Every exit is checked against the service's `RestartPolicy`. Restarts are delayed by an
+//! exponential backoff, and a service which has to be restarted more than `max_restarts` times
+//! within the restart window is marked as failed and left down until an operator starts it again.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::result;
+
+use rustc_serialize::{Encodable, Encoder};
+use time::{Duration, SteadyTime};
+
+/// The number of restarts allowed within the restart window before a service is marked failed.
+pub const DEFAULT_MAX_RESTARTS: u32 = 5;
+
+/// The length of the restart window, in seconds.
+pub const DEFAULT_RESTART_WINDOW: u64 = 300;
+
+/// How long we wait before the first restart, in milliseconds. Every further restart within the
+/// window doubles it.
+const BASE_BACKOFF_MS: i64 = 1_000;
+
+/// The longest we will ever wait before a restart, in milliseconds.
+const MAX_BACKOFF_MS: i64 = 60_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl RestartPolicy {
+    pub fn from_str(policy: &str) -> Self {
+        match policy {
+            "always" => RestartPolicy::Always,
+            "on-failure" => RestartPolicy::OnFailure,
+            "never" => RestartPolicy::Never,
+            p => panic!("Invalid restart policy {}", p),
+        }
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let policy = match *self {
+            RestartPolicy::Always => "always",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Never => "never",
+        };
+        write!(f, "{}", policy)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable)]
+pub enum RestartState {
+    /// Nothing is holding the service back from being started.
+    Running,
+    /// The process exited, and we are waiting out the backoff before starting it again.
+    Backoff,
+    /// The process exited too many times within the window; we have given up on it.
+    Failed,
+    /// The process exited, and the restart policy says it stays down.
+    Stopped,
+}
+
+impl fmt::Display for RestartState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match *self {
+            RestartState::Running => "running",
+            RestartState::Backoff => "backoff",
+            RestartState::Failed => "failed",
+            RestartState::Stopped => "stopped",
+        };
+        write!(f, "{}", state)
+    }
+}
+
+#[derive(Debug)]
+pub struct RestartTracker {
+    max_restarts: u32,
+    window: Duration,
+    recent: VecDeque<SteadyTime>,
+    count: u64,
+    state: RestartState,
+    next_attempt: Option<SteadyTime>,
+}
+
+impl RestartTracker {
+    pub fn new(max_restarts: u32, window: u64) -> Self {
+        RestartTracker {
+            max_restarts: max_restarts,
+            window: Duration::seconds(window as i64),
+            recent: VecDeque::new(),
+            count: 0,
+            state: RestartState::Running,
+            next_attempt: None,
+        }
+    }
+
+    /// Record that the process exited without being asked to, and decide what happens next.
+    pub fn process_exited(&mut self, policy: RestartPolicy, success: bool) -> RestartState {
+        self.process_exited_at(policy, success, SteadyTime::now())
+    }
+
+    fn process_exited_at(&mut self,
+                         policy: RestartPolicy,
+                         success: bool,
+                         now: SteadyTime)
+                         -> RestartState {
+        self.next_attempt = None;
+        let restart = match policy {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => !success,
+            RestartPolicy::Never => false,
+        };
+        if !restart {
+            self.state = RestartState::Stopped;
+            return self.state;
+        }
+        let window_start = now - self.window;
+        while self.recent.front().map_or(false, |t| *t < window_start) {
+            self.recent.pop_front();
+        }
+        if self.recent.len() as u32 >= self.max_restarts {
+            self.state = RestartState::Failed;
+            return self.state;
+        }
+        let backoff = backoff_for(self.recent.len());
+        self.recent.push_back(now);
+        self.count += 1;
+        self.next_attempt = Some(now + backoff);
+        self.state = RestartState::Backoff;
+        self.state
+    }
+
+    /// Returns true if the service may be started now.
+    pub fn may_start(&self) -> bool {
+        self.may_start_at(SteadyTime::now())
+    }
+
+    fn may_start_at(&self, now: SteadyTime) -> bool {
+        match self.state {
+            RestartState::Running => true,
+            RestartState::Backoff => self.next_attempt.map_or(true, |t| now >= t),
+            RestartState::Failed | RestartState::Stopped => false,
+        }
+    }
+
+    /// Record that the service has been started again.
+    pub fn started(&mut self) {
+        if self.state == RestartState::Backoff {
+            self.state = RestartState::Running;
+            self.next_attempt = None;
+        }
+    }
+
+    /// Forget about past restarts; used when an operator starts the service by hand.
+    pub fn reset(&mut self) {
+        self.recent.clear();
+        self.state = RestartState::Running;
+        self.next_attempt = None;
+    }
+
+    /// Returns how long we will wait before the next restart.
+    pub fn next_attempt_in(&self) -> Option<Duration> {
+        self.next_attempt.map(|t| t - SteadyTime::now())
+    }
+
+    pub fn state(&self) -> RestartState {
+        self.state
+    }
+
+    /// Returns the total number of times the process has been restarted after exiting.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl Encodable for RestartTracker {
+    fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
+        let next_attempt_ms = self.next_attempt_in().map(|d| d.num_milliseconds());
+        try!(s.emit_struct("restarts", 6, |s| {
+            try!(s.emit_struct_field("state", 0, |s| self.state.encode(s)));
+            try!(s.emit_struct_field("count", 1, |s| self.count.encode(s)));
+            try!(s.emit_struct_field("recent", 2, |s| self.recent.len().encode(s)));
+            try!(s.emit_struct_field("max_restarts", 3, |s| self.max_restarts.encode(s)));
+            try!(s.emit_struct_field("window", 4, |s| self.window.num_seconds().encode(s)));
+            try!(s.emit_struct_field("next_attempt_ms", 5, |s| next_attempt_ms.encode(s)));
+            Ok(())
+        }));
+        Ok(())
+    }
+}
+
+fn backoff_for(restarts: usize) -> Duration {
+    // Anything past 2^6 seconds is over the cap anyway, and this keeps the shift in range.
+    let factor = 1i64 << ::std::cmp::min(restarts, 6);
+    Duration::milliseconds(::std::cmp::min(BASE_BACKOFF_MS * factor, MAX_BACKOFF_MS))
+}
+
+#[cfg(test)]
+mod test {
+    use time::{Duration, SteadyTime};
+
+    use super::{backoff_for, RestartPolicy, RestartState, RestartTracker, MAX_BACKOFF_MS};
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        assert_eq!(backoff_for(0), Duration::seconds(1));
+        assert_eq!(backoff_for(1), Duration::seconds(2));
+        assert_eq!(backoff_for(3), Duration::seconds(8));
+        assert_eq!(backoff_for(100), Duration::milliseconds(MAX_BACKOFF_MS));
+    }
+
+    #[test]
+    fn restart_waits_for_the_backoff() {
+        let now = SteadyTime::now();
+        let mut tracker = RestartTracker::new(5, 300);
+        assert!(tracker.may_start_at(now));
+        assert_eq!(tracker.process_exited_at(RestartPolicy::Always, false, now),
+                   RestartState::Backoff);
+        assert!(!tracker.may_start_at(now));
+        assert!(tracker.may_start_at(now + Duration::seconds(1)));
+        tracker.started();
+        assert_eq!(tracker.state(), RestartState::Running);
+        assert_eq!(tracker.count(), 1);
+    }
+
+    #[test]
+    fn on_failure_leaves_clean_exits_down() {
+        let now = SteadyTime::now();
+        let mut tracker = RestartTracker::new(5, 300);
+        assert_eq!(tracker.process_exited_at(RestartPolicy::OnFailure, true, now),
+                   RestartState::Stopped);
+        assert!(!tracker.may_start_at(now + Duration::seconds(600)));
+        let mut tracker = RestartTracker::new(5, 300);
+        assert_eq!(tracker.process_exited_at(RestartPolicy::OnFailure, false, now),
+                   RestartState::Backoff);
+    }
+
+    #[test]
+    fn never_leaves_every_exit_down() {
+        let mut tracker = RestartTracker::new(5, 300);
+        assert_eq!(tracker.process_exited_at(RestartPolicy::Never, false, SteadyTime::now()),
+                   RestartState::Stopped);
+    }
+
+    #[test]
+    fn too_many_restarts_in_the_window_fails() {
+        let now = SteadyTime::now();
+        let mut tracker = RestartTracker::new(2, 300);
+        tracker.process_exited_at(RestartPolicy::Always, false, now);
+        tracker.process_exited_at(RestartPolicy::Always, false, now + Duration::seconds(10));
+        assert_eq!(tracker.process_exited_at(RestartPolicy::Always,
+                                             false,
+                                             now + Duration::seconds(20)),
+                   RestartState::Failed);
+        assert!(!tracker.may_start_at(now + Duration::seconds(600)));
+        tracker.reset();
+        assert!(tracker.may_start_at(now + Duration::seconds(20)));
+    }
+
+    #[test]
+    fn restarts_outside_the_window_are_forgotten() {
+        let now = SteadyTime::now();
+        let mut tracker = RestartTracker::new(2, 60);
+        tracker.process_exited_at(RestartPolicy::Always, false, now);
+        tracker.process_exited_at(RestartPolicy::Always, false, now + Duration::seconds(10));
+        assert_eq!(tracker.process_exited_at(RestartPolicy::Always,
+                                             false,
+                                             now + Duration::seconds(120)),
+                   RestartState::Backoff);
+        assert_eq!(tracker.count(), 3);
+    }
+}
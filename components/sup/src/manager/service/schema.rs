@@ -0,0 +1,325 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schemas for service configuration.
+//!
+//! A package can ship a `config_schema.toml` next to its `default.toml`, describing the keys of
+//! its configuration. Every table with a `type` describes the key at its path; any other table
+//! is a namespace for the keys below it:
+//!
+//! ```toml
+//! [port]
+//! type = "integer"
+//! required = true
+//! min = 1
+//! max = 65535
+//!
+//! [log.level]
+//! type = "string"
+//! enum = ["debug", "info", "warn", "error"]
+//! ```
+//!
+//! Keys which are not described by the schema are not checked.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use toml;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "CS";
+
+/// The name of the schema file, in the package's config directory.
+pub const CONFIG_SCHEMA_FILENAME: &'static str = "config_schema.toml";
+
+const VALUE_TYPES: &'static [&'static str] = &["string", "integer", "float", "boolean",
+                                               "array", "table"];
+
+#[derive(Debug, Clone, PartialEq)]
+struct KeySchema {
+    value_type: String,
+    required: bool,
+    allowed: Option<Vec<toml::Value>>,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSchema {
+    keys: BTreeMap<String, KeySchema>,
+}
+
+impl ConfigSchema {
+    /// Read the schema at `path`. A package without a schema isn't an error; it just has nothing
+    /// to check.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<ConfigSchema>> {
+        let mut file = match File::open(path.as_ref()) {
+            Ok(file) => file,
+            Err(e) => {
+                debug!("Failed to open {}: {}", path.as_ref().display(), e);
+                return Ok(None);
+            }
+        };
+        let mut contents = String::new();
+        try!(file.read_to_string(&mut contents));
+        ConfigSchema::parse(&contents).map(Some)
+    }
+
+    pub fn parse(contents: &str) -> Result<ConfigSchema> {
+        let mut toml_parser = toml::Parser::new(contents);
+        let toml = try!(toml_parser.parse()
+            .ok_or(sup_error!(Error::TomlParser(toml_parser.errors))));
+        ConfigSchema::from_toml(&toml)
+    }
+
+    pub fn from_toml(table: &toml::Table) -> Result<ConfigSchema> {
+        let mut schema = ConfigSchema { keys: BTreeMap::new() };
+        try!(schema.add_keys("", table));
+        Ok(schema)
+    }
+
+    fn add_keys(&mut self, prefix: &str, table: &toml::Table) -> Result<()> {
+        for (name, value) in table.iter() {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            let definition = match value.as_table() {
+                Some(definition) => definition,
+                None => {
+                    return Err(sup_error!(Error::ConfigSchemaInvalid(format!("{} must be a \
+                                                                              table",
+                                                                             path))))
+                }
+            };
+            if definition.contains_key("type") {
+                let key = try!(key_schema(&path, definition));
+                self.keys.insert(path, key);
+            } else {
+                try!(self.add_keys(&path, definition));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a configuration against the schema, returning every problem found.
+    pub fn validate(&self, cfg: &toml::Table) -> Result<()> {
+        let mut errors = Vec::new();
+        for (path, key) in self.keys.iter() {
+            match lookup(cfg, path) {
+                Some(value) => key.check(path, value, &mut errors),
+                None => {
+                    if key.required {
+                        errors.push(format!("{} is required", path));
+                    }
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(sup_error!(Error::ConfigInvalid(errors)))
+        }
+    }
+}
+
+impl KeySchema {
+    fn check(&self, path: &str, value: &toml::Value, errors: &mut Vec<String>) {
+        let type_matches = match (self.value_type.as_str(), value) {
+            ("float", &toml::Value::Integer(_)) => true,
+            (expected, value) => expected == value.type_str(),
+        };
+        if !type_matches {
+            errors.push(format!("{} must be of type {}, not {}",
+                                path,
+                                self.value_type,
+                                value.type_str()));
+            return;
+        }
+        if let Some(ref allowed) = self.allowed {
+            if !allowed.contains(value) {
+                let allowed: Vec<String> = allowed.iter().map(|v| v.to_string()).collect();
+                errors.push(format!("{} must be one of {}, not {}",
+                                    path,
+                                    allowed.join(", "),
+                                    value));
+            }
+        }
+        let number = match *value {
+            toml::Value::Integer(i) => Some(i as f64),
+            toml::Value::Float(f) => Some(f),
+            _ => None,
+        };
+        if let Some(number) = number {
+            if let Some(min) = self.min {
+                if number < min {
+                    errors.push(format!("{} must be at least {}, not {}", path, min, value));
+                }
+            }
+            if let Some(max) = self.max {
+                if number > max {
+                    errors.push(format!("{} must be at most {}, not {}", path, max, value));
+                }
+            }
+        }
+    }
+}
+
+fn key_schema(path: &str, definition: &toml::Table) -> Result<KeySchema> {
+    let value_type = match definition.get("type").and_then(|t| t.as_str()) {
+        Some(t) if VALUE_TYPES.contains(&t) => String::from(t),
+        _ => {
+            return Err(sup_error!(Error::ConfigSchemaInvalid(format!("{} must have a type, one \
+                                                                      of {}",
+                                                                     path,
+                                                                     VALUE_TYPES.join(", ")))))
+        }
+    };
+    let required = match definition.get("required") {
+        Some(&toml::Value::Boolean(required)) => required,
+        Some(_) => {
+            return Err(sup_error!(Error::ConfigSchemaInvalid(format!("{}.required must be a \
+                                                                      boolean",
+                                                                     path))))
+        }
+        None => false,
+    };
+    let allowed = match definition.get("enum") {
+        Some(&toml::Value::Array(ref values)) => Some(values.clone()),
+        Some(_) => {
+            return Err(sup_error!(Error::ConfigSchemaInvalid(format!("{}.enum must be an array",
+                                                                     path))))
+        }
+        None => None,
+    };
+    let min = try!(bound(path, "min", definition));
+    let max = try!(bound(path, "max", definition));
+    if (min.is_some() || max.is_some()) && value_type != "integer" && value_type != "float" {
+        return Err(sup_error!(Error::ConfigSchemaInvalid(format!("{} can only have a min or \
+                                                                  max if it is a number",
+                                                                 path))));
+    }
+    Ok(KeySchema {
+        value_type: value_type,
+        required: required,
+        allowed: allowed,
+        min: min,
+        max: max,
+    })
+}
+
+fn bound(path: &str, name: &str, definition: &toml::Table) -> Result<Option<f64>> {
+    match definition.get(name) {
+        Some(&toml::Value::Integer(i)) => Ok(Some(i as f64)),
+        Some(&toml::Value::Float(f)) => Ok(Some(f)),
+        Some(_) => {
+            Err(sup_error!(Error::ConfigSchemaInvalid(format!("{}.{} must be a number",
+                                                              path,
+                                                              name))))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Find the value at a dotted path in a configuration.
+pub fn lookup<'a>(cfg: &'a toml::Table, path: &str) -> Option<&'a toml::Value> {
+    let mut parts = path.split('.');
+    let mut value = match parts.next() {
+        Some(first) => cfg.get(first),
+        None => None,
+    };
+    for part in parts {
+        value = match value {
+            Some(&toml::Value::Table(ref table)) => table.get(part),
+            _ => None,
+        };
+    }
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use toml;
+
+    use error::{Error, SupError};
+    use super::ConfigSchema;
+
+    const SCHEMA: &'static str = r#"
+        [port]
+        type = "integer"
+        required = true
+        min = 1
+        max = 65535
+
+        [ratio]
+        type = "float"
+
+        [log.level]
+        type = "string"
+        enum = ["debug", "info"]
+    "#;
+
+    fn cfg(contents: &str) -> toml::Table {
+        toml::Parser::new(contents).parse().unwrap()
+    }
+
+    fn errors_for(contents: &str) -> Vec<String> {
+        let schema = ConfigSchema::parse(SCHEMA).unwrap();
+        match schema.validate(&cfg(contents)) {
+            Ok(()) => Vec::new(),
+            Err(SupError { err: Error::ConfigInvalid(errors), .. }) => errors,
+            Err(e) => panic!("Unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        let errors = errors_for("port = 6379\nratio = 1\n[log]\nlevel = \"info\"\nextra = true\n");
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn missing_required_key_fails() {
+        assert_eq!(errors_for("ratio = 0.5\n"), vec!["port is required"]);
+    }
+
+    #[test]
+    fn wrong_type_fails() {
+        assert_eq!(errors_for("port = \"6379\"\n"),
+                   vec!["port must be of type integer, not string"]);
+    }
+
+    #[test]
+    fn out_of_range_fails() {
+        assert_eq!(errors_for("port = 70000\n"),
+                   vec!["port must be at most 65535, not 70000"]);
+    }
+
+    #[test]
+    fn value_outside_enum_fails() {
+        let errors = errors_for("port = 80\n[log]\nlevel = \"trace\"\n");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("log.level must be one of"));
+    }
+
+    #[test]
+    fn schema_without_type_is_rejected() {
+        assert!(ConfigSchema::parse("[port]\nrequired = true\n").is_err());
+        assert!(ConfigSchema::parse("[name]\ntype = \"string\"\nmin = 1\n").is_err());
+    }
+}
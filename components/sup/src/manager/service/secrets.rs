@@ -0,0 +1,192 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets in gossiped service configuration.
+//!
+//! `hab config apply --secret` encrypts individual keys of a configuration to the service group's
+//! box key. Each encrypted value is replaced by a table holding only the encrypted TOML of the
+//! value:
+//!
+//! ```toml
+//! [db]
+//! user = "app"
+//! password = { secret = "BOX-1\n..." }
+//! ```
+//!
+//! The configuration is gossiped, and written to `gossip.toml`, with its secrets still encrypted.
+//! The supervisor decrypts them in memory when it loads the configuration, so templates see the
+//! plain values, and puts the encrypted values back before it writes the `config.toml` snapshot.
+
+use std::collections::BTreeMap;
+use std::mem;
+use std::path::Path;
+
+use hcore::crypto::BoxKeyPair;
+use toml;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "SS";
+
+/// The only key of a table holding a secret.
+pub const SECRET_KEY: &'static str = "secret";
+
+/// Decrypt every secret in a configuration in place, returning the encrypted value of each secret
+/// by its dotted path.
+pub fn decrypt<P: AsRef<Path> + ?Sized>(table: &mut toml::Table,
+                                        cache_key_path: &P)
+                                        -> Result<BTreeMap<String, toml::Value>> {
+    let mut secrets = BTreeMap::new();
+    try!(decrypt_table("", table, cache_key_path.as_ref(), &mut secrets));
+    Ok(secrets)
+}
+
+fn decrypt_table(prefix: &str,
+                 table: &mut toml::Table,
+                 cache_key_path: &Path,
+                 secrets: &mut BTreeMap<String, toml::Value>)
+                 -> Result<()> {
+    for (name, value) in table.iter_mut() {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        let payload = match *value {
+            toml::Value::Table(ref t) => secret_payload(t),
+            _ => None,
+        };
+        match payload {
+            Some(payload) => {
+                let decrypted = try!(decrypt_value(&path, &payload, cache_key_path));
+                secrets.insert(path, mem::replace(value, decrypted));
+            }
+            None => {
+                if let toml::Value::Table(ref mut t) = *value {
+                    try!(decrypt_table(&path, t, cache_key_path, secrets));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn secret_payload(table: &toml::Table) -> Option<String> {
+    if table.len() != 1 {
+        return None;
+    }
+    table.get(SECRET_KEY).and_then(|s| s.as_str()).map(|s| String::from(s))
+}
+
+fn decrypt_value(path: &str, payload: &str, cache_key_path: &Path) -> Result<toml::Value> {
+    let bytes = try!(BoxKeyPair::decrypt(payload.as_bytes(), cache_key_path));
+    let plain = try!(String::from_utf8(bytes));
+    parse_value(&plain).ok_or(sup_error!(Error::InvalidSecret(String::from(path))))
+}
+
+/// Parse the TOML of a single value, as it is encrypted in a secret.
+fn parse_value(plain: &str) -> Option<toml::Value> {
+    toml::Parser::new(&format!("value = {}", plain))
+        .parse()
+        .and_then(|mut t| t.remove("value"))
+}
+
+/// Put the encrypted value of every secret back into a configuration, under the table at
+/// `prefix`.
+pub fn redact(table: &mut toml::Table, prefix: &str, secrets: &BTreeMap<String, toml::Value>) {
+    for (path, encrypted) in secrets.iter() {
+        let full_path = format!("{}.{}", prefix, path);
+        let parts: Vec<&str> = full_path.split('.').collect();
+        replace_existing(table, &parts, encrypted);
+    }
+}
+
+fn replace_existing(table: &mut toml::Table, parts: &[&str], value: &toml::Value) {
+    if parts.len() == 1 {
+        if table.contains_key(parts[0]) {
+            table.insert(String::from(parts[0]), value.clone());
+        }
+        return;
+    }
+    if let Some(&mut toml::Value::Table(ref mut t)) = table.get_mut(parts[0]) {
+        replace_existing(t, &parts[1..], value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use hcore::crypto::BoxKeyPair;
+    use tempdir::TempDir;
+    use toml;
+
+    use super::{decrypt, parse_value, redact, secret_payload, SECRET_KEY};
+
+    fn table(contents: &str) -> toml::Table {
+        toml::Parser::new(contents).parse().unwrap()
+    }
+
+    #[test]
+    fn only_lone_secret_keys_are_secrets() {
+        let cfg = table("[a]\nsecret = \"BOX-1\"\n[b]\nsecret = \"BOX-1\"\nother = 1\n");
+        assert_eq!(secret_payload(cfg.get("a").unwrap().as_table().unwrap()),
+                   Some(String::from("BOX-1")));
+        assert_eq!(secret_payload(cfg.get("b").unwrap().as_table().unwrap()), None);
+    }
+
+    #[test]
+    fn secrets_keep_their_type() {
+        assert_eq!(parse_value("\"hunter2\""),
+                   Some(toml::Value::String(String::from("hunter2"))));
+        assert_eq!(parse_value("5432"), Some(toml::Value::Integer(5432)));
+        assert_eq!(parse_value("not toml"), None);
+    }
+
+    #[test]
+    fn secrets_are_decrypted_in_place() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let service = BoxKeyPair::generate_pair_for_service("acme", "redis.default", cache.path())
+            .unwrap();
+        let user = BoxKeyPair::generate_pair_for_user("wecoyote", cache.path()).unwrap();
+        let payload = user.encrypt("\"hunter2\"".as_bytes(), &service).unwrap();
+        let mut secret = toml::Table::new();
+        secret.insert(String::from(SECRET_KEY),
+                      toml::Value::String(String::from_utf8(payload).unwrap()));
+        let mut cfg = table("[db]\nuser = \"app\"\n");
+        if let Some(&mut toml::Value::Table(ref mut db)) = cfg.get_mut("db") {
+            db.insert(String::from("password"), toml::Value::Table(secret.clone()));
+        }
+        let secrets = decrypt(&mut cfg, cache.path()).unwrap();
+        assert_eq!(cfg.get("db").unwrap().lookup("password").unwrap().as_str(),
+                   Some("hunter2"));
+        assert_eq!(secrets.get("db.password"), Some(&toml::Value::Table(secret)));
+    }
+
+    #[test]
+    fn redact_puts_encrypted_values_back() {
+        let mut cfg = table("[cfg.db]\npassword = \"hunter2\"\nuser = \"app\"\n");
+        let mut secrets = BTreeMap::new();
+        secrets.insert(String::from("db.password"),
+                       toml::Value::Table(table("secret = \"BOX-1\"\n")));
+        secrets.insert(String::from("db.missing"),
+                       toml::Value::Table(table("secret = \"BOX-1\"\n")));
+        redact(&mut cfg, "cfg", &secrets);
+        assert_eq!(cfg.get("cfg").unwrap().lookup("db.password.secret").unwrap().as_str(),
+                   Some("BOX-1"));
+        assert_eq!(cfg.get("cfg").unwrap().lookup("db.user").unwrap().as_str(),
+                   Some("app"));
+        assert!(cfg.get("cfg").unwrap().lookup("db.missing").is_none());
+    }
+}
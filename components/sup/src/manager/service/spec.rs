@@ -0,0 +1,181 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Service specs describe the services a Supervisor has been asked to run.
+//!
+//! A spec is everything we need to load a service: which package, which group it belongs to, and
+//! how it should be run. The set of loaded specs is written to disk every time it changes, so
+//! that a restarted Supervisor comes back up running the same services.
+
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use hcore::fs::sup_path;
+use hcore::package::PackageIdent;
+use hcore::service::ServiceGroup;
+use rustc_serialize::json;
+
+use config::{gconfig, Topology, UpdateStrategy};
+use manager::service::restart::{RestartPolicy, DEFAULT_MAX_RESTARTS, DEFAULT_RESTART_WINDOW};
+use error::Result;
+use limits::ResourceLimits;
+use logs::LogConfig;
+use manager::signals::Signal;
+use supervisor::DEFAULT_STOP_TIMEOUT;
+
+static SPECS_FILENAME: &'static str = "services.json";
+
+#[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct ServiceSpec {
+    pub ident: PackageIdent,
+    pub group: String,
+    pub organization: Option<String>,
+    pub topology: Topology,
+    pub update_strategy: UpdateStrategy,
+    pub binds: Vec<String>,
+    pub restart_policy: RestartPolicy,
+    pub max_restarts: u32,
+    pub restart_window: u64,
+    pub stop_signal: u32,
+    pub stop_timeout: u64,
+    pub limits: ResourceLimits,
+    pub log: LogConfig,
+}
+
+impl ServiceSpec {
+    pub fn new(ident: PackageIdent, group: String) -> Self {
+        ServiceSpec {
+            ident: ident,
+            group: group,
+            organization: None,
+            topology: Topology::default(),
+            update_strategy: UpdateStrategy::default(),
+            binds: Vec::new(),
+            restart_policy: RestartPolicy::default(),
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            restart_window: DEFAULT_RESTART_WINDOW,
+            stop_signal: Signal::SIGTERM as u32,
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
+            limits: ResourceLimits::default(),
+            log: LogConfig::default(),
+        }
+    }
+
+    /// Build a spec for the service given on the command line.
+    pub fn from_config() -> Self {
+        let mut spec = ServiceSpec::new(gconfig().package().clone(),
+                                        gconfig().group().to_string());
+        spec.organization = gconfig().organization().clone();
+        spec.topology = *gconfig().topology();
+        spec.update_strategy = gconfig().update_strategy();
+        spec.binds = gconfig().bind();
+        spec.restart_policy = gconfig().restart_policy();
+        spec.max_restarts = gconfig().max_restarts();
+        spec.restart_window = gconfig().restart_window();
+        spec.stop_signal = gconfig().stop_signal();
+        spec.stop_timeout = gconfig().stop_timeout();
+        spec.limits = gconfig().limits();
+        spec.log = LogConfig {
+            format: gconfig().log_format(),
+            max_size: gconfig().log_max_size(),
+            max_age: gconfig().log_max_age(),
+        };
+        spec
+    }
+
+    pub fn service_group(&self) -> ServiceGroup {
+        ServiceGroup::new(self.ident.name.clone(),
+                          self.group.clone(),
+                          self.organization.clone())
+    }
+}
+
+/// Returns the path to the file holding the specs loaded in this Supervisor.
+pub fn specs_path() -> PathBuf {
+    sup_path(gconfig().name()).join(SPECS_FILENAME)
+}
+
+/// Read the persisted specs. A missing file means no services have been loaded yet.
+pub fn read_specs<P: AsRef<Path>>(path: P) -> Result<Vec<ServiceSpec>> {
+    if !path.as_ref().exists() {
+        return Ok(Vec::new());
+    }
+    let mut file = try!(File::open(path.as_ref()));
+    let mut contents = String::new();
+    try!(file.read_to_string(&mut contents));
+    let specs = try!(json::decode(&contents));
+    Ok(specs)
+}
+
+/// Persist the specs, writing to a temporary file first so a crash never leaves a half written
+/// file behind.
+pub fn write_specs<P: AsRef<Path>>(path: P, specs: &[ServiceSpec]) -> Result<()> {
+    if let Some(parent) = path.as_ref().parent() {
+        try!(fs::create_dir_all(parent));
+    }
+    let new_filename = format!("{}.write", path.as_ref().to_string_lossy());
+    {
+        let mut new_file = try!(File::create(&new_filename));
+        try!(new_file.write_all(try!(json::encode(&specs)).as_bytes()));
+    }
+    try!(fs::rename(&new_filename, path.as_ref()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use config::Topology;
+    use logs::LogFormat;
+    use manager::service::restart::RestartPolicy;
+    use super::{read_specs, write_specs, ServiceSpec};
+
+    #[test]
+    fn read_specs_without_a_file_is_empty() {
+        let tmp = TempDir::new("specs").unwrap();
+        let specs = read_specs(tmp.path().join("services.json")).unwrap();
+        assert!(specs.is_empty());
+    }
+
+    #[test]
+    fn specs_round_trip_through_disk() {
+        let tmp = TempDir::new("specs").unwrap();
+        let path = tmp.path().join("nested").join("services.json");
+        let mut redis = ServiceSpec::new(PackageIdent::from_str("core/redis").unwrap(),
+                                         "production".to_string());
+        redis.topology = Topology::Leader;
+        redis.binds = vec!["cache:redis.production".to_string()];
+        redis.restart_policy = RestartPolicy::OnFailure;
+        redis.stop_timeout = 30;
+        redis.limits.memory = Some(256 * 1024 * 1024);
+        redis.log.format = LogFormat::Json;
+        let nginx = ServiceSpec::new(PackageIdent::from_str("core/nginx").unwrap(),
+                                     "default".to_string());
+        write_specs(&path, &[redis.clone(), nginx.clone()]).unwrap();
+        let specs = read_specs(&path).unwrap();
+        assert_eq!(specs, vec![redis, nginx]);
+    }
+
+    #[test]
+    fn service_group_uses_package_name() {
+        let spec = ServiceSpec::new(PackageIdent::from_str("core/redis").unwrap(),
+                                    "production".to_string());
+        assert_eq!(spec.service_group().as_string(), "redis.production");
+    }
+}
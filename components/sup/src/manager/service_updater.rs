@@ -39,7 +39,7 @@ const UPDATE_STRATEGY_FREQUENCY_MS: i64 = 60_000;
 
 type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable, RustcDecodable)]
 pub enum UpdateStrategy {
     None,
     AtOnce,
@@ -116,6 +116,11 @@ impl ServiceUpdater {
         }
     }
 
+    /// Stop tracking updates for a service group that has been unloaded.
+    pub fn remove(&mut self, service_group: &ServiceGroup) {
+        self.states.remove(service_group);
+    }
+
     pub fn check_for_updated_package(&mut self,
                                      service: &mut Service,
                                      census_list: &CensusList)
@@ -137,7 +142,8 @@ impl ServiceUpdater {
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
                 if let Some(census) = census_list.get(&service.service_group.as_string()) {
-                    if service.topology == Topology::Leader {
+                    if service.topology == Topology::Leader ||
+                       service.topology == Topology::Lease {
                         debug!("Rolling Update, determining proper suitability because we're in \
                                 a leader topology");
                         match (census.me(), census.get_leader()) {
@@ -14,6 +14,12 @@
 
 //! Contains the cross-platform signal behavior.
 
+use std::str::FromStr;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "SG";
+
 pub enum SignalEvent {
     Shutdown,
     Passthrough(u32),
@@ -30,3 +36,79 @@ pub use manager::signals::unix::{init, check_for_signal, send_signal, Signal};
 
 #[cfg(windows)]
 pub use manager::signals::windows::{init, check_for_signal, send_signal, Signal};
+
+impl FromStr for Signal {
+    type Err = SupError;
+
+    /// Parses a signal from its name, with or without the `SIG` prefix (`HUP`, `SIGHUP`), or
+    /// from its number on this platform.
+    fn from_str(value: &str) -> Result<Self> {
+        let upper = value.to_uppercase();
+        let name = if upper.starts_with("SIG") {
+            &upper[3..]
+        } else {
+            &upper[..]
+        };
+        let signal = match name {
+            "HUP" => Some(Signal::SIGHUP),
+            "INT" => Some(Signal::SIGINT),
+            "QUIT" => Some(Signal::SIGQUIT),
+            "KILL" => Some(Signal::SIGKILL),
+            "ALRM" => Some(Signal::SIGALRM),
+            "TERM" => Some(Signal::SIGTERM),
+            "USR1" => Some(Signal::SIGUSR1),
+            "USR2" => Some(Signal::SIGUSR2),
+            number => number.parse::<u32>().ok().and_then(signal_from_number),
+        };
+        signal.ok_or_else(|| sup_error!(Error::InvalidSignal(value.to_string())))
+    }
+}
+
+/// Returns the signal with the given number. Signal numbers differ between platforms (`USR1` is
+/// 10 on Linux and 30 on the BSDs), so we go by the numbers `Signal` has on this one.
+fn signal_from_number(number: u32) -> Option<Signal> {
+    [Signal::SIGHUP,
+     Signal::SIGINT,
+     Signal::SIGQUIT,
+     Signal::SIGKILL,
+     Signal::SIGALRM,
+     Signal::SIGTERM,
+     Signal::SIGUSR1,
+     Signal::SIGUSR2]
+        .iter()
+        .find(|signal| (*signal).clone() as u32 == number)
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Signal;
+
+    #[test]
+    fn signal_from_str_accepts_names_and_numbers() {
+        assert_eq!(Signal::from_str("HUP").unwrap() as u32, Signal::SIGHUP as u32);
+        assert_eq!(Signal::from_str("sigterm").unwrap() as u32,
+                   Signal::SIGTERM as u32);
+        assert_eq!(Signal::from_str("9").unwrap() as u32, Signal::SIGKILL as u32);
+    }
+
+    #[test]
+    fn signal_from_str_rejects_unknown_signals() {
+        assert!(Signal::from_str("SIGWINCH").is_err());
+        assert!(Signal::from_str("").is_err());
+        assert!(Signal::from_str("28").is_err());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn signal_from_str_uses_linux_numbers() {
+        assert_eq!(Signal::SIGUSR1 as u32, 10);
+        assert_eq!(Signal::SIGUSR2 as u32, 12);
+        assert_eq!(Signal::from_str("10").unwrap() as u32, Signal::SIGUSR1 as u32);
+        assert_eq!(Signal::from_str("12").unwrap() as u32, Signal::SIGUSR2 as u32);
+        assert!(Signal::from_str("30").is_err());
+        assert!(Signal::from_str("31").is_err());
+    }
+}
@@ -56,8 +56,14 @@ pub enum Signal {
     /// terminate process - software termination signal
     SIGTERM = 15,
     /// terminate process - User defined signal 1
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    SIGUSR1 = 10,
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
     SIGUSR1 = 30,
     /// terminate process - User defined signal 2
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    SIGUSR2 = 12,
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
     SIGUSR2 = 31,
 }
 
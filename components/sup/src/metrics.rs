@@ -0,0 +1,326 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics about the Supervisor, in the Prometheus text exposition format.
+//!
+//! Most of what we export is read straight out of the manager state when the metrics are
+//! scraped. Hooks are the exception: a `Hook` is built fresh every time it runs, so each run is
+//! recorded here as it finishes.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Write};
+use std::sync::RwLock;
+
+use butterfly::member::Health;
+use butterfly::rumor::{Rumor, RumorStore};
+use butterfly::rumor::election::Election_Status;
+use hcore::service::ServiceGroup;
+use time::Duration;
+
+use health_check::CheckResult;
+use manager;
+use package::HookType;
+
+/// The content type Prometheus expects the metrics to be served with.
+pub const CONTENT_TYPE: &'static str = "text/plain; version=0.0.4";
+
+lazy_static! {
+    static ref HOOK_STATS: RwLock<BTreeMap<(String, String), HookStats>> =
+        RwLock::new(BTreeMap::new());
+}
+
+/// What we know about the runs of one hook of one service group.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HookStats {
+    pub runs: u64,
+    pub failures: u64,
+    pub last_duration: f64,
+    pub last_exit_code: i32,
+}
+
+/// Record a finished run of a hook.
+pub fn record_hook_run(service_group: &ServiceGroup,
+                       hook: HookType,
+                       duration: Duration,
+                       exit_code: i32) {
+    let mut stats = HOOK_STATS.write().expect("Hook stats lock is poisoned");
+    let entry = stats.entry((service_group.to_string(), hook.to_string()))
+        .or_insert(HookStats::default());
+    entry.runs += 1;
+    if exit_code != 0 {
+        entry.failures += 1;
+    }
+    entry.last_duration = duration.num_milliseconds() as f64 / 1000.0;
+    entry.last_exit_code = exit_code;
+}
+
+/// Returns what we know about a hook of a service group, if it has ever run.
+pub fn hook_stats(service_group: &ServiceGroup, hook: HookType) -> Option<HookStats> {
+    let stats = HOOK_STATS.read().expect("Hook stats lock is poisoned");
+    stats.get(&(service_group.to_string(), hook.to_string())).cloned()
+}
+
+/// Render every metric we export.
+pub fn render(state: &manager::State) -> String {
+    let mut out = String::new();
+    render_butterfly(&mut out, state);
+    render_services(&mut out, state);
+    render_hooks(&mut out);
+    out
+}
+
+fn render_butterfly(out: &mut String, state: &manager::State) {
+    let server = &state.butterfly;
+    header(out,
+           "hab_butterfly_swim_rounds",
+           "counter",
+           "The number of completed SWIM probe rounds.");
+    sample(out, "hab_butterfly_swim_rounds", &[], server.swim_rounds());
+    header(out,
+           "hab_butterfly_gossip_rounds",
+           "counter",
+           "The number of completed gossip rounds.");
+    sample(out, "hab_butterfly_gossip_rounds", &[], server.gossip_rounds());
+
+    let mut members: HashMap<String, usize> = HashMap::new();
+    for health in [Health::Alive, Health::Suspect, Health::Confirmed, Health::Departed].iter() {
+        members.insert(health.to_string().to_lowercase(), 0);
+    }
+    server.member_list.with_members(|member| {
+        if let Some(health) = server.member_list.health_of(member) {
+            *members.entry(health.to_string().to_lowercase()).or_insert(0) += 1;
+        }
+    });
+    header(out,
+           "hab_butterfly_members",
+           "gauge",
+           "The number of members in the ring, by health.");
+    let mut healths: Vec<_> = members.into_iter().collect();
+    healths.sort();
+    for (health, count) in healths {
+        sample(out, "hab_butterfly_members", &[("health", health.as_str())], count);
+    }
+
+    header(out,
+           "hab_butterfly_rumors",
+           "gauge",
+           "The number of rumors held in each rumor store.");
+    sample(out,
+           "hab_butterfly_rumors",
+           &[("store", "service")],
+           rumor_count(&server.service_store));
+    sample(out,
+           "hab_butterfly_rumors",
+           &[("store", "service_config")],
+           rumor_count(&server.service_config_store));
+    sample(out,
+           "hab_butterfly_rumors",
+           &[("store", "service_file")],
+           rumor_count(&server.service_file_store));
+    sample(out,
+           "hab_butterfly_rumors",
+           &[("store", "election")],
+           rumor_count(&server.election_store));
+    sample(out,
+           "hab_butterfly_rumors",
+           &[("store", "election_update")],
+           rumor_count(&server.update_store));
+
+    let mut elections = Vec::new();
+    server.election_store.with_keys(|(key, rumors)| for election in rumors.values() {
+        elections.push(("election", key.clone(), election_status(election.get_status())));
+    });
+    server.update_store.with_keys(|(key, rumors)| for election in rumors.values() {
+        elections.push(("update", key.clone(), election_status(election.get_status())));
+    });
+    elections.sort();
+    header(out,
+           "hab_butterfly_election_status",
+           "gauge",
+           "The status of each election we know about; 1 for the status it is in.");
+    for (kind, service_group, current) in elections {
+        for status in ["running", "no_quorum", "finished"].iter() {
+            sample(out,
+                   "hab_butterfly_election_status",
+                   &[("service_group", service_group.as_str()),
+                     ("type", kind),
+                     ("status", *status)],
+                   if *status == current { 1 } else { 0 });
+        }
+    }
+}
+
+fn render_services(out: &mut String, state: &manager::State) {
+    let services = state.services.read().expect("Services lock is poisoned");
+    header(out,
+           "hab_sup_service_up",
+           "gauge",
+           "Whether the service's process is running.");
+    for service in services.iter() {
+        sample(out,
+               "hab_sup_service_up",
+               &[("service_group", service.service_group_str().as_str())],
+               if service.is_down() { 0 } else { 1 });
+    }
+    header(out,
+           "hab_sup_service_restarts_total",
+           "counter",
+           "The number of times the service's process has been restarted after exiting.");
+    for service in services.iter() {
+        sample(out,
+               "hab_sup_service_restarts_total",
+               &[("service_group", service.service_group_str().as_str())],
+               service.restarts.count());
+    }
+    header(out,
+           "hab_sup_service_health_check",
+           "gauge",
+           "The result of the service's last health check; 0 ok, 1 warning, 2 critical, 3 \
+            unknown.");
+    for service in services.iter() {
+        if let Some(record) = service.health_history.latest() {
+            sample(out,
+                   "hab_sup_service_health_check",
+                   &[("service_group", service.service_group_str().as_str())],
+                   check_result_code(record.result));
+        }
+    }
+}
+
+fn render_hooks(out: &mut String) {
+    let stats = HOOK_STATS.read().expect("Hook stats lock is poisoned");
+    header(out,
+           "hab_sup_hook_runs_total",
+           "counter",
+           "The number of times the hook has run.");
+    for (&(ref service_group, ref hook), stat) in stats.iter() {
+        sample(out,
+               "hab_sup_hook_runs_total",
+               &[("service_group", service_group.as_str()), ("hook", hook.as_str())],
+               stat.runs);
+    }
+    header(out,
+           "hab_sup_hook_failures_total",
+           "counter",
+           "The number of times the hook has exited with a non-zero exit code.");
+    for (&(ref service_group, ref hook), stat) in stats.iter() {
+        sample(out,
+               "hab_sup_hook_failures_total",
+               &[("service_group", service_group.as_str()), ("hook", hook.as_str())],
+               stat.failures);
+    }
+    header(out,
+           "hab_sup_hook_duration_seconds",
+           "gauge",
+           "How long the last run of the hook took.");
+    for (&(ref service_group, ref hook), stat) in stats.iter() {
+        sample(out,
+               "hab_sup_hook_duration_seconds",
+               &[("service_group", service_group.as_str()), ("hook", hook.as_str())],
+               stat.last_duration);
+    }
+    header(out,
+           "hab_sup_hook_exit_code",
+           "gauge",
+           "The exit code of the last run of the hook.");
+    for (&(ref service_group, ref hook), stat) in stats.iter() {
+        sample(out,
+               "hab_sup_hook_exit_code",
+               &[("service_group", service_group.as_str()), ("hook", hook.as_str())],
+               stat.last_exit_code);
+    }
+}
+
+fn rumor_count<T: Rumor + Clone>(store: &RumorStore<T>) -> usize {
+    let mut count = 0;
+    store.with_keys(|(_key, rumors)| count += rumors.len());
+    count
+}
+
+fn election_status(status: Election_Status) -> &'static str {
+    match status {
+        Election_Status::Running => "running",
+        Election_Status::NoQuorum => "no_quorum",
+        Election_Status::Finished => "finished",
+    }
+}
+
+/// Health checks are exported with the exit codes a health check hook uses for them.
+fn check_result_code(result: CheckResult) -> u32 {
+    match result {
+        CheckResult::Ok => 0,
+        CheckResult::Warning => 1,
+        CheckResult::Critical => 2,
+        CheckResult::Unknown => 3,
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn sample<T: fmt::Display>(out: &mut String, name: &str, labels: &[(&str, &str)], value: T) {
+    let _ = write!(out, "{}", name);
+    if !labels.is_empty() {
+        let labels: Vec<String> = labels.iter()
+            .map(|&(k, v)| format!("{}=\"{}\"", k, escape_label(v)))
+            .collect();
+        let _ = write!(out, "{{{}}}", labels.join(","));
+    }
+    let _ = writeln!(out, " {}", value);
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace("\\", "\\\\").replace("\"", "\\\"").replace("\n", "\\n")
+}
+
+#[cfg(test)]
+mod test {
+    use hcore::service::ServiceGroup;
+    use time::Duration;
+
+    use package::HookType;
+    use super::{escape_label, hook_stats, record_hook_run, sample};
+
+    #[test]
+    fn sample_formats_labels() {
+        let mut out = String::new();
+        sample(&mut out, "hab_test", &[], 3);
+        sample(&mut out,
+               "hab_test",
+               &[("service_group", "redis.default"), ("hook", "init")],
+               0.5);
+        assert_eq!(out,
+                   "hab_test 3\nhab_test{service_group=\"redis.default\",hook=\"init\"} 0.5\n");
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn hook_runs_are_recorded() {
+        let sg = ServiceGroup::new("metrics", "test", None);
+        assert!(hook_stats(&sg, HookType::PostRun).is_none());
+        record_hook_run(&sg, HookType::PostRun, Duration::milliseconds(1500), 0);
+        record_hook_run(&sg, HookType::PostRun, Duration::milliseconds(250), 2);
+        let stats = hook_stats(&sg, HookType::PostRun).unwrap();
+        assert_eq!(stats.runs, 2);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.last_duration, 0.25);
+        assert_eq!(stats.last_exit_code, 2);
+    }
+}
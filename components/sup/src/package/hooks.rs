@@ -14,18 +14,23 @@
 
 use std::fmt;
 use std::fs::{self, File};
-use std::io::BufReader;
+use std::io::{self, BufReader};
 use std::io::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Child;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
 
 use handlebars::Handlebars;
+use time::SteadyTime;
 
 use error::{Error, Result};
 use hcore::service::ServiceGroup;
 use hcore::util;
 use package::Package;
 use manager::service::config::{ServiceConfig, never_escape_fn};
+use metrics;
 use util::convert;
 use util::handlebars_helpers;
 use util::users as hab_users;
@@ -34,6 +39,23 @@ use util as sup_util;
 pub const HOOK_PERMISSIONS: u32 = 0o755;
 static LOGKEY: &'static str = "PH";
 
+/// The most a hook whose output we keep may write to stdout, in bytes. Anything after it is read
+/// and thrown away.
+const MAX_HOOK_OUTPUT_BYTES: u64 = 64 * 1024;
+/// How long a hook whose output we keep may run before it is killed, in seconds.
+const HOOK_OUTPUT_TIMEOUT_SECS: u64 = 60;
+
+/// Every kind of hook a package can ship, in the order the supervisor would first run them.
+pub const HOOK_TYPES: [HookType; 9] = [HookType::Init,
+                                       HookType::FileUpdated,
+                                       HookType::Reconfigure,
+                                       HookType::Run,
+                                       HookType::PostRun,
+                                       HookType::HealthCheck,
+                                       HookType::Suitability,
+                                       HookType::PreStop,
+                                       HookType::PostStop];
+
 #[derive(Debug, Clone, Copy)]
 pub enum HookType {
     HealthCheck,
@@ -41,6 +63,10 @@ pub enum HookType {
     FileUpdated,
     Run,
     Init,
+    PostRun,
+    PreStop,
+    PostStop,
+    Suitability,
 }
 
 impl fmt::Display for HookType {
@@ -51,10 +77,26 @@ impl fmt::Display for HookType {
             &HookType::FileUpdated => write!(f, "file_updated"),
             &HookType::Reconfigure => write!(f, "reconfigure"),
             &HookType::Run => write!(f, "run"),
+            &HookType::PostRun => write!(f, "post-run"),
+            &HookType::PreStop => write!(f, "pre-stop"),
+            &HookType::PostStop => write!(f, "post-stop"),
+            &HookType::Suitability => write!(f, "suitability"),
         }
     }
 }
 
+/// Render a hook template with the given service configuration.
+pub fn render<P: AsRef<Path>>(template: P, context: &ServiceConfig) -> Result<String> {
+    let mut handlebars = Handlebars::new();
+    handlebars_helpers::register(&mut handlebars);
+    handlebars.register_escape_fn(never_escape_fn);
+    try!(handlebars.register_template_file("hook", template.as_ref()));
+    let toml = try!(context.to_toml());
+    let svc_data = convert::toml_to_json(toml);
+    let data = try!(handlebars.render("hook", &svc_data));
+    Ok(data)
+}
+
 #[derive(Debug)]
 pub struct Hook {
     pub htype: HookType,
@@ -81,9 +123,68 @@ impl Hook {
     }
 
     pub fn run(&self, service_group: &ServiceGroup) -> Result<()> {
+        let started = SteadyTime::now();
         let mut child = try!(sup_util::create_command(&self.path, &self.user, &self.group).spawn());
         self.stream_output(service_group, &mut child);
+        self.wait(service_group, started, &mut child)
+    }
+
+    /// Run the hook, returning what it wrote to stdout rather than streaming it. Stderr is
+    /// streamed as the hook runs, so a hook that fills up one pipe never waits on us reading the
+    /// other. Only the first `MAX_HOOK_OUTPUT_BYTES` of stdout are kept, and a hook still running
+    /// after `HOOK_OUTPUT_TIMEOUT_SECS` is killed.
+    pub fn output(&self, service_group: &ServiceGroup) -> Result<String> {
+        let started = SteadyTime::now();
+        let mut child = try!(sup_util::create_command(&self.path, &self.user, &self.group).spawn());
+        if let Some(stderr) = child.stderr.take() {
+            let preamble_str = self.stream_preamble(service_group);
+            try!(thread::Builder::new()
+                .name(format!("hook-{}-stderr", self.htype))
+                .spawn(move || for line in BufReader::new(stderr).lines() {
+                    if let Some(ref l) = line.ok() {
+                        outputln!(preamble preamble_str, l);
+                    }
+                }));
+        }
+        let (tx, rx) = channel();
+        if let Some(mut stdout) = child.stdout.take() {
+            try!(thread::Builder::new()
+                .name(format!("hook-{}-stdout", self.htype))
+                .spawn(move || {
+                    let mut output = Vec::new();
+                    let result = stdout.by_ref()
+                        .take(MAX_HOOK_OUTPUT_BYTES)
+                        .read_to_end(&mut output)
+                        .and_then(|_| io::copy(&mut stdout, &mut io::sink()));
+                    let _ = tx.send(result.map(|_| output));
+                }));
+        }
+        let output = match rx.recv_timeout(Duration::from_secs(HOOK_OUTPUT_TIMEOUT_SECS)) {
+            Ok(output) => try!(output),
+            Err(RecvTimeoutError::Timeout) => {
+                outputln!(preamble self.stream_preamble(service_group),
+                          "Killing hook; still running after {} seconds",
+                          HOOK_OUTPUT_TIMEOUT_SECS);
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(sup_error!(Error::HookTimedOut(self.htype, HOOK_OUTPUT_TIMEOUT_SECS)));
+            }
+            Err(RecvTimeoutError::Disconnected) => Vec::new(),
+        };
+        try!(self.wait(service_group, started, &mut child));
+        Ok(String::from_utf8_lossy(&output).into_owned())
+    }
+
+    fn wait(&self,
+            service_group: &ServiceGroup,
+            started: SteadyTime,
+            child: &mut Child)
+            -> Result<()> {
         let exit_status = try!(child.wait());
+        metrics::record_hook_run(service_group,
+                                 self.htype,
+                                 SteadyTime::now() - started,
+                                 exit_status.code().unwrap_or(-1));
         if exit_status.success() {
             Ok(())
         } else {
@@ -94,14 +195,7 @@ impl Hook {
     pub fn compile(&self, context: Option<&ServiceConfig>) -> Result<()> {
         if let Some(ctx) = context {
             debug!("Rendering hook {:?}", self);
-            let mut handlebars = Handlebars::new();
-            handlebars.register_helper("json", Box::new(handlebars_helpers::json_helper));
-            handlebars.register_helper("toml", Box::new(handlebars_helpers::toml_helper));
-            handlebars.register_escape_fn(never_escape_fn);
-            try!(handlebars.register_template_file("hook", &self.template));
-            let toml = try!(ctx.to_toml());
-            let svc_data = convert::toml_to_json(toml);
-            let data = try!(handlebars.render("hook", &svc_data));
+            let data = try!(render(&self.template, ctx));
             let mut file = try!(File::create(&self.path));
             try!(file.write_all(data.as_bytes()));
             try!(util::perm::set_owner(&self.path, &self.user, &self.group));
@@ -148,6 +242,10 @@ pub struct HookTable<'a> {
     pub reconfigure_hook: Option<Hook>,
     pub file_updated_hook: Option<Hook>,
     pub run_hook: Option<Hook>,
+    pub post_run_hook: Option<Hook>,
+    pub pre_stop_hook: Option<Hook>,
+    pub post_stop_hook: Option<Hook>,
+    pub suitability_hook: Option<Hook>,
 }
 
 impl<'a> HookTable<'a> {
@@ -159,6 +257,10 @@ impl<'a> HookTable<'a> {
             reconfigure_hook: None,
             file_updated_hook: None,
             run_hook: None,
+            post_run_hook: None,
+            pre_stop_hook: None,
+            post_stop_hook: None,
+            suitability_hook: None,
         }
     }
 
@@ -179,6 +281,22 @@ impl<'a> HookTable<'a> {
             hook.compile(Some(context))
                 .unwrap_or_else(|e| outputln!("Failed to compile file updated hook: {}", e));
         }
+        if let Some(ref hook) = self.post_run_hook {
+            hook.compile(Some(context))
+                .unwrap_or_else(|e| outputln!("Failed to compile post-run hook: {}", e));
+        }
+        if let Some(ref hook) = self.pre_stop_hook {
+            hook.compile(Some(context))
+                .unwrap_or_else(|e| outputln!("Failed to compile pre-stop hook: {}", e));
+        }
+        if let Some(ref hook) = self.post_stop_hook {
+            hook.compile(Some(context))
+                .unwrap_or_else(|e| outputln!("Failed to compile post-stop hook: {}", e));
+        }
+        if let Some(ref hook) = self.suitability_hook {
+            hook.compile(Some(context))
+                .unwrap_or_else(|e| outputln!("Failed to compile suitability hook: {}", e));
+        }
     }
 
     pub fn load_hooks(&mut self) -> &mut Self {
@@ -191,6 +309,10 @@ impl<'a> HookTable<'a> {
                     self.reconfigure_hook = self.load_hook(HookType::Reconfigure);
                     self.health_check_hook = self.load_hook(HookType::HealthCheck);
                     self.run_hook = self.load_hook(HookType::Run);
+                    self.post_run_hook = self.load_hook(HookType::PostRun);
+                    self.pre_stop_hook = self.load_hook(HookType::PreStop);
+                    self.post_stop_hook = self.load_hook(HookType::PostStop);
+                    self.suitability_hook = self.load_hook(HookType::Suitability);
                 }
             }
             Err(_) => {}
@@ -43,6 +43,10 @@ const HEALTHCHECK_FILENAME: &'static str = "health_check";
 const FILEUPDATED_FILENAME: &'static str = "file_updated";
 const RECONFIGURE_FILENAME: &'static str = "reconfigure";
 const RUN_FILENAME: &'static str = "run";
+const POST_RUN_FILENAME: &'static str = "post-run";
+const PRE_STOP_FILENAME: &'static str = "pre-stop";
+const POST_STOP_FILENAME: &'static str = "post-stop";
+const SUITABILITY_FILENAME: &'static str = "suitability";
 
 #[derive(Debug, Clone, RustcEncodable)]
 pub struct Package {
@@ -116,6 +120,10 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::PostRun => base.join(POST_RUN_FILENAME),
+            HookType::PreStop => base.join(PRE_STOP_FILENAME),
+            HookType::PostStop => base.join(POST_STOP_FILENAME),
+            HookType::Suitability => base.join(SUITABILITY_FILENAME),
         }
     }
 
@@ -127,6 +135,10 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::PostRun => base.join(POST_RUN_FILENAME),
+            HookType::PreStop => base.join(PRE_STOP_FILENAME),
+            HookType::PostStop => base.join(POST_STOP_FILENAME),
+            HookType::Suitability => base.join(SUITABILITY_FILENAME),
         }
     }
 
@@ -304,6 +316,33 @@ impl Package {
         }
     }
 
+    /// Run post-run hook if present
+    pub fn post_run(&self, service_group: &ServiceGroup) -> Result<()> {
+        if let Some(hook) = self.hooks().post_run_hook {
+            hook.run(service_group)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Run pre-stop hook if present
+    pub fn pre_stop(&self, service_group: &ServiceGroup) -> Result<()> {
+        if let Some(hook) = self.hooks().pre_stop_hook {
+            hook.run(service_group)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Run post-stop hook if present
+    pub fn post_stop(&self, service_group: &ServiceGroup) -> Result<()> {
+        if let Some(hook) = self.hooks().post_stop_hook {
+            hook.run(service_group)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn health_check(&self,
                         supervisor: &Supervisor,
                         service_group: &ServiceGroup)
@@ -335,6 +374,17 @@ impl Package {
         }
     }
 
+    /// Run the suitability hook if present, returning the number it printed on its last line of
+    /// output.
+    pub fn suitability(&self, service_group: &ServiceGroup) -> Result<Option<u64>> {
+        if let Some(hook) = self.hooks().suitability_hook {
+            let output = try!(hook.output(service_group));
+            parse_suitability(&output).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn hooks(&self) -> HookTable {
         let mut hooks = HookTable::new(&self);
         hooks.load_hooks();
@@ -363,3 +413,28 @@ impl fmt::Display for Package {
         write!(f, "{}", self.ident())
     }
 }
+
+/// Parse the output of a suitability hook; its last non-empty line is the suitability.
+fn parse_suitability(output: &str) -> Result<u64> {
+    let line = output.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).last().unwrap_or("");
+    line.parse::<u64>().map_err(|_| sup_error!(Error::InvalidSuitability(String::from(line))))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_suitability;
+
+    #[test]
+    fn suitability_is_the_last_line_of_output() {
+        assert_eq!(parse_suitability("42\n").unwrap(), 42);
+        assert_eq!(parse_suitability("checking WAL position\n 1048576 \n\n").unwrap(),
+                   1048576);
+    }
+
+    #[test]
+    fn suitability_must_be_a_number() {
+        assert!(parse_suitability("").is_err());
+        assert!(parse_suitability("-1\n").is_err());
+        assert!(parse_suitability("lots\n").is_err());
+    }
+}
@@ -23,21 +23,29 @@ use std::fs::{self, File};
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::path::PathBuf;
-use std::process::Child;
+use std::process::Command;
 use std::result;
+use std::sync::{Arc, Mutex};
 use std::thread;
 
 use hcore;
 use hcore::os::process::{HabChild, ExitStatusExt};
 use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
+use ansi_term::Colour::Red;
 use rustc_serialize::{Encodable, Encoder};
-use time::SteadyTime;
+use time::{Duration, SteadyTime};
 
 use error::{Result, Error};
+use limits::{self, Breach, Cgroup, ResourceLimits};
+use logs::{self, LogConfig, ServiceLog, Stream};
 use util;
 
 const PIDFILE_NAME: &'static str = "PID";
+
+/// How long a process has to exit after being sent its stop signal before it is killed, in
+/// seconds.
+pub const DEFAULT_STOP_TIMEOUT: u64 = 8;
 static LOGKEY: &'static str = "SV";
 
 #[derive(Debug, RustcEncodable)]
@@ -46,6 +54,10 @@ pub enum ProcessState {
     Up,
     Start,
     Restart,
+    /// The process was killed by the kernel for running out of memory.
+    OomKilled,
+    /// The process exited after running into one of its resource limits.
+    LimitExceeded,
 }
 
 impl fmt::Display for ProcessState {
@@ -55,6 +67,8 @@ impl fmt::Display for ProcessState {
             &ProcessState::Up => "up",
             &ProcessState::Start => "start",
             &ProcessState::Restart => "restart",
+            &ProcessState::OomKilled => "oom-killed",
+            &ProcessState::LimitExceeded => "limit-exceeded",
         };
         write!(f, "{}", state)
     }
@@ -69,13 +83,27 @@ impl fmt::Display for ProcessState {
 pub struct RuntimeConfig {
     pub svc_user: String,
     pub svc_group: String,
+    pub stop_signal: u32,
+    pub stop_timeout: u64,
+    pub limits: ResourceLimits,
+    pub log: LogConfig,
 }
 
 impl RuntimeConfig {
-    pub fn new(svc_user: String, svc_group: String) -> RuntimeConfig {
+    pub fn new(svc_user: String,
+               svc_group: String,
+               stop_signal: u32,
+               stop_timeout: u64,
+               limits: ResourceLimits,
+               log: LogConfig)
+               -> RuntimeConfig {
         RuntimeConfig {
             svc_user: svc_user,
             svc_group: svc_group,
+            stop_signal: stop_signal,
+            stop_timeout: stop_timeout,
+            limits: limits,
+            log: log,
         }
     }
 }
@@ -89,13 +117,22 @@ pub struct Supervisor {
     pub state_entered: SteadyTime,
     pub has_started: bool,
     pub runtime_config: RuntimeConfig,
+    pub last_exit_code: Option<i32>,
+    cgroup: Option<Cgroup>,
+    log: Arc<Mutex<ServiceLog>>,
 }
 
 impl Supervisor {
     pub fn new(package_ident: PackageIdent,
                service_group: &ServiceGroup,
+               member_id: &str,
                runtime_config: RuntimeConfig)
                -> Supervisor {
+        let log_dir = hcore::fs::svc_var_path(&package_ident.name).join(logs::LOG_DIR);
+        let log = ServiceLog::new(log_dir,
+                                  runtime_config.log.clone(),
+                                  service_group.to_string(),
+                                  member_id.to_string());
         Supervisor {
             child: None,
             package_ident: package_ident,
@@ -104,6 +141,9 @@ impl Supervisor {
             state_entered: SteadyTime::now(),
             has_started: false,
             runtime_config: runtime_config,
+            last_exit_code: None,
+            cgroup: None,
+            log: Arc::new(Mutex::new(log)),
         }
     }
 
@@ -119,7 +159,7 @@ impl Supervisor {
                              SteadyTime::now() - self.state_entered);
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::OomKilled | ProcessState::LimitExceeded => false,
         };
         (healthy, status)
     }
@@ -128,18 +168,34 @@ impl Supervisor {
         if self.child.is_none() {
             outputln!(preamble & self.preamble, "Starting");
             self.enter_state(ProcessState::Start);
-            let mut child = try!(util::create_command(self.run_cmd(),
-                                                      &self.runtime_config.svc_user,
-                                                      &self.runtime_config.svc_group)
-                .spawn());
+            let mut cmd = util::create_command(self.run_cmd(),
+                                               &self.runtime_config.svc_user,
+                                               &self.runtime_config.svc_group);
+            let procs = self.prepare_limits(&mut cmd);
+            let mut child = try!(cmd.spawn());
+            drop(procs);
 
             let hab_child = try!(HabChild::from(&mut child));
             self.child = Some(hab_child);
             try!(self.create_pidfile());
-            let package_name = self.preamble.clone();
-            try!(thread::Builder::new()
-                .name(String::from("sup-service-read"))
-                .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
+            if let Some(stdout) = child.stdout.take() {
+                let package_name = self.preamble.clone();
+                let log = self.log.clone();
+                try!(thread::Builder::new()
+                    .name(String::from("sup-service-read"))
+                    .spawn(move || -> Result<()> {
+                        child_reader(stdout, Stream::Stdout, package_name, log)
+                    }));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                let package_name = self.preamble.clone();
+                let log = self.log.clone();
+                try!(thread::Builder::new()
+                    .name(String::from("sup-service-read-err"))
+                    .spawn(move || -> Result<()> {
+                        child_reader(stderr, Stream::Stderr, package_name, log)
+                    }));
+            }
             self.enter_state(ProcessState::Up);
             self.has_started = true;
         } else {
@@ -148,12 +204,46 @@ impl Supervisor {
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Set up the cgroup and resource limits the process starts with. A cgroup we fail to set up
+    /// doesn't stop the service from starting; it runs without the limits the cgroup enforces.
+    fn prepare_limits(&mut self, cmd: &mut Command) -> Option<File> {
+        let resource_limits = &self.runtime_config.limits;
+        self.cgroup = match Cgroup::create(&self.preamble, resource_limits) {
+            Ok(cgroup) => cgroup,
+            Err(e) => {
+                outputln!(preamble self.preamble,
+                          "Failed to set up a cgroup; the cpu, memory and pids limits will not \
+                           be enforced: {}",
+                          e);
+                None
+            }
+        };
+        let procs = match self.cgroup {
+            Some(ref cgroup) => {
+                match cgroup.procs() {
+                    Ok(procs) => Some(procs),
+                    Err(e) => {
+                        outputln!(preamble self.preamble,
+                                  "Failed to open {}: {}",
+                                  cgroup.path().display(),
+                                  e);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+        limits::prepare_command(cmd, resource_limits, procs.as_ref());
+        procs
+    }
+
+    /// Send the stop signal to a process, wait for the stop timeout, then send SIGKILL
     pub fn stop(&mut self) -> Result<()> {
         match self.child {
             Some(ref mut child) => {
                 outputln!(preamble & self.preamble, "Stopping...");
-                let shutdown = try!(child.kill());
+                let timeout = Duration::seconds(self.runtime_config.stop_timeout as i64);
+                let shutdown = try!(child.terminate(self.runtime_config.stop_signal, timeout));
                 outputln!("{} - Shutdown method: {}", self.preamble, shutdown);
             }
             None => {},
@@ -182,6 +272,9 @@ impl Supervisor {
         self.enter_state(ProcessState::Down);
         try!(self.stop());
         self.cleanup_pidfile();
+        if let Some(cgroup) = self.cgroup.take() {
+            cgroup.remove();
+        }
         Ok(())
     }
 
@@ -192,14 +285,16 @@ impl Supervisor {
         Ok(())
     }
 
-    /// if the child process exists, check it's status via waitpid().
-    pub fn check_process(&mut self) -> Result<()> {
+    /// if the child process exists, check it's status via waitpid(). Returns true if the process
+    /// exited without us asking it to.
+    pub fn check_process(&mut self) -> Result<bool> {
         let changed = match self.child {
             None => false,
             Some(ref mut child) => {
                 match child.status() {
                     Ok(ref status) if status.no_status() => false,
                     Ok(ref status) => {
+                        self.last_exit_code = status.code();
                         if status.code().is_some() {
                             outputln!("{} - process {} died with exit code {}",
                                       self.preamble,
@@ -226,15 +321,32 @@ impl Supervisor {
                 ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
                     outputln!("{} - Service exited", self.preamble);
                     self.child = None;
+                    if let Some(breach) = self.cgroup.as_mut().and_then(|c| c.breach()) {
+                        outputln!("{} - {}",
+                                  self.preamble,
+                                  Red.bold().paint(format!("Service {}", breach)));
+                        match breach {
+                            Breach::OomKill => self.enter_state(ProcessState::OomKilled),
+                            Breach::Memory | Breach::Pids => {
+                                self.enter_state(ProcessState::LimitExceeded)
+                            }
+                        }
+                    }
+                    return Ok(true);
                 }
-                ProcessState::Down => {
+                ProcessState::Down | ProcessState::OomKilled | ProcessState::LimitExceeded => {
                     self.enter_state(ProcessState::Down);
                     self.child = None;
                 }
             }
         }
 
-        Ok(())
+        Ok(false)
+    }
+
+    /// Returns the directory the service's log is written to.
+    pub fn log_dir(&self) -> PathBuf {
+        self.log.lock().expect("Service log lock is poisoned").dir().to_path_buf()
     }
 
     pub fn run_cmd(&self) -> PathBuf {
@@ -312,7 +424,9 @@ impl Encodable for Supervisor {
             None => None,
         };
 
-        try!(s.emit_struct("supervisor", 7, |s| {
+        let cgroup = self.cgroup.as_ref().map(|c| c.path().to_string_lossy().into_owned());
+
+        try!(s.emit_struct("supervisor", 9, |s| {
             try!(s.emit_struct_field("pid", 0, |s| pid.encode(s)));
             try!(s.emit_struct_field("package_ident", 1, |s| self.package_ident.encode(s)));
             try!(s.emit_struct_field("preamble", 2, |s| self.preamble.encode(s)));
@@ -322,6 +436,8 @@ impl Encodable for Supervisor {
                                      |s| self.state_entered.to_string().encode(s)));
             try!(s.emit_struct_field("has_started", 5, |s| self.has_started.encode(s)));
             try!(s.emit_struct_field("runtime_config", 6, |s| self.runtime_config.encode(s)));
+            try!(s.emit_struct_field("last_exit_code", 7, |s| self.last_exit_code.encode(s)));
+            try!(s.emit_struct_field("cgroup", 8, |s| cgroup.encode(s)));
             Ok(())
         }));
         Ok(())
@@ -334,20 +450,30 @@ impl Drop for Supervisor {
     }
 }
 
-/// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
-        None => return Err(sup_error!(Error::UnpackFailed)),
+/// Consume output from a child process until EOF, echoing it and writing it to the service's
+/// log, then finish
+fn child_reader<R: Read>(output: R,
+                         stream: Stream,
+                         package_name: String,
+                         log: Arc<Mutex<ServiceLog>>)
+                         -> Result<()> {
+    let logkey = match stream {
+        Stream::Stdout => "O",
+        Stream::Stderr => "E",
     };
-
-    let mut reader = BufReader::new(c_stdout);
+    let mut reader = BufReader::new(output);
     let mut buffer = String::new();
 
-    while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
+    while try!(reader.read_line(&mut buffer)) > 0 {
+        let mut line = output_format!(preamble &package_name, logkey logkey);
         line.push_str(&buffer);
         print!("{}", line);
+        let written = log.lock()
+            .expect("Service log lock is poisoned")
+            .write_line(stream, buffer.trim_right_matches(&['\r', '\n'][..]));
+        if let Err(e) = written {
+            debug!("Error writing to the service log: {}, continuing", e);
+        }
         buffer.clear();
     }
     debug!("child_reader exiting");
@@ -12,9 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use handlebars::{Context, Handlebars, Helper, RenderContext, RenderError};
+//! Helpers available to configuration templates and hooks.
+//!
+//! Besides `json` and `toml`, there are helpers for strings (`upper`, `lower`, `replace`, `join`,
+//! and the `split` block), for default values (`default`), for math (`add`, `sub`, `mul`, `div`,
+//! `mod`), for encoding (`base64`, `urlencode`) and for numbers (`toNumber`). The `eachAlive`,
+//! `eachHealthy`, `eachWhere` and `eachSorted` blocks iterate over a list of members like `each`,
+//! skipping or reordering them; all but `eachSorted` take an optional `sort="<field>"`.
+
+use std::cmp::Ordering;
+
+use handlebars::{Context, Handlebars, Helper, RenderContext, RenderError, Renderable};
 use rustc_serialize::Encodable;
+use rustc_serialize::base64::{STANDARD, ToBase64};
+use rustc_serialize::json::{Json, ToJson};
 use toml;
+use url::form_urlencoded;
+
+/// Register every helper with `handlebars`.
+pub fn register(handlebars: &mut Handlebars) {
+    handlebars.register_helper("json", Box::new(json_helper));
+    handlebars.register_helper("toml", Box::new(toml_helper));
+    handlebars.register_helper("upper", Box::new(upper_helper));
+    handlebars.register_helper("lower", Box::new(lower_helper));
+    handlebars.register_helper("replace", Box::new(replace_helper));
+    handlebars.register_helper("split", Box::new(split_helper));
+    handlebars.register_helper("join", Box::new(join_helper));
+    handlebars.register_helper("default", Box::new(default_helper));
+    handlebars.register_helper("add", Box::new(add_helper));
+    handlebars.register_helper("sub", Box::new(sub_helper));
+    handlebars.register_helper("mul", Box::new(mul_helper));
+    handlebars.register_helper("div", Box::new(div_helper));
+    handlebars.register_helper("mod", Box::new(mod_helper));
+    handlebars.register_helper("base64", Box::new(base64_helper));
+    handlebars.register_helper("urlencode", Box::new(urlencode_helper));
+    handlebars.register_helper("toNumber", Box::new(to_number_helper));
+    handlebars.register_helper("eachAlive", Box::new(each_alive_helper));
+    handlebars.register_helper("eachHealthy", Box::new(each_healthy_helper));
+    handlebars.register_helper("eachWhere", Box::new(each_where_helper));
+    handlebars.register_helper("eachSorted", Box::new(each_sorted_helper));
+}
 
 pub fn json_helper(_: &Context,
                    h: &Helper,
@@ -43,11 +80,417 @@ pub fn toml_helper(_: &Context,
     Ok(())
 }
 
+pub fn upper_helper(_: &Context,
+                    h: &Helper,
+                    _: &Handlebars,
+                    rc: &mut RenderContext)
+                    -> Result<(), RenderError> {
+    let value = to_string(try!(param(h, 0)));
+    write(rc, &value.to_uppercase())
+}
+
+pub fn lower_helper(_: &Context,
+                    h: &Helper,
+                    _: &Handlebars,
+                    rc: &mut RenderContext)
+                    -> Result<(), RenderError> {
+    let value = to_string(try!(param(h, 0)));
+    write(rc, &value.to_lowercase())
+}
+
+/// `{{replace value "from" "to"}}` replaces every occurrence of `from`.
+pub fn replace_helper(_: &Context,
+                      h: &Helper,
+                      _: &Handlebars,
+                      rc: &mut RenderContext)
+                      -> Result<(), RenderError> {
+    let value = to_string(try!(param(h, 0)));
+    let from = to_string(try!(param(h, 1)));
+    let to = to_string(try!(param(h, 2)));
+    write(rc, &value.replace(&from, &to))
+}
+
+/// `{{#split value ","}}{{@part}}{{/split}}` renders the block once for each part of `value`,
+/// which is available as `@part`.
+pub fn split_helper(c: &Context,
+                    h: &Helper,
+                    r: &Handlebars,
+                    rc: &mut RenderContext)
+                    -> Result<(), RenderError> {
+    let value = to_string(try!(param(h, 0)));
+    let separator = to_string(try!(param(h, 1)));
+    if separator.is_empty() {
+        return Err(RenderError::new("Separator for helper \"split\" must not be empty"));
+    }
+    let template = match h.template() {
+        Some(t) => t,
+        None => return Ok(()),
+    };
+    let parts: Vec<&str> = if value.is_empty() {
+        Vec::new()
+    } else {
+        value.split(separator.as_str()).collect()
+    };
+    for (i, part) in parts.iter().enumerate() {
+        let mut local_rc = rc.derive();
+        set_loop_vars(&mut local_rc, i, parts.len());
+        local_rc.set_local_var("@part".to_string(), part.to_json());
+        try!(template.render(c, r, &mut local_rc));
+    }
+    Ok(())
+}
+
+/// `{{join list ", "}}` joins the items of a list; `field="ip"` joins that field of each item
+/// instead.
+pub fn join_helper(_: &Context,
+                   h: &Helper,
+                   _: &Handlebars,
+                   rc: &mut RenderContext)
+                   -> Result<(), RenderError> {
+    let list = try!(param(h, 0));
+    let separator = match h.param(1) {
+        Some(s) => to_string(s.value()),
+        None => String::from(","),
+    };
+    let field = h.hash_get("field").map(|f| to_string(f.value()));
+    let joined = match *list {
+        Json::Array(ref items) => {
+            let strings: Vec<String> = items.iter()
+                .map(|item| match field {
+                    Some(ref f) => item.find(f).map(to_string).unwrap_or(String::new()),
+                    None => to_string(item),
+                })
+                .collect();
+            strings.join(&separator)
+        }
+        _ => to_string(list),
+    };
+    write(rc, &joined)
+}
+
+/// `{{default value "fallback"}}` renders `fallback` if `value` is unset or empty.
+pub fn default_helper(_: &Context,
+                      h: &Helper,
+                      _: &Handlebars,
+                      rc: &mut RenderContext)
+                      -> Result<(), RenderError> {
+    let value = try!(param(h, 0));
+    let fallback = try!(param(h, 1));
+    let chosen = match *value {
+        Json::Null => fallback,
+        Json::String(ref s) if s.is_empty() => fallback,
+        _ => value,
+    };
+    write(rc, &to_string(chosen))
+}
+
+pub fn add_helper(_: &Context,
+                  h: &Helper,
+                  _: &Handlebars,
+                  rc: &mut RenderContext)
+                  -> Result<(), RenderError> {
+    math(h, rc, |a, b| a.checked_add(b), |a, b| Some(a + b))
+}
+
+pub fn sub_helper(_: &Context,
+                  h: &Helper,
+                  _: &Handlebars,
+                  rc: &mut RenderContext)
+                  -> Result<(), RenderError> {
+    math(h, rc, |a, b| a.checked_sub(b), |a, b| Some(a - b))
+}
+
+pub fn mul_helper(_: &Context,
+                  h: &Helper,
+                  _: &Handlebars,
+                  rc: &mut RenderContext)
+                  -> Result<(), RenderError> {
+    math(h, rc, |a, b| a.checked_mul(b), |a, b| Some(a * b))
+}
+
+/// Division of two integers is integer division.
+pub fn div_helper(_: &Context,
+                  h: &Helper,
+                  _: &Handlebars,
+                  rc: &mut RenderContext)
+                  -> Result<(), RenderError> {
+    math(h,
+         rc,
+         |a, b| a.checked_div(b),
+         |a, b| if b == 0.0 { None } else { Some(a / b) })
+}
+
+pub fn mod_helper(_: &Context,
+                  h: &Helper,
+                  _: &Handlebars,
+                  rc: &mut RenderContext)
+                  -> Result<(), RenderError> {
+    math(h,
+         rc,
+         |a, b| a.checked_rem(b),
+         |a, b| if b == 0.0 { None } else { Some(a % b) })
+}
+
+pub fn base64_helper(_: &Context,
+                     h: &Helper,
+                     _: &Handlebars,
+                     rc: &mut RenderContext)
+                     -> Result<(), RenderError> {
+    let value = to_string(try!(param(h, 0)));
+    write(rc, &value.as_bytes().to_base64(STANDARD))
+}
+
+/// Encodes a value for use in a URL query string.
+pub fn urlencode_helper(_: &Context,
+                        h: &Helper,
+                        _: &Handlebars,
+                        rc: &mut RenderContext)
+                        -> Result<(), RenderError> {
+    let value = to_string(try!(param(h, 0)));
+    let encoded: String = form_urlencoded::byte_serialize(value.as_bytes()).collect();
+    write(rc, &encoded)
+}
+
+/// Renders a number, or a string holding one, as a number; fails on anything else.
+pub fn to_number_helper(_: &Context,
+                        h: &Helper,
+                        _: &Handlebars,
+                        rc: &mut RenderContext)
+                        -> Result<(), RenderError> {
+    let number = try!(number_param(h, 0));
+    write(rc, &number.to_string())
+}
+
+/// `{{#eachAlive svc.members}}` iterates over the members which are alive.
+pub fn each_alive_helper(c: &Context,
+                         h: &Helper,
+                         r: &Handlebars,
+                         rc: &mut RenderContext)
+                         -> Result<(), RenderError> {
+    let sort = h.hash_get("sort").map(|s| to_string(s.value()));
+    each_matching(c, h, r, rc, sort, is_alive)
+}
+
+/// `{{#eachHealthy svc.members}}` iterates over the members which are alive and whose health
+/// check isn't critical.
+pub fn each_healthy_helper(c: &Context,
+                           h: &Helper,
+                           r: &Handlebars,
+                           rc: &mut RenderContext)
+                           -> Result<(), RenderError> {
+    let sort = h.hash_get("sort").map(|s| to_string(s.value()));
+    each_matching(c, h, r, rc, sort, |m| {
+        is_alive(m) && m.find("health_check").and_then(|hc| hc.as_string()) != Some("CRITICAL")
+    })
+}
+
+/// `{{#eachWhere svc.members "leader" true}}` iterates over the members whose field equals the
+/// value; without a value, over the members whose field is set and not false, zero or empty.
+pub fn each_where_helper(c: &Context,
+                         h: &Helper,
+                         r: &Handlebars,
+                         rc: &mut RenderContext)
+                         -> Result<(), RenderError> {
+    let field = to_string(try!(param(h, 1)));
+    let expected = h.param(2).map(|e| to_string(e.value()));
+    let sort = h.hash_get("sort").map(|s| to_string(s.value()));
+    each_matching(c, h, r, rc, sort, |m| {
+        match (m.find(&field), expected.as_ref()) {
+            (Some(value), Some(expected)) => to_string(value) == *expected,
+            (Some(value), None) => is_truthy(value),
+            (None, _) => false,
+        }
+    })
+}
+
+/// `{{#eachSorted svc.members "member_id"}}` iterates over every member, ordered by a field.
+pub fn each_sorted_helper(c: &Context,
+                          h: &Helper,
+                          r: &Handlebars,
+                          rc: &mut RenderContext)
+                          -> Result<(), RenderError> {
+    let sort = to_string(try!(param(h, 1)));
+    each_matching(c, h, r, rc, Some(sort), |_| true)
+}
+
+/// Render the block of `h` for each item of the list in its first param which matches
+/// `predicate`, as `each` would, optionally ordered by the `sort` field of each item. The block's
+/// `else` is rendered if nothing matches.
+fn each_matching<F>(c: &Context,
+                    h: &Helper,
+                    r: &Handlebars,
+                    rc: &mut RenderContext,
+                    sort: Option<String>,
+                    predicate: F)
+                    -> Result<(), RenderError>
+    where F: Fn(&Json) -> bool
+{
+    let value = try!(h.param(0).ok_or_else(|| {
+        RenderError::new(&format!("Param not found for helper \"{}\"", h.name()))
+    }));
+    let path = try!(value.path().ok_or_else(|| {
+        RenderError::new(&format!("Helper \"{}\" must be given the path to a list", h.name()))
+    }));
+    let list = match *value.value() {
+        Json::Array(ref list) => list,
+        Json::Null => return render_inverse(c, h, r, rc),
+        _ => {
+            return Err(RenderError::new(&format!("Helper \"{}\" must be given a list",
+                                                 h.name())))
+        }
+    };
+    let template = match h.template() {
+        Some(t) => t,
+        None => return Ok(()),
+    };
+    let mut indices: Vec<usize> = (0..list.len()).filter(|&i| predicate(&list[i])).collect();
+    if let Some(ref field) = sort {
+        indices.sort_by(|&a, &b| {
+            list[a].find(field).partial_cmp(&list[b].find(field)).unwrap_or(Ordering::Equal)
+        });
+    }
+    if indices.is_empty() {
+        return render_inverse(c, h, r, rc);
+    }
+    for (n, &i) in indices.iter().enumerate() {
+        let mut local_rc = rc.derive();
+        set_loop_vars(&mut local_rc, n, indices.len());
+        // Point the block at the item where it lives in the context, as `each` does.
+        let item_path = format!("{}/{}.[{}]", local_rc.get_path(), path, i);
+        local_rc.set_path(item_path);
+        try!(template.render(c, r, &mut local_rc));
+    }
+    Ok(())
+}
+
+fn render_inverse(c: &Context,
+                  h: &Helper,
+                  r: &Handlebars,
+                  rc: &mut RenderContext)
+                  -> Result<(), RenderError> {
+    match h.inverse() {
+        Some(t) => t.render(c, r, rc),
+        None => Ok(()),
+    }
+}
+
+fn set_loop_vars(rc: &mut RenderContext, index: usize, len: usize) {
+    rc.set_local_var("@index".to_string(), index.to_json());
+    rc.set_local_var("@first".to_string(), (index == 0).to_json());
+    rc.set_local_var("@last".to_string(), (index == len - 1).to_json());
+}
+
+fn is_alive(member: &Json) -> bool {
+    member.find("alive").and_then(|a| a.as_boolean()).unwrap_or(false)
+}
+
+fn is_truthy(value: &Json) -> bool {
+    match *value {
+        Json::Null => false,
+        Json::Boolean(b) => b,
+        Json::I64(i) => i != 0,
+        Json::U64(u) => u != 0,
+        Json::F64(f) => f != 0.0,
+        Json::String(ref s) => !s.is_empty(),
+        Json::Array(ref a) => !a.is_empty(),
+        Json::Object(_) => true,
+    }
+}
+
+fn param<'a>(h: &'a Helper, idx: usize) -> Result<&'a Json, RenderError> {
+    h.param(idx)
+        .map(|p| p.value())
+        .ok_or_else(|| {
+            RenderError::new(&format!("Param {} not found for helper \"{}\"", idx, h.name()))
+        })
+}
+
+/// The number in a param, as an `I64` if it is an integer and an `F64` otherwise. Strings are
+/// parsed.
+fn number_param(h: &Helper, idx: usize) -> Result<Json, RenderError> {
+    let value = try!(param(h, idx));
+    let number = match *value {
+        Json::I64(i) => Some(Json::I64(i)),
+        Json::U64(u) if u <= i64::max_value() as u64 => Some(Json::I64(u as i64)),
+        Json::U64(u) => Some(Json::F64(u as f64)),
+        Json::F64(f) => Some(Json::F64(f)),
+        Json::String(ref s) => {
+            let s = s.trim();
+            s.parse::<i64>()
+                .ok()
+                .map(Json::I64)
+                .or_else(|| s.parse::<f64>().ok().map(Json::F64))
+        }
+        _ => None,
+    };
+    number.ok_or_else(|| {
+        RenderError::new(&format!("Param {} of helper \"{}\" is not a number: {}",
+                                  idx,
+                                  h.name(),
+                                  value))
+    })
+}
+
+fn math<I, F>(h: &Helper, rc: &mut RenderContext, int_op: I, float_op: F) -> Result<(), RenderError>
+    where I: Fn(i64, i64) -> Option<i64>,
+          F: Fn(f64, f64) -> Option<f64>
+{
+    let a = try!(number_param(h, 0));
+    let b = try!(number_param(h, 1));
+    let result = match (&a, &b) {
+        (&Json::I64(a), &Json::I64(b)) => int_op(a, b).map(Json::I64),
+        _ => {
+            float_op(a.as_f64().unwrap_or(0.0), b.as_f64().unwrap_or(0.0))
+                .and_then(|f| if f.is_finite() { Some(Json::F64(f)) } else { None })
+        }
+    };
+    match result {
+        Some(result) => write(rc, &result.to_string()),
+        None => {
+            Err(RenderError::new(&format!("Helper \"{}\" has no result for {} and {}",
+                                          h.name(),
+                                          a,
+                                          b)))
+        }
+    }
+}
+
+/// Strings render as themselves and nothing renders as nothing; anything else renders as JSON.
+fn to_string(value: &Json) -> String {
+    match *value {
+        Json::String(ref s) => s.clone(),
+        Json::Null => String::new(),
+        _ => value.to_string(),
+    }
+}
+
+fn write(rc: &mut RenderContext, s: &str) -> Result<(), RenderError> {
+    try!(rc.writer.write(s.as_bytes()));
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use handlebars::{Handlebars, Template};
+    use rustc_serialize::json::Json;
     use std::collections::BTreeMap;
-    use super::{json_helper, toml_helper};
+    use super::{json_helper, register, toml_helper};
+
+    static MEMBERS: &'static str = r#"{"svc": {"port": 6379, "members": [
+        {"member_id": "c", "ip": "10.0.0.3", "alive": true, "leader": false,
+         "health_check": "OK"},
+        {"member_id": "a", "ip": "10.0.0.1", "alive": true, "leader": true,
+         "health_check": "CRITICAL"},
+        {"member_id": "b", "ip": "10.0.0.2", "alive": false, "leader": false,
+         "health_check": "OK"}]}}"#;
+
+    fn render(template: &str, data: &str) -> Result<String, String> {
+        let mut handlebars = Handlebars::new();
+        register(&mut handlebars);
+        handlebars.register_escape_fn(|s: &str| s.to_string());
+        handlebars.template_render(template, &Json::from_str(data).unwrap())
+            .map_err(|e| e.to_string())
+    }
 
     #[test]
     fn test_handlebars_json_helper() {
@@ -91,4 +534,67 @@ mod test {
 "#
                        .to_string());
     }
+
+    #[test]
+    fn test_handlebars_string_helpers() {
+        let data = r#"{"name": "Redis Server", "hosts": "a:1,b:2"}"#;
+        assert_eq!(render("{{upper name}} {{lower name}}", data).unwrap(),
+                   "REDIS SERVER redis server");
+        assert_eq!(render(r#"{{replace name " " "-"}}"#, data).unwrap(),
+                   "Redis-Server");
+        assert_eq!(render(r#"{{#split hosts ","}}[{{@index}}:{{@part}}]{{/split}}"#, data)
+                       .unwrap(),
+                   "[0:a:1][1:b:2]");
+        assert_eq!(render(r#"{{join svc.members ", " field="ip"}}"#, MEMBERS).unwrap(),
+                   "10.0.0.3, 10.0.0.1, 10.0.0.2");
+    }
+
+    #[test]
+    fn test_handlebars_default_helper() {
+        let data = r#"{"set": "value", "empty": ""}"#;
+        assert_eq!(render(r#"{{default set "x"}} {{default empty "x"}} {{default unset "x"}}"#,
+                          data)
+                       .unwrap(),
+                   "value x x");
+    }
+
+    #[test]
+    fn test_handlebars_math_helpers() {
+        let data = r#"{"port": 6379, "workers": "4", "ratio": 0.5}"#;
+        assert_eq!(render("{{add port 10000}} {{sub port 1}} {{mul workers 2}}", data).unwrap(),
+                   "16379 6378 8");
+        assert_eq!(render("{{div 7 2}} {{mod 7 2}} {{mul ratio 3}}", data).unwrap(),
+                   "3 1 1.5");
+        assert!(render("{{div port 0}}", data).is_err());
+        assert!(render(r#"{{add port "many"}}"#, data).is_err());
+    }
+
+    #[test]
+    fn test_handlebars_encoding_helpers() {
+        let data = r#"{"secret": "user:pass", "query": "a b&c=d", "number": " 42 "}"#;
+        assert_eq!(render("{{base64 secret}}", data).unwrap(), "dXNlcjpwYXNz");
+        assert_eq!(render("{{urlencode query}}", data).unwrap(), "a+b%26c%3Dd");
+        assert_eq!(render("{{toNumber number}}", data).unwrap(), "42");
+        assert!(render("{{toNumber query}}", data).is_err());
+    }
+
+    #[test]
+    fn test_handlebars_each_member_helpers() {
+        let alive = r#"{{#eachAlive svc.members sort="member_id"}}{{member_id}}{{/eachAlive}}"#;
+        assert_eq!(render(alive, MEMBERS).unwrap(), "ac");
+        assert_eq!(render("{{#eachHealthy svc.members}}{{member_id}}{{/eachHealthy}}", MEMBERS)
+                       .unwrap(),
+                   "c");
+        assert_eq!(render(r#"{{#eachWhere svc.members "leader" true}}{{ip}}{{/eachWhere}}"#,
+                          MEMBERS)
+                       .unwrap(),
+                   "10.0.0.1");
+        let sorted = "{{#eachSorted svc.members \"member_id\"}}{{@index}}{{member_id}}\
+                      {{/eachSorted}}";
+        assert_eq!(render(sorted, MEMBERS).unwrap(), "0a1b2c");
+        assert_eq!(render(r#"{{#eachWhere svc.members "suspect"}}x{{else}}none{{/eachWhere}}"#,
+                          MEMBERS)
+                       .unwrap(),
+                   "none");
+    }
 }
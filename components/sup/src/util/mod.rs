@@ -15,6 +15,7 @@
 pub mod convert;
 pub mod handlebars_helpers;
 pub mod path;
+pub mod remote_sup;
 pub mod sys;
 pub mod users;
 
@@ -0,0 +1,70 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small client for the http gateway of a running Supervisor, used by the commands which
+//! manage that Supervisor rather than running a service themselves.
+
+use std::io::Read;
+
+use hyper::Client;
+use hyper::header::{Authorization, Bearer, ContentType};
+use hyper::method::Method;
+
+use config::gconfig;
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "RS";
+
+/// The gateway we talk to when no remote Supervisor was given.
+static DEFAULT_REMOTE_SUP: &'static str = "127.0.0.1:9631";
+
+pub fn get(path: &str) -> Result<String> {
+    request(Method::Get, path, None)
+}
+
+pub fn post(path: &str, body: &str) -> Result<String> {
+    request(Method::Post, path, Some(body))
+}
+
+pub fn delete(path: &str) -> Result<String> {
+    request(Method::Delete, path, None)
+}
+
+fn request(method: Method, path: &str, body: Option<&str>) -> Result<String> {
+    let remote = match *gconfig().remote_sup() {
+        Some(ref remote) => remote.as_str(),
+        None => DEFAULT_REMOTE_SUP,
+    };
+    let url = format!("http://{}{}", remote, path);
+    debug!("{} {}", method, url);
+    let client = Client::new();
+    let mut request = client.request(method, url.as_str());
+    if let Some(ref token) = *gconfig().gateway_auth_token() {
+        request = request.header(Authorization(Bearer { token: token.clone() }));
+    }
+    if let Some(body) = body {
+        request = request.header(ContentType::json()).body(body);
+    }
+    let mut response = try!(request.send());
+    let mut response_body = String::new();
+    try!(response.read_to_string(&mut response_body));
+    if response.status.is_success() {
+        Ok(response_body)
+    } else {
+        Err(sup_error!(Error::RemoteSupFailed(format!("{} returned {} {}",
+                                                       url,
+                                                       response.status,
+                                                       response_body))))
+    }
+}